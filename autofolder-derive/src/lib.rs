@@ -0,0 +1,238 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_docs)]
+
+//! Procedural macros for the [autofolder](https://docs.rs/autofolder) crate.
+//!
+//! These are re-exported by `autofolder` itself when its `derive` feature is enabled, and
+//! should be used from there.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Attribute that implements `FolderTrait` for `ImplFolder` with the annotated free function.
+///
+/// The function must take the output and the item as its parameters, and return the new
+/// output. Generic parameters and `where` clauses are carried over to the `FolderTrait` impl.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// pub struct Total<T>(T);
+///
+/// #[autofolder::fold]
+/// fn add<T>(total: Total<T>, item: T) -> Total<T>
+/// where
+///     T: core::ops::Add<Output = T>,
+/// {
+///     Total(total.0 + item)
+/// }
+///
+/// let mut sum = ImplFolder::new(Total(0_u32));
+/// sum.extend(1..=4);
+/// assert_eq!(sum.into_inner().0, 10);
+///
+/// // The function can still be called directly:
+/// assert_eq!(add(Total(1.5), 2.0).0, 3.5);
+/// ```
+#[proc_macro_attribute]
+pub fn fold(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = TokenStream2::from(attr);
+    if !attr.is_empty() {
+        return syn::Error::new_spanned(attr, "#[fold] doesn't take arguments")
+            .to_compile_error()
+            .into();
+    }
+    let func = syn::parse_macro_input!(item as syn::ItemFn);
+    expand_fold(&func)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_fold(func: &syn::ItemFn) -> syn::Result<TokenStream2> {
+    let sig = &func.sig;
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "#[fold] can't be used on async functions",
+        ));
+    }
+    let types = sig
+        .inputs
+        .iter()
+        .map(|input| match input {
+            syn::FnArg::Typed(pat_type) => Ok(&*pat_type.ty),
+            syn::FnArg::Receiver(receiver) => Err(syn::Error::new_spanned(
+                receiver,
+                "#[fold] must be used on a free function",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let (output_type, item_type) = match types.as_slice() {
+        [output_type, item_type] => (output_type, item_type),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &sig.inputs,
+                "#[fold] function must take the output and the item as parameters",
+            ))
+        }
+    };
+    match &sig.output {
+        syn::ReturnType::Type(_, return_type)
+            if quote!(#return_type).to_string() == quote!(#output_type).to_string() => {}
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &sig.output,
+                "#[fold] function must return the type of its first parameter",
+            ))
+        }
+    }
+    let ident = &sig.ident;
+    // Lifetimes can be inferred, but type and const parameters have to be forwarded:
+    let params = sig
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(t) => Some(&t.ident),
+            syn::GenericParam::Const(c) => Some(&c.ident),
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let (impl_generics, _, where_clause) = sig.generics.split_for_impl();
+    Ok(quote! {
+        #func
+
+        impl #impl_generics ::autofolder::FolderTrait<#output_type, #item_type>
+            for ::autofolder::ImplFolder<#output_type, #item_type>
+        #where_clause
+        {
+            fn fold(output: #output_type, item: #item_type) -> #output_type {
+                #ident::<#(#params),*>(output, item)
+            }
+        }
+    })
+}
+
+/// Derive macro that implements `ReduceTrait` for the `ImplReduce` of the type.
+///
+/// The reduce function is provided with one of the following attributes:
+/// - `#[reduce(with = func)]`: `func` reduces two values of the type into one.
+/// - `#[reduce(inner = func)]`: for newtypes, `func` reduces the two inner values into one,
+///   which is then wrapped back.
+///
+/// `func` can be a path or a closure; closures must name the type explicitly in their
+/// parameters, as `Self` refers to the `ImplReduce`. Generic parameters and `where` clauses of
+/// the type are carried over to the `ReduceTrait` impl.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// #[derive(Reduce, Debug, PartialEq)]
+/// #[reduce(inner = core::cmp::max)]
+/// pub struct Max<T: Ord>(T);
+///
+/// #[derive(Reduce, Debug, PartialEq)]
+/// #[reduce(with = longest)]
+/// pub struct Word {
+///     s: &'static str,
+/// }
+///
+/// fn longest(a: Word, b: Word) -> Word {
+///     if b.s.len() > a.s.len() {
+///         b
+///     } else {
+///         a
+///     }
+/// }
+///
+/// let max = (1..=5).map(Max).collect::<ImplReduce<_>>();
+/// assert_eq!(max.into_inner(), Some(Max(5)));
+///
+/// let mut longest = ImplReduce::default();
+/// longest.extend(["a", "abc", "ab", "xyz"].iter().map(|&s| Word { s }));
+/// assert_eq!(longest.into_inner(), Some(Word { s: "abc" }));
+/// ```
+#[proc_macro_derive(Reduce, attributes(reduce))]
+pub fn derive_reduce(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    expand_reduce(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+enum ReduceFunc {
+    With(syn::Expr),
+    Inner(syn::Expr),
+}
+
+fn parse_reduce_func(input: &syn::DeriveInput) -> syn::Result<ReduceFunc> {
+    let mut func = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("reduce")) {
+        attr.parse_nested_meta(|meta| {
+            let new = if meta.path.is_ident("with") {
+                ReduceFunc::With(meta.value()?.parse()?)
+            } else if meta.path.is_ident("inner") {
+                ReduceFunc::Inner(meta.value()?.parse()?)
+            } else {
+                return Err(meta.error("unsupported reduce attribute, expected `with` or `inner`"));
+            };
+            if func.replace(new).is_some() {
+                return Err(meta.error("the reduce function was already provided"));
+            }
+            Ok(())
+        })?;
+    }
+    func.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "missing #[reduce(with = ...)] or #[reduce(inner = ...)] attribute",
+        )
+    })
+}
+
+fn expand_reduce(input: &syn::DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let body = match parse_reduce_func(input)? {
+        ReduceFunc::With(func) => quote! {
+            (#func)(lhs, rhs)
+        },
+        ReduceFunc::Inner(func) => {
+            let member = match &input.data {
+                syn::Data::Struct(data) if data.fields.len() == 1 => {
+                    match data.fields.iter().next().and_then(|f| f.ident.clone()) {
+                        Some(ident) => syn::Member::Named(ident),
+                        None => syn::Member::Unnamed(0.into()),
+                    }
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        name,
+                        "#[reduce(inner = ...)] requires a struct with a single field",
+                    ))
+                }
+            };
+            quote! {
+                #name {
+                    #member: (#func)(lhs.#member, rhs.#member),
+                }
+            }
+        }
+    };
+    Ok(quote! {
+        impl #impl_generics ::autofolder::ReduceTrait<#name #ty_generics>
+            for ::autofolder::ImplReduce<#name #ty_generics>
+        #where_clause
+        {
+            fn reduce(lhs: #name #ty_generics, rhs: #name #ty_generics) -> #name #ty_generics {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const LEN: u64 = 100_000;
+
+/// Compare folding `u64` items one by one with extending by the whole iterator
+fn bench_sum(c: &mut Criterion) {
+    let items = (0..LEN).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("u64 sum");
+    group.bench_function("fold", |b| {
+        b.iter(|| {
+            let mut sum = DynFolder::<u64, u64, _>::new(0, |a, i| a + i);
+            black_box(&items).iter().for_each(|i| sum.fold(*i));
+            sum.into_inner()
+        })
+    });
+    group.bench_function("extend", |b| {
+        b.iter(|| {
+            let mut sum = DynFolder::<u64, u64, _>::new(0, |a, i| a + i);
+            sum.extend(black_box(&items).iter().copied());
+            sum.into_inner()
+        })
+    });
+    group.finish();
+}
+
+/// Compare folding `&str` items one by one into a `String` with extending by the whole iterator
+fn bench_concat(c: &mut Criterion) {
+    let items = (0..LEN).map(|i| i.to_string()).collect::<Vec<_>>();
+    let concat = |mut a: String, i: &str| {
+        a.push_str(i);
+        a
+    };
+    let mut group = c.benchmark_group("String concat");
+    group.bench_function("fold", |b| {
+        b.iter(|| {
+            let mut folder = DynFolder::<String, &str, _>::new(String::new(), concat);
+            black_box(&items).iter().for_each(|i| folder.fold(i));
+            folder.into_inner()
+        })
+    });
+    group.bench_function("extend", |b| {
+        b.iter(|| {
+            let mut folder = DynFolder::<String, &str, _>::new(String::new(), concat);
+            folder.extend(black_box(&items).iter().map(String::as_str));
+            folder.into_inner()
+        })
+    });
+    group.finish();
+}
+
+/// Compare extending `Max<f32>` and `Adder<f32>` by a slice iterator with the slice methods
+fn bench_slice(c: &mut Criterion) {
+    let items = (0..LEN).map(|i| (i % 1000) as f32).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("f32 slice");
+    group.bench_function("max extend", |b| {
+        b.iter(|| {
+            let mut max = Max::<f32>::default();
+            max.extend(black_box(&items).iter());
+            max.into_inner()
+        })
+    });
+    group.bench_function("max reduce_slice", |b| {
+        b.iter(|| {
+            let mut max = Max::<f32>::default();
+            max.reduce_slice(black_box(&items));
+            max.into_inner()
+        })
+    });
+    group.bench_function("sum extend", |b| {
+        b.iter(|| {
+            let mut sum = Adder::<f32>::default();
+            sum.extend(black_box(&items).iter());
+            sum.into_inner()
+        })
+    });
+    group.bench_function("sum fold_slice", |b| {
+        b.iter(|| {
+            let mut sum = Adder::<f32>::default();
+            sum.fold_slice(black_box(&items));
+            sum.into_inner()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_sum, bench_concat, bench_slice);
+criterion_main!(benches);
@@ -0,0 +1,367 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// The `CheckedAdder` type sums primitive integers, recording overflows.
+///
+/// The sum starts at `0`, and becomes *overflowed* when an addition overflows: from then on,
+/// further items are ignored and [`CheckedAdder::into_inner`] returns `None`.
+///
+/// As with the other adders, [`Sum`](core::iter::Sum) is implemented alongside
+/// [`FromIterator`](core::iter::FromIterator).
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that sums u8 items.
+/// let mut sum = CheckedAdder::<u8>::default();
+///
+/// // We can "fold-in" individual items:
+/// sum.fold(100);
+///
+/// // We can then peek at the running output:
+/// println!("Partial sum is {:?}", sum.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// sum.extend(vec![100, 100]);
+///
+/// // And finally consume the autofolder to get the final output value, if there was no
+/// // overflow:
+/// assert!(sum.is_overflowed());
+/// assert_eq!(sum.into_inner(), None);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckedAdder<Item> {
+    item: Option<Item>,
+    count: usize,
+}
+
+/// The `SaturatingAdder` type sums primitive integers, saturating at the numeric bounds
+/// instead of overflowing.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut sum = vec![100, 100].into_iter().collect::<SaturatingAdder<i8>>();
+/// assert_eq!(*sum.as_ref(), i8::MAX);
+///
+/// // Subsequent items are added to the saturated value:
+/// sum.fold(-27);
+/// assert_eq!(sum.into_inner(), 100);
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SaturatingAdder<Item> {
+    item: Item,
+    count: usize,
+}
+
+/// The `WrappingAdder` type sums primitive integers, wrapping around at the numeric bounds
+/// instead of overflowing, in both debug and release builds.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let sum = vec![200, 100].into_iter().collect::<WrappingAdder<u8>>();
+/// assert_eq!(sum.into_inner(), 44);
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WrappingAdder<Item> {
+    item: Item,
+    count: usize,
+}
+
+macro_rules! impl_checkedadder {
+    ($($int: ty),*) => {$(
+        impl CheckedAdder<$int> {
+            /// Creates a new `CheckedAdder` with the provided initial value.
+            pub const fn new(initial: $int) -> Self {
+                Self {
+                    item: Some(initial),
+                    count: 0,
+                }
+            }
+            /// Deconstruct self and return the sum, or `None` if it overflowed.
+            pub fn into_inner(self) -> Option<$int> {
+                self.item
+            }
+            /// Returns the number of items folded in.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Returns `true` if no item was folded in.
+            pub fn is_empty(&self) -> bool {
+                self.count == 0
+            }
+            /// Returns a reference to the sum, or `None` if it overflowed.
+            pub fn as_ref(&self) -> Option<&$int> {
+                self.item.as_ref()
+            }
+            /// Returns `true` if an addition overflowed.
+            pub fn is_overflowed(&self) -> bool {
+                self.item.is_none()
+            }
+            /// Add the given item to the sum.
+            pub fn fold(&mut self, item: $int) {
+                self.item = self.item.and_then(|current| current.checked_add(item));
+                self.count += 1;
+            }
+            /// Add the item behind the ref to the sum.
+            pub fn fold_ref(&mut self, item: &$int) {
+                self.fold(*item)
+            }
+            /// Add the sum of `other` to self.
+            pub fn merge_from(&mut self, other: Self) {
+                let count = self.count + other.count;
+                match other.item {
+                    Some(item) => self.fold(item),
+                    None => self.item = None,
+                }
+                self.count = count;
+            }
+            /// Add the sum of `other` to self and return the result.
+            pub fn merge(mut self, other: Self) -> Self {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl Default for CheckedAdder<$int> {
+            fn default() -> Self {
+                Self::new(0)
+            }
+        }
+
+        impl From<$int> for CheckedAdder<$int> {
+            fn from(item: $int) -> Self {
+                Self::new(item)
+            }
+        }
+
+        impl Extend<$int> for CheckedAdder<$int> {
+            fn extend<It: IntoIterator<Item = $int>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold(i));
+            }
+        }
+
+        impl<'a> Extend<&'a $int> for CheckedAdder<$int> {
+            fn extend<It: IntoIterator<Item = &'a $int>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold_ref(i));
+            }
+        }
+
+        impl core::iter::FromIterator<$int> for CheckedAdder<$int> {
+            fn from_iter<It: IntoIterator<Item = $int>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a> core::iter::FromIterator<&'a $int> for CheckedAdder<$int> {
+            fn from_iter<It: IntoIterator<Item = &'a $int>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl core::iter::Sum<$int> for CheckedAdder<$int> {
+            fn sum<It: Iterator<Item = $int>>(iter: It) -> Self {
+                iter.collect()
+            }
+        }
+
+        impl<'a> core::iter::Sum<&'a $int> for CheckedAdder<$int> {
+            fn sum<It: Iterator<Item = &'a $int>>(iter: It) -> Self {
+                iter.collect()
+            }
+        }
+
+        impl FoldItem<$int> for CheckedAdder<$int> {
+            fn fold_item(&mut self, item: $int) {
+                self.fold(item)
+            }
+        }
+
+        impl Folding for CheckedAdder<$int> {
+            type Item = $int;
+            type Output = Option<$int>;
+            type Peek<'a>
+                = Option<&'a $int>
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Option<$int> {
+                self.into_inner()
+            }
+        }
+    )*};
+}
+
+impl_checkedadder!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_intadder {
+    ($name: ident, $add: ident, $($int: ty),*) => {$(
+        impl $name<$int> {
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the provided initial value.")]
+            pub const fn new(initial: $int) -> Self {
+                Self {
+                    item: initial,
+                    count: 0,
+                }
+            }
+            /// Deconstruct self and return the sum.
+            pub fn into_inner(self) -> $int {
+                self.item
+            }
+            /// Returns the number of items folded in.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Returns `true` if no item was folded in.
+            pub fn is_empty(&self) -> bool {
+                self.count == 0
+            }
+            #[doc = concat!("Add the given item to the sum with `", stringify!($add), "`.")]
+            pub fn fold(&mut self, item: $int) {
+                self.item = self.item.$add(item);
+                self.count += 1;
+            }
+            #[doc = concat!("Add the item behind the ref to the sum with `", stringify!($add), "`.")]
+            pub fn fold_ref(&mut self, item: &$int) {
+                self.fold(*item)
+            }
+            /// Add the sum of `other` to self.
+            pub fn merge_from(&mut self, other: Self) {
+                let count = self.count + other.count;
+                self.fold(other.item);
+                self.count = count;
+            }
+            /// Add the sum of `other` to self and return the result.
+            pub fn merge(mut self, other: Self) -> Self {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl AsRef<$int> for $name<$int> {
+            fn as_ref(&self) -> &$int {
+                &self.item
+            }
+        }
+
+        impl From<$int> for $name<$int> {
+            fn from(item: $int) -> Self {
+                Self::new(item)
+            }
+        }
+
+        impl Extend<$int> for $name<$int> {
+            fn extend<It: IntoIterator<Item = $int>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold(i));
+            }
+        }
+
+        impl<'a> Extend<&'a $int> for $name<$int> {
+            fn extend<It: IntoIterator<Item = &'a $int>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold_ref(i));
+            }
+        }
+
+        impl core::iter::FromIterator<$int> for $name<$int> {
+            fn from_iter<It: IntoIterator<Item = $int>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a> core::iter::FromIterator<&'a $int> for $name<$int> {
+            fn from_iter<It: IntoIterator<Item = &'a $int>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl core::iter::Sum<$int> for $name<$int> {
+            fn sum<It: Iterator<Item = $int>>(iter: It) -> Self {
+                iter.collect()
+            }
+        }
+
+        impl<'a> core::iter::Sum<&'a $int> for $name<$int> {
+            fn sum<It: Iterator<Item = &'a $int>>(iter: It) -> Self {
+                iter.collect()
+            }
+        }
+
+        impl FoldItem<$int> for $name<$int> {
+            fn fold_item(&mut self, item: $int) {
+                self.fold(item)
+            }
+        }
+
+        impl Folding for $name<$int> {
+            type Item = $int;
+            type Output = $int;
+            type Peek<'a>
+                = &'a $int
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> $int {
+                self.into_inner()
+            }
+        }
+    )*};
+}
+
+impl_intadder!(
+    SaturatingAdder,
+    saturating_add,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize
+);
+impl_intadder!(
+    WrappingAdder,
+    wrapping_add,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize
+);
@@ -0,0 +1,224 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use core::marker;
+
+/// The `Any` type checks if any of the iterated values satisfies a predicate.
+///
+/// This is a resumable counterpart of [`Iterator::any`]: the result can be built from several
+/// iterators, and `extend` stops consuming the iterator given as soon as the result is `true`.
+/// Items folded in after that are not evaluated.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that checks if any item is even.
+/// let mut any = Any::new(|i: &u32| i % 2 == 0);
+///
+/// // We can "fold-in" individual items:
+/// any.fold(1);
+///
+/// // We can then peek at the running output:
+/// println!("Partial result is {}", any.as_ref());
+///
+/// // And still keep on folding by processing whole iterators, that are consumed only until
+/// // the result is known:
+/// let mut iter = vec![3, 4, 5].into_iter();
+/// any.extend(&mut iter);
+/// assert_eq!(iter.next(), Some(5));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// assert!(any.into_inner());
+/// ```
+#[derive(Copy, Clone)]
+pub struct Any<Item, Func> {
+    result: bool,
+    count: usize,
+    function: Func,
+    item: marker::PhantomData<Item>,
+}
+
+/// The `All` type checks if all the iterated values satisfy a predicate.
+///
+/// This is a resumable counterpart of [`Iterator::all`]: the result can be built from several
+/// iterators, and `extend` stops consuming the iterator given as soon as the result is `false`.
+/// Items folded in after that are not evaluated.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut all = All::new(|s: &&str| !s.is_empty());
+/// all.extend(vec!["a", "b"]);
+/// assert!(*all.as_ref());
+///
+/// let mut iter = vec!["c", "", "d"].into_iter();
+/// all.extend(&mut iter);
+/// assert_eq!(all.count(), 4);
+/// assert_eq!(iter.next(), Some("d"));
+/// assert!(!all.into_inner());
+/// ```
+#[derive(Copy, Clone)]
+pub struct All<Item, Func> {
+    result: bool,
+    count: usize,
+    function: Func,
+    item: marker::PhantomData<Item>,
+}
+
+macro_rules! impl_anyall {
+    ($name: ident, $decided: literal) => {
+        impl<Item, Func> $name<Item, Func> {
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the provided predicate.")]
+            pub const fn new(func: Func) -> Self
+            where
+                Func: FnMut(&Item) -> bool,
+            {
+                Self {
+                    result: !$decided,
+                    count: 0,
+                    function: func,
+                    item: marker::PhantomData,
+                }
+            }
+            /// Deconstruct self and return the result.
+            pub fn into_inner(self) -> bool {
+                self.result
+            }
+            #[doc = concat!("Returns `true` if the result is already known to be `", stringify!($decided), "`, in which case further items are not evaluated.")]
+            pub fn is_decided(&self) -> bool {
+                self.result == $decided
+            }
+            /// Returns the number of items evaluated by the predicate.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Evaluates the predicate on the given item, unless the result is already known.
+            pub fn fold(&mut self, item: Item)
+            where
+                Func: FnMut(&Item) -> bool,
+            {
+                self.fold_ref(&item)
+            }
+            /// Evaluates the predicate on the item behind the ref, unless the result is already
+            /// known.
+            pub fn fold_ref(&mut self, item: &Item)
+            where
+                Func: FnMut(&Item) -> bool,
+            {
+                if !self.is_decided() {
+                    self.result = (self.function)(item);
+                    self.count += 1;
+                }
+            }
+            /// Combine the result of `other` into self.
+            pub fn merge_from(&mut self, other: Self) {
+                if other.is_decided() {
+                    self.result = $decided;
+                }
+                self.count += other.count;
+            }
+            /// Combine the result of `other` into self and return the result.
+            pub fn merge(mut self, other: Self) -> Self {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl<Item, Func> AsRef<bool> for $name<Item, Func> {
+            fn as_ref(&self) -> &bool {
+                &self.result
+            }
+        }
+
+        impl<Item, Func> core::fmt::Debug for $name<Item, Func> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{}::<{}, _> {{ result: {:?}, count: {}, function: {} }}",
+                    stringify!($name),
+                    &core::any::type_name::<Item>(),
+                    self.result,
+                    self.count,
+                    &core::any::type_name::<Func>(),
+                )
+            }
+        }
+
+        impl<Item, Func> From<Func> for $name<Item, Func>
+        where
+            Func: FnMut(&Item) -> bool,
+        {
+            fn from(func: Func) -> Self {
+                Self::new(func)
+            }
+        }
+
+        impl<Item, Func> Extend<Item> for $name<Item, Func>
+        where
+            Func: FnMut(&Item) -> bool,
+        {
+            fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+                let mut iter = iter.into_iter();
+                while !self.is_decided() {
+                    match iter.next() {
+                        Some(item) => self.fold(item),
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        impl<'a, Item, Func> Extend<&'a Item> for $name<Item, Func>
+        where
+            Func: FnMut(&Item) -> bool,
+        {
+            fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+                let mut iter = iter.into_iter();
+                while !self.is_decided() {
+                    match iter.next() {
+                        Some(item) => self.fold_ref(item),
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        impl<Item, Func> FoldItem<Item> for $name<Item, Func>
+        where
+            Func: FnMut(&Item) -> bool,
+        {
+            fn fold_item(&mut self, item: Item) {
+                self.fold(item)
+            }
+        }
+
+        impl<Item, Func> Folding for $name<Item, Func>
+        where
+            Func: FnMut(&Item) -> bool,
+        {
+            type Item = Item;
+            type Output = bool;
+            type Peek<'a>
+                = bool
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.result
+            }
+            fn finish(self) -> bool {
+                self.into_inner()
+            }
+        }
+    };
+}
+
+impl_anyall!(Any, true);
+impl_anyall!(All, false);
@@ -0,0 +1,273 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::marker;
+
+/// The `ApproxDistinct` type estimates the number of distinct items using the HyperLogLog
+/// algorithm.
+///
+/// Instead of storing the items, it keeps `2^precision` one-byte registers, which makes the
+/// memory usage constant. The relative standard error of the estimate is given by
+/// [`ApproxDistinct::error_bound`]; see [`CountDistinct`](crate::CountDistinct) for an exact
+/// count.
+///
+/// Items are hashed with [`DefaultHasher`], so merging estimators created by different builds
+/// of a program is not supported.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that estimates the number of distinct u64 items.
+/// let mut distinct = ApproxDistinct::<u64>::new(12);
+///
+/// // We can "reduce-in" individual items:
+/// distinct.reduce(7);
+///
+/// // We can then peek at the running estimate:
+/// println!("Partial estimate is {}", distinct.estimate());
+///
+/// // And still keep on folding by processing whole iterators:
+/// distinct.extend((0..10_000).map(|i| i % 1000));
+///
+/// // And finally consume the autofolder to get the estimate, which is within a few standard
+/// // errors of the exact count:
+/// let error = 4.0 * distinct.error_bound();
+/// let estimate = distinct.into_inner() as f64;
+/// assert!((estimate - 1000.0).abs() < 1000.0 * error);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ApproxDistinctState"))]
+pub struct ApproxDistinct<Item> {
+    precision: u8,
+    registers: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    item: marker::PhantomData<Item>,
+}
+
+/// The deserialized state of an [`ApproxDistinct`], which is validated before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ApproxDistinctState {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl<Item> std::convert::TryFrom<ApproxDistinctState> for ApproxDistinct<Item> {
+    type Error = String;
+    fn try_from(state: ApproxDistinctState) -> Result<Self, String> {
+        if !Self::supports(state.precision) {
+            return Err(format!("unsupported precision {}", state.precision));
+        }
+        if state.registers.len() != 1 << state.precision {
+            return Err(format!(
+                "{} registers for precision {}",
+                state.registers.len(),
+                state.precision
+            ));
+        }
+        Ok(Self {
+            precision: state.precision,
+            registers: state.registers,
+            item: marker::PhantomData,
+        })
+    }
+}
+
+impl<Item> ApproxDistinct<Item> {
+    /// The smallest supported precision.
+    pub const MIN_PRECISION: u8 = 4;
+    /// The largest supported precision.
+    pub const MAX_PRECISION: u8 = 18;
+    /// The precision used by [`Default`].
+    pub const DEFAULT_PRECISION: u8 = 12;
+
+    /// Creates a new empty `ApproxDistinct` with `2^precision` registers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precision is not between [`ApproxDistinct::MIN_PRECISION`] and
+    /// [`ApproxDistinct::MAX_PRECISION`].
+    pub fn new(precision: u8) -> Self {
+        assert!(
+            Self::supports(precision),
+            "unsupported precision {}",
+            precision
+        );
+        Self {
+            precision,
+            registers: vec![0; 1 << precision],
+            item: marker::PhantomData,
+        }
+    }
+    fn supports(precision: u8) -> bool {
+        (Self::MIN_PRECISION..=Self::MAX_PRECISION).contains(&precision)
+    }
+    /// Returns the precision.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+    /// Returns the relative standard error of the estimate, `1.04 / sqrt(2^precision)`.
+    pub fn error_bound(&self) -> f64 {
+        1.04 / (self.registers.len() as f64).sqrt()
+    }
+    /// Returns the estimated number of distinct items reduced in.
+    pub fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum = self
+            .registers
+            .iter()
+            .map(|&r| 2.0_f64.powi(-i32::from(r)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            // Linear counting is more accurate for small cardinalities:
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        }
+    }
+    /// Deconstruct self and return the estimate, rounded.
+    pub fn into_inner(self) -> u64 {
+        self.estimate().round() as u64
+    }
+    /// Updates the registers with the given item.
+    pub fn reduce(&mut self, item: Item)
+    where
+        Item: Hash,
+    {
+        self.reduce_ref(&item)
+    }
+    /// Updates the registers with the item behind the ref.
+    ///
+    /// As only the hash of the item is used, this doesn't require the `Clone` trait.
+    pub fn reduce_ref(&mut self, item: &Item)
+    where
+        Item: Hash,
+    {
+        let mut hasher = DefaultHasher::new();
+        item.hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = (hash >> (64 - self.precision)) as usize;
+        let rest = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        if rank > self.registers[index] {
+            self.registers[index] = rank;
+        }
+    }
+    /// Combine the registers of `other` into self.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precisions are different.
+    pub fn merge_from(&mut self, other: Self) {
+        assert_eq!(
+            self.precision, other.precision,
+            "merging estimators with different precisions"
+        );
+        debug_assert_eq!(self.registers.len(), other.registers.len());
+        for (register, other) in self.registers.iter_mut().zip(other.registers) {
+            *register = (*register).max(other);
+        }
+    }
+    /// Combine the registers of `other` into self and return the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the precisions are different.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for ApproxDistinct<Item> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_PRECISION)
+    }
+}
+
+impl<Item> Extend<Item> for ApproxDistinct<Item>
+where
+    Item: Hash,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for ApproxDistinct<Item>
+where
+    Item: Hash,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Item> for ApproxDistinct<Item>
+where
+    Item: Hash,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> std::iter::FromIterator<&'a Item> for ApproxDistinct<Item>
+where
+    Item: Hash,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for ApproxDistinct<Item>
+where
+    Item: Hash,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for ApproxDistinct<Item>
+where
+    Item: Hash,
+{
+    type Item = Item;
+    type Output = u64;
+    type Peek<'a>
+        = f64
+    where
+        Self: 'a;
+    fn peek(&self) -> f64 {
+        self.estimate()
+    }
+    fn finish(self) -> u64 {
+        self.into_inner()
+    }
+}
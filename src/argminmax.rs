@@ -0,0 +1,248 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// The `ArgMax` type uses the [`std::cmp::PartialOrd`] trait to contain the largest iterated
+/// value along with the zero-based index at which it was reduced in.
+///
+/// Each reduced item gets the next index, starting from `0` or from the offset given to
+/// [`ArgMax::with_offset`]. If the largest value shows up more than once, the index of the
+/// first occurrence is kept.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that collects the max f32 and its index.
+/// let mut argmax = ArgMax::<f32>::default();
+///
+/// // We can "reduce-in" individual items, which get index 0:
+/// argmax.reduce(0.5);
+///
+/// // We can then peek at the running output:
+/// println!("Partial argmax is {:?}", argmax.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// argmax.extend(vec![0.25, 2.0, -1.0, 2.0]);
+///
+/// // And finally consume the autofolder to get the final index and value:
+/// assert_eq!(argmax.into_inner(), Some((2, 2.0)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArgMax<Item> {
+    entry: Option<(usize, Item)>,
+    next: usize,
+}
+
+/// The `ArgMin` type uses the [`std::cmp::PartialOrd`] trait to contain the smallest iterated
+/// value along with the zero-based index at which it was reduced in.
+///
+/// Each reduced item gets the next index, starting from `0` or from the offset given to
+/// [`ArgMin::with_offset`]. If the smallest value shows up more than once, the index of the
+/// first occurrence is kept.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that collects the min f32 and its index, resuming the indexes of a
+/// // previous run of 10 items.
+/// let mut argmin = ArgMin::<f32>::with_offset(10);
+///
+/// // We can "reduce-in" individual items, which get index 10:
+/// argmin.reduce(0.5);
+///
+/// // We can then peek at the running output:
+/// println!("Partial argmin is {:?}", argmin.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// argmin.extend(vec![0.25, 2.0, -1.0, -1.0]);
+///
+/// // And finally consume the autofolder to get the final index and value:
+/// assert_eq!(argmin.into_inner(), Some((13, -1.0)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArgMin<Item> {
+    entry: Option<(usize, Item)>,
+    next: usize,
+}
+
+macro_rules! impl_argminmax {
+    ($name: ident, $cmpval: expr) => {
+        impl<Item> $name<Item> {
+            #[doc = concat!("Creates a new empty `", stringify!($name), "`.")]
+            pub fn new() -> Self {
+                Self::with_offset(0)
+            }
+            #[doc = concat!("Creates a new empty `", stringify!($name), "` that assigns indexes starting at `offset`.")]
+            ///
+            /// This can be used to resume the indexes of a previous run.
+            pub fn with_offset(offset: usize) -> Self {
+                Self {
+                    entry: None,
+                    next: offset,
+                }
+            }
+            /// Deconstruct self and return the index and value, if there is one.
+            pub fn into_inner(self) -> Option<(usize, Item)> {
+                self.entry
+            }
+            /// Returns the index and a reference to the value, if there is one.
+            pub fn as_ref(&self) -> Option<(usize, &Item)> {
+                self.entry.as_ref().map(|(index, item)| (*index, item))
+            }
+            /// Returns the index of the value, if there is one.
+            pub fn index(&self) -> Option<usize> {
+                self.entry.as_ref().map(|(index, _)| *index)
+            }
+            /// Returns the index that the next reduced item gets.
+            pub fn next_index(&self) -> usize {
+                self.next
+            }
+            fn replaces(&self, index: usize, item: &Item) -> bool
+            where
+                Item: PartialOrd,
+            {
+                match &self.entry {
+                    None => true,
+                    Some((current_index, current)) => match item.partial_cmp(current) {
+                        Some(core::cmp::Ordering::Equal) => index < *current_index,
+                        ordering => ordering == Some($cmpval),
+                    },
+                }
+            }
+            /// Replaces the current value with the new one if the new one is greater/smaller,
+            /// recording its index.
+            pub fn reduce(&mut self, item: Item)
+            where
+                Item: PartialOrd,
+            {
+                let index = self.next;
+                self.next += 1;
+                if self.replaces(index, &item) {
+                    self.entry = Some((index, item));
+                }
+            }
+            /// Replaces the current value with the one behind the ref if it is greater/smaller,
+            /// recording its index.
+            ///
+            /// This function requires the `Clone` trait, but uses it only if necessary.
+            pub fn reduce_ref(&mut self, item: &Item)
+            where
+                Item: PartialOrd + Clone,
+            {
+                let index = self.next;
+                self.next += 1;
+                if self.replaces(index, item) {
+                    self.entry = Some((index, item.clone()));
+                }
+            }
+            /// Reduce the value of `other` into self, keeping its index.
+            ///
+            #[doc = concat!("The indexes of both sides are compared as-is, so `other` should be created with [`", stringify!($name), "::with_offset`] when the runs are consecutive. The next index of self becomes the largest of both.")]
+            pub fn merge_from(&mut self, other: Self)
+            where
+                Item: PartialOrd,
+            {
+                if let Some((index, item)) = other.entry {
+                    if self.replaces(index, &item) {
+                        self.entry = Some((index, item));
+                    }
+                }
+                self.next = self.next.max(other.next);
+            }
+            /// Reduce the value of `other` into self and return the result.
+            pub fn merge(mut self, other: Self) -> Self
+            where
+                Item: PartialOrd,
+            {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl<Item> Default for $name<Item> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<Item> Extend<Item> for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.reduce(i));
+            }
+        }
+
+        impl<'a, Item> Extend<&'a Item> for $name<Item>
+        where
+            Item: PartialOrd + Clone,
+        {
+            fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.reduce_ref(i));
+            }
+        }
+
+        impl<Item> core::iter::FromIterator<Item> for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a, Item> core::iter::FromIterator<&'a Item> for $name<Item>
+        where
+            Item: PartialOrd + Clone,
+        {
+            fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<Item> FoldItem<Item> for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            fn fold_item(&mut self, item: Item) {
+                self.reduce(item)
+            }
+        }
+
+        impl<Item> Folding for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            type Item = Item;
+            type Output = Option<(usize, Item)>;
+            type Peek<'a>
+                = Option<(usize, &'a Item)>
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Option<(usize, Item)> {
+                self.into_inner()
+            }
+        }
+    };
+}
+
+impl_argminmax!(ArgMax, core::cmp::Ordering::Greater);
+impl_argminmax!(ArgMin, core::cmp::Ordering::Less);
@@ -0,0 +1,204 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// The `ClosestTo` type keeps the item closest to a target, using a struct field for the
+/// distance function.
+///
+/// The distance function gets the target and the item, and returns a distance that is
+/// compared with the [`std::cmp::PartialOrd`] trait. On ties, the first item is kept.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the number closest to 10:
+/// let mut closest = ClosestTo::new(10_i32, |a: &i32, b: &i32| (a - b).abs());
+///
+/// // We can "fold-in" individual items:
+/// closest.fold(3);
+///
+/// // We can then peek at the running output:
+/// println!("Partial closest is {:?}", closest.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// closest.extend(vec![15, 8, 12]);
+///
+/// // And finally consume the autofolder to get the final output value:
+/// println!("Closest is {:?}", closest.into_inner());
+/// ```
+#[derive(Copy, Clone)]
+pub struct ClosestTo<Item, Dist, Func> {
+    target: Item,
+    best: Option<(Item, Dist)>,
+    function: Func,
+}
+
+impl<Item, Dist, Func> ClosestTo<Item, Dist, Func> {
+    /// Creates a new `ClosestTo` with the provided target and distance function.
+    pub const fn new(target: Item, func: Func) -> Self
+    where
+        Func: Fn(&Item, &Item) -> Dist,
+    {
+        Self {
+            target,
+            best: None,
+            function: func,
+        }
+    }
+    /// Returns a reference to the target.
+    pub fn target(&self) -> &Item {
+        &self.target
+    }
+    /// Deconstruct self and return the closest item, if there is one.
+    pub fn into_inner(self) -> Option<Item> {
+        self.best.map(|(item, _)| item)
+    }
+    /// Deconstruct self and return the closest item along with its distance to the target.
+    pub fn into_inner_with_distance(self) -> Option<(Item, Dist)> {
+        self.best
+    }
+    /// Returns a reference to the closest item, if there is one.
+    pub fn as_ref(&self) -> Option<&Item> {
+        self.best.as_ref().map(|(item, _)| item)
+    }
+    /// Returns a reference to the distance of the closest item to the target.
+    pub fn distance(&self) -> Option<&Dist> {
+        self.best.as_ref().map(|(_, dist)| dist)
+    }
+    fn is_closer(&self, dist: &Dist) -> bool
+    where
+        Dist: PartialOrd,
+    {
+        self.best.as_ref().is_none_or(|(_, best)| dist < best)
+    }
+    /// Replaces the current item with the new one if it's closer to the target.
+    pub fn fold(&mut self, item: Item)
+    where
+        Func: Fn(&Item, &Item) -> Dist,
+        Dist: PartialOrd,
+    {
+        let dist = (self.function)(&self.target, &item);
+        if self.is_closer(&dist) {
+            self.best = Some((item, dist));
+        }
+    }
+    /// Replaces the current item with the one behind the ref if it's closer to the target.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if necessary.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Func: Fn(&Item, &Item) -> Dist,
+        Dist: PartialOrd,
+        Item: Clone,
+    {
+        let dist = (self.function)(&self.target, item);
+        if self.is_closer(&dist) {
+            self.best = Some((item.clone(), dist));
+        }
+    }
+}
+
+impl<Item, Dist, Func> core::fmt::Debug for ClosestTo<Item, Dist, Func>
+where
+    Item: core::fmt::Debug,
+    Dist: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ClosestTo::<{}, {}, _> {{ target: {:?}, best: {:?}, function: {} }}",
+            &core::any::type_name::<Item>(),
+            &core::any::type_name::<Dist>(),
+            self.target,
+            self.best,
+            &core::any::type_name::<Func>(),
+        )
+    }
+}
+
+impl<Item, Dist, Func> Extend<Item> for ClosestTo<Item, Dist, Func>
+where
+    Func: Fn(&Item, &Item) -> Dist,
+    Dist: PartialOrd,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item, Dist, Func> Extend<&'a Item> for ClosestTo<Item, Dist, Func>
+where
+    Func: Fn(&Item, &Item) -> Dist,
+    Dist: PartialOrd,
+    Item: Clone + 'a,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+/// Returns the Levenshtein edit distance between two strings, counted in `char`s.
+///
+/// This can be used as the distance function of [`ClosestTo`] for "did you mean"-style
+/// selection:
+/// ```
+/// use autofolder::*;
+///
+/// let mut closest = ClosestTo::new("fold", levenshtein);
+/// closest.extend(vec!["find", "old", "folder"]);
+/// assert_eq!(closest.into_inner(), Some("old"));
+/// ```
+#[cfg(feature = "levenshtein")]
+pub fn levenshtein<S>(a: &S, b: &S) -> usize
+where
+    S: AsRef<str> + ?Sized,
+{
+    let a = a.as_ref().chars().collect::<Vec<_>>();
+    let mut row = (0..=a.len()).collect::<Vec<_>>();
+    for (j, cb) in b.as_ref().chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = j + 1;
+        for (i, ca) in a.iter().enumerate() {
+            let subst = diag + usize::from(*ca != cb);
+            diag = row[i + 1];
+            row[i + 1] = subst.min(row[i] + 1).min(diag + 1);
+        }
+    }
+    row[a.len()]
+}
+
+impl<Item, Dist, Func> FoldItem<Item> for ClosestTo<Item, Dist, Func>
+where
+    Func: Fn(&Item, &Item) -> Dist,
+    Dist: PartialOrd,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item, Dist, Func> Folding for ClosestTo<Item, Dist, Func>
+where
+    Func: Fn(&Item, &Item) -> Dist,
+    Dist: PartialOrd,
+{
+    type Item = Item;
+    type Output = Option<Item>;
+    type Peek<'a>
+        = Option<&'a Item>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<Item> {
+        self.into_inner()
+    }
+}
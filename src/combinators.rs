@@ -0,0 +1,282 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use core::marker;
+
+/// Extension trait with combinators that adapt the items before they reach an autofolder.
+///
+/// It's implemented for all autofolders. It also provides [`FoldingExt::fold_opt`] and
+/// [`FoldingExt::extend_flatten`], that fold [`Option`]s in place, skipping the `None`s - which
+/// is convenient after fallible parsing with `.ok()`.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// struct LogRecord {
+///     level: u8,
+///     latency: f64,
+/// }
+///
+/// // Create an autofolder that gets the max latency of the records with level 2 or above:
+/// let mut max = Max::<f64>::default()
+///     .premap(|record: LogRecord| record.latency)
+///     .filter(|record: &LogRecord| record.level >= 2);
+///
+/// // The adapted autofolder works like the inner one:
+/// max.fold(LogRecord { level: 1, latency: 0.5 });
+/// max.extend(vec![
+///     LogRecord { level: 3, latency: 0.25 },
+///     LogRecord { level: 2, latency: 0.125 },
+/// ]);
+///
+/// // And we can get the inner autofolder back:
+/// let max = max.into_inner().into_inner();
+/// assert_eq!(max.into_inner(), Some(0.25));
+/// ```
+pub trait FoldingExt: Folding + Sized {
+    /// Returns an autofolder that transforms the items with `func` before folding them into
+    /// self.
+    fn premap<Raw, Func>(self, func: Func) -> Premap<Self, Raw, Func>
+    where
+        Func: FnMut(Raw) -> Self::Item,
+    {
+        Premap {
+            folder: self,
+            func,
+            raw: marker::PhantomData,
+        }
+    }
+    /// Returns an autofolder that folds into self only the items for which `predicate`
+    /// returns `true`.
+    fn filter<Func>(self, predicate: Func) -> Filter<Self, Func>
+    where
+        Func: FnMut(&Self::Item) -> bool,
+    {
+        Filter {
+            folder: self,
+            predicate,
+        }
+    }
+    /// Folds the item into self, if there is one.
+    ///
+    /// Example:
+    /// ```
+    /// use autofolder::*;
+    ///
+    /// let mut max = Max::<u32>::default();
+    /// max.fold_opt("7".parse().ok());
+    /// max.fold_opt("x".parse().ok());
+    /// assert_eq!(max.into_inner(), Some(7));
+    /// ```
+    fn fold_opt(&mut self, item: Option<Self::Item>) {
+        if let Some(item) = item {
+            self.fold_item(item);
+        }
+    }
+    /// Folds the items of the iterator into self, skipping the `None`s.
+    ///
+    /// Example:
+    /// ```
+    /// use autofolder::*;
+    ///
+    /// let mut sum = DynFolder::new(0, |a, i| a + i);
+    /// sum.extend_flatten("1 x 2 3".split(' ').map(|s| s.parse::<u32>().ok()));
+    /// assert_eq!(sum.into_inner(), 6);
+    /// ```
+    fn extend_flatten<It>(&mut self, iter: It)
+    where
+        It: IntoIterator<Item = Option<Self::Item>>,
+    {
+        iter.into_iter()
+            .flatten()
+            .for_each(|item| self.fold_item(item));
+    }
+}
+
+impl<F> FoldingExt for F where F: Folding {}
+
+/// Autofolder returned by [`FoldingExt::premap`].
+pub struct Premap<F, Raw, Func> {
+    folder: F,
+    func: Func,
+    raw: marker::PhantomData<fn(Raw)>,
+}
+
+impl<F, Raw, Func> Premap<F, Raw, Func> {
+    /// Deconstruct self and return the inner autofolder.
+    pub fn into_inner(self) -> F {
+        self.folder
+    }
+    /// Transforms an individual item and folds it into the inner autofolder.
+    pub fn fold(&mut self, item: Raw)
+    where
+        F: Folding,
+        Func: FnMut(Raw) -> F::Item,
+    {
+        self.folder.fold_item((self.func)(item))
+    }
+}
+
+impl<F, Raw, Func> AsRef<F> for Premap<F, Raw, Func> {
+    fn as_ref(&self) -> &F {
+        &self.folder
+    }
+}
+
+impl<F, Raw, Func> Clone for Premap<F, Raw, Func>
+where
+    F: Clone,
+    Func: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            folder: self.folder.clone(),
+            func: self.func.clone(),
+            raw: marker::PhantomData,
+        }
+    }
+}
+
+impl<F, Raw, Func> core::fmt::Debug for Premap<F, Raw, Func>
+where
+    F: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Premap::<_, {}, _> {{ folder: {:?}, func: {} }}",
+            &core::any::type_name::<Raw>(),
+            self.folder,
+            &core::any::type_name::<Func>(),
+        )
+    }
+}
+
+impl<F, Raw, Func> Extend<Raw> for Premap<F, Raw, Func>
+where
+    F: Folding,
+    Func: FnMut(Raw) -> F::Item,
+{
+    fn extend<It: IntoIterator<Item = Raw>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<F, Raw, Func> FoldItem<Raw> for Premap<F, Raw, Func>
+where
+    F: Folding,
+    Func: FnMut(Raw) -> F::Item,
+{
+    fn fold_item(&mut self, item: Raw) {
+        self.fold(item)
+    }
+}
+
+impl<F, Raw, Func> Folding for Premap<F, Raw, Func>
+where
+    F: Folding,
+    Func: FnMut(Raw) -> F::Item,
+{
+    type Item = Raw;
+    type Output = F::Output;
+    type Peek<'a>
+        = F::Peek<'a>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.folder.peek()
+    }
+    fn finish(self) -> F::Output {
+        self.folder.finish()
+    }
+}
+
+/// Autofolder returned by [`FoldingExt::filter`].
+#[derive(Clone)]
+pub struct Filter<F, Func> {
+    folder: F,
+    predicate: Func,
+}
+
+impl<F, Func> Filter<F, Func> {
+    /// Deconstruct self and return the inner autofolder.
+    pub fn into_inner(self) -> F {
+        self.folder
+    }
+    /// Folds an individual item into the inner autofolder, if the predicate returns `true`.
+    pub fn fold(&mut self, item: F::Item)
+    where
+        F: Folding,
+        Func: FnMut(&F::Item) -> bool,
+    {
+        if (self.predicate)(&item) {
+            self.folder.fold_item(item)
+        }
+    }
+}
+
+impl<F, Func> AsRef<F> for Filter<F, Func> {
+    fn as_ref(&self) -> &F {
+        &self.folder
+    }
+}
+
+impl<F, Func> core::fmt::Debug for Filter<F, Func>
+where
+    F: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Filter {{ folder: {:?}, predicate: {} }}",
+            self.folder,
+            &core::any::type_name::<Func>(),
+        )
+    }
+}
+
+impl<F, Func> Extend<F::Item> for Filter<F, Func>
+where
+    F: Folding,
+    Func: FnMut(&F::Item) -> bool,
+{
+    fn extend<It: IntoIterator<Item = F::Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<F, Func> FoldItem<F::Item> for Filter<F, Func>
+where
+    F: Folding,
+    Func: FnMut(&F::Item) -> bool,
+{
+    fn fold_item(&mut self, item: F::Item) {
+        self.fold(item)
+    }
+}
+
+impl<F, Func> Folding for Filter<F, Func>
+where
+    F: Folding,
+    Func: FnMut(&F::Item) -> bool,
+{
+    type Item = F::Item;
+    type Output = F::Output;
+    type Peek<'a>
+        = F::Peek<'a>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.folder.peek()
+    }
+    fn finish(self) -> F::Output {
+        self.folder.finish()
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// The `CountDistinct` type folds items into a [`HashSet`] to count the distinct ones.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that counts the distinct words.
+/// let mut distinct = CountDistinct::<&str>::default();
+///
+/// // We can "fold-in" individual items:
+/// distinct.fold("to");
+///
+/// // We can then peek at the running count:
+/// println!("Partial count is {}", distinct.count());
+///
+/// // And still keep on folding by processing whole iterators:
+/// distinct.extend("be or not to be".split(' '));
+///
+/// // And finally consume the autofolder to get the number of distinct items:
+/// assert_eq!(distinct.into_inner(), 4);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CountDistinct<Item> {
+    set: HashSet<Item>,
+}
+
+impl<Item> CountDistinct<Item> {
+    /// Creates a new empty `CountDistinct`.
+    pub fn new() -> Self {
+        Self {
+            set: HashSet::new(),
+        }
+    }
+    /// Returns the number of distinct items folded in.
+    pub fn count(&self) -> usize {
+        self.set.len()
+    }
+    /// Deconstruct self and return the number of distinct items folded in.
+    pub fn into_inner(self) -> usize {
+        self.count()
+    }
+    /// Deconstruct self and return the set of distinct items.
+    pub fn into_set(self) -> HashSet<Item> {
+        self.set
+    }
+    /// Folds an individual item into self.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Eq + Hash,
+    {
+        self.set.insert(item);
+    }
+    /// Folds in the item behind the reference.
+    ///
+    /// This function requires the `Clone` trait, but uses it only for items not seen before.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Eq + Hash + Clone,
+    {
+        if !self.set.contains(item) {
+            self.set.insert(item.clone());
+        }
+    }
+    /// Folds the distinct items of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Eq + Hash,
+    {
+        if other.set.len() > self.set.len() {
+            let set = std::mem::replace(&mut self.set, other.set);
+            self.set.extend(set);
+        } else {
+            self.set.extend(other.set);
+        }
+    }
+    /// Folds the distinct items of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Eq + Hash,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for CountDistinct<Item> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> From<HashSet<Item>> for CountDistinct<Item> {
+    fn from(set: HashSet<Item>) -> Self {
+        Self { set }
+    }
+}
+
+impl<Item> AsRef<HashSet<Item>> for CountDistinct<Item> {
+    fn as_ref(&self) -> &HashSet<Item> {
+        &self.set
+    }
+}
+
+impl<Item> Extend<Item> for CountDistinct<Item>
+where
+    Item: Eq + Hash,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for CountDistinct<Item>
+where
+    Item: Eq + Hash + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Item> for CountDistinct<Item>
+where
+    Item: Eq + Hash,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> std::iter::FromIterator<&'a Item> for CountDistinct<Item>
+where
+    Item: Eq + Hash + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for CountDistinct<Item>
+where
+    Item: Eq + Hash,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item> Folding for CountDistinct<Item>
+where
+    Item: Eq + Hash,
+{
+    type Item = Item;
+    type Output = usize;
+    type Peek<'a>
+        = usize
+    where
+        Self: 'a;
+    fn peek(&self) -> usize {
+        self.count()
+    }
+    fn finish(self) -> usize {
+        self.into_inner()
+    }
+}
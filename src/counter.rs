@@ -0,0 +1,214 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The `Counter` type counts the occurrences of each item in a [`HashMap`].
+///
+/// This is the autofolder version of Python's `collections.Counter`. Unlike [`Mode`](crate::Mode),
+/// it keeps only the counts, and returns all of them.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that counts chars.
+/// let mut counter = Counter::<char>::default();
+///
+/// // We can "fold-in" individual items:
+/// counter.fold('b');
+///
+/// // We can then peek at the running counts:
+/// println!("Partial count of 'b' is {}", counter.count(&'b'));
+///
+/// // And still keep on folding by processing whole iterators:
+/// counter.extend("abracadabra".chars());
+/// assert_eq!(counter.most_common(2), vec![(&'a', 5), (&'b', 3)]);
+///
+/// // And finally consume the autofolder to get the map with the counts:
+/// let counts = counter.into_inner();
+/// assert_eq!(counts[&'r'], 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Counter<Item> {
+    counts: HashMap<Item, usize>,
+    total: usize,
+}
+
+impl<Item> Counter<Item> {
+    /// Creates a new empty `Counter`.
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+            total: 0,
+        }
+    }
+    /// Returns the number of distinct items folded in.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+    /// Returns `true` if no item was folded in.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+    /// Returns the total number of items folded in.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+    /// Returns the number of occurrences of the given item.
+    pub fn count(&self, item: &Item) -> usize
+    where
+        Item: Eq + Hash,
+    {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+    /// Returns references to the `n` most frequent items and their counts, in descending
+    /// order of count.
+    ///
+    /// The order of items with the same count is unspecified.
+    pub fn most_common(&self, n: usize) -> Vec<(&Item, usize)> {
+        let mut entries = self
+            .counts
+            .iter()
+            .map(|(item, count)| (item, *count))
+            .collect::<Vec<_>>();
+        entries.sort_by(|(_, a), (_, b)| b.cmp(a));
+        entries.truncate(n);
+        entries
+    }
+    /// Deconstruct self and return the map with the counts.
+    pub fn into_inner(self) -> HashMap<Item, usize> {
+        self.counts
+    }
+    /// Counts an individual item.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Eq + Hash,
+    {
+        self.add(item, 1);
+    }
+    /// Counts the item behind the reference.
+    ///
+    /// This function requires the `Clone` trait, but uses it only for items not seen before.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Eq + Hash + Clone,
+    {
+        if let Some(count) = self.counts.get_mut(item) {
+            *count += 1;
+            self.total += 1;
+        } else {
+            self.add(item.clone(), 1);
+        }
+    }
+    fn add(&mut self, item: Item, count: usize)
+    where
+        Item: Eq + Hash,
+    {
+        *self.counts.entry(item).or_insert(0) += count;
+        self.total += count;
+    }
+    /// Adds the counts of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Eq + Hash,
+    {
+        for (item, count) in other.counts {
+            self.add(item, count);
+        }
+    }
+    /// Adds the counts of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Eq + Hash,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> AsRef<HashMap<Item, usize>> for Counter<Item> {
+    fn as_ref(&self) -> &HashMap<Item, usize> {
+        &self.counts
+    }
+}
+
+impl<Item> Default for Counter<Item> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> Extend<Item> for Counter<Item>
+where
+    Item: Eq + Hash,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for Counter<Item>
+where
+    Item: Eq + Hash + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Item> for Counter<Item>
+where
+    Item: Eq + Hash,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> std::iter::FromIterator<&'a Item> for Counter<Item>
+where
+    Item: Eq + Hash + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for Counter<Item>
+where
+    Item: Eq + Hash,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item> Folding for Counter<Item>
+where
+    Item: Eq + Hash,
+{
+    type Item = Item;
+    type Output = HashMap<Item, usize>;
+    type Peek<'a>
+        = &'a HashMap<Item, usize>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> HashMap<Item, usize> {
+        self.into_inner()
+    }
+}
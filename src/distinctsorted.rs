@@ -0,0 +1,231 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::btree_set;
+use std::collections::BTreeSet;
+use std::ops::RangeBounds;
+
+/// The `DistinctSorted` type folds items into a [`BTreeSet`], keeping only the distinct
+/// values in ascending order, as given by [`std::cmp::Ord`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that collects distinct usize items:
+/// let mut distinct = DistinctSorted::<usize>::default();
+///
+/// // We can "fold-in" individual items:
+/// distinct.fold(3);
+///
+/// // And keep on folding by processing whole iterators:
+/// distinct.extend([5, 1, 3, 5]);
+///
+/// // We can then query the running set:
+/// assert!(distinct.contains(&5));
+/// println!("Items in 2..=4: {:?}", distinct.range(2..=4).collect::<Vec<_>>());
+///
+/// // And finally consume the autofolder to get the items in order:
+/// println!("Distinct items are {:?}", distinct.into_vec());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DistinctSorted<Item> {
+    set: BTreeSet<Item>,
+}
+
+impl<Item> DistinctSorted<Item> {
+    /// Creates a new empty `DistinctSorted`.
+    pub const fn new() -> Self
+    where
+        Item: Ord,
+    {
+        Self {
+            set: BTreeSet::new(),
+        }
+    }
+    /// Deconstruct self and return the inner set.
+    pub fn into_inner(self) -> BTreeSet<Item> {
+        self.set
+    }
+    /// Deconstruct self and return the distinct items in ascending order.
+    pub fn into_vec(self) -> Vec<Item> {
+        self.set.into_iter().collect()
+    }
+    /// Returns the number of distinct items.
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+    /// Returns `true` if no item was folded in.
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+    /// Returns `true` if the item was folded in.
+    pub fn contains(&self, item: &Item) -> bool
+    where
+        Item: Ord,
+    {
+        self.set.contains(item)
+    }
+    /// Returns the smallest item, if there is one.
+    pub fn first(&self) -> Option<&Item>
+    where
+        Item: Ord,
+    {
+        self.set.first()
+    }
+    /// Returns the largest item, if there is one.
+    pub fn last(&self) -> Option<&Item>
+    where
+        Item: Ord,
+    {
+        self.set.last()
+    }
+    /// Returns an iterator over the distinct items in ascending order.
+    pub fn iter(&self) -> btree_set::Iter<'_, Item> {
+        self.set.iter()
+    }
+    /// Returns an iterator over the distinct items within the range, in ascending order.
+    pub fn range<R>(&self, range: R) -> btree_set::Range<'_, Item>
+    where
+        Item: Ord,
+        R: RangeBounds<Item>,
+    {
+        self.set.range(range)
+    }
+    /// Folds an individual value into self.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Ord,
+    {
+        self.set.insert(item);
+    }
+    /// Folds in the value behind the reference, if it's not already present.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if necessary.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Ord + Clone,
+    {
+        if !self.set.contains(item) {
+            self.set.insert(item.clone());
+        }
+    }
+    /// Folds all items of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Ord,
+    {
+        let mut other = other.set;
+        self.set.append(&mut other);
+    }
+    /// Folds all items of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Ord,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for DistinctSorted<Item>
+where
+    Item: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> From<BTreeSet<Item>> for DistinctSorted<Item> {
+    fn from(set: BTreeSet<Item>) -> Self {
+        Self { set }
+    }
+}
+
+impl<Item> AsRef<BTreeSet<Item>> for DistinctSorted<Item> {
+    fn as_ref(&self) -> &BTreeSet<Item> {
+        &self.set
+    }
+}
+
+impl<Item> Extend<Item> for DistinctSorted<Item>
+where
+    Item: Ord,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for DistinctSorted<Item>
+where
+    Item: Ord + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Item> for DistinctSorted<Item>
+where
+    Item: Ord,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> std::iter::FromIterator<&'a Item> for DistinctSorted<Item>
+where
+    Item: Ord + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> IntoIterator for &'a DistinctSorted<Item> {
+    type Item = &'a Item;
+    type IntoIter = btree_set::Iter<'a, Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.set.iter()
+    }
+}
+
+impl<Item> FoldItem<Item> for DistinctSorted<Item>
+where
+    Item: Ord,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item> Folding for DistinctSorted<Item>
+where
+    Item: Ord,
+{
+    type Item = Item;
+    type Output = BTreeSet<Item>;
+    type Peek<'a>
+        = &'a BTreeSet<Item>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> BTreeSet<Item> {
+        self.into_inner()
+    }
+}
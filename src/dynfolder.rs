@@ -5,8 +5,26 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 
-use std::marker;
-use std::mem;
+use crate::FoldItem;
+use crate::Folding;
+use core::marker;
+
+const POISONED: &str = "DynFolder poisoned by a panicking folding function";
+
+/// Shows the output, or that there is none due to poisoning, in `Debug` implementations.
+pub(crate) struct DebugOutput<'a, Output>(pub(crate) &'a Option<Output>);
+
+impl<Output> core::fmt::Debug for DebugOutput<'_, Output>
+where
+    Output: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Some(output) => output.fmt(f),
+            None => write!(f, "<poisoned>"),
+        }
+    }
+}
 
 /// The `DynFolder` type uses a struct field for the folding function.
 ///
@@ -39,79 +57,330 @@ use std::mem;
 /// // And finally consume the autofolder to get the final output value:
 /// println!("Total sum is {}", sum.into_inner());
 /// ```
+///
+/// If the folding function panics, the output that was moved into it is lost and the
+/// `DynFolder` becomes *poisoned*: [`DynFolder::is_poisoned`] returns `true` and all other
+/// methods panic, except for [`DynFolder::reset_with`], which recovers it.
+///
+/// An observer can be attached with [`DynFolder::with_observer`] to get called with the
+/// running output after every fold.
 #[derive(Copy, Clone)]
-pub struct DynFolder<Output, Item, Func> {
-    output: Output,
+pub struct DynFolder<Output, Item, Func, Observer = fn(&Output)> {
+    output: Option<Output>,
+    count: usize,
     function: Func,
+    observer: Option<Observer>,
     item: marker::PhantomData<Item>,
 }
 
 impl<Output, Item, Func> DynFolder<Output, Item, Func> {
     /// Creates a new `DynFolder` with the provided initial value and folding function.
-    pub fn new(initial: Output, func: Func) -> Self
+    pub const fn new(initial: Output, func: Func) -> Self
     where
         Func: Fn(Output, Item) -> Output,
     {
         Self {
-            output: initial,
+            output: Some(initial),
+            count: 0,
             function: func,
+            observer: None,
+            item: marker::PhantomData,
+        }
+    }
+    /// Creates a new `DynFolder` from a state previously returned by
+    /// [`DynFolder::into_state`] and the folding function.
+    ///
+    /// The count of items is not part of the state, and starts from zero.
+    pub const fn from_state(state: Output, func: Func) -> Self
+    where
+        Func: Fn(Output, Item) -> Output,
+    {
+        Self::new(state, func)
+    }
+    /// Creates a new `DynFolder` from an output and a folding function, as returned by
+    /// [`DynFolder::into_parts`].
+    pub const fn from_parts(output: Output, func: Func) -> Self
+    where
+        Func: Fn(Output, Item) -> Output,
+    {
+        Self::new(output, func)
+    }
+}
+
+/// A [`DynFolder`] with a boxed folding function, which can be sent to other threads.
+///
+/// As each closure has a distinct type, `DynFolder`s with different closures can't be stored
+/// together; boxing the function erases its type, so that they can. Use
+/// [`DynFolder::boxed`] to create instances.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut folders: Vec<BoxedDynFolder<u32, u32>> = vec![
+///     DynFolder::boxed(0, |a, b| a + b),
+///     DynFolder::boxed(0, std::cmp::max),
+/// ];
+/// for folder in &mut folders {
+///     folder.extend(vec![3, 5, 1]);
+/// }
+/// let outputs = folders.into_iter().map(|f| f.into_inner()).collect::<Vec<_>>();
+/// assert_eq!(outputs, vec![9, 5]);
+/// ```
+#[cfg(feature = "std")]
+pub type BoxedDynFolder<Output, Item> =
+    DynFolder<Output, Item, Box<dyn Fn(Output, Item) -> Output + Send>>;
+
+/// A [`DynFolder`] with a boxed folding function, which can be sent to and shared between
+/// threads.
+///
+/// This is the `Sync` variant of [`BoxedDynFolder`]; use [`DynFolder::boxed_sync`] to create
+/// instances.
+#[cfg(feature = "std")]
+pub type BoxedSyncDynFolder<Output, Item> =
+    DynFolder<Output, Item, Box<dyn Fn(Output, Item) -> Output + Send + Sync>>;
+
+#[cfg(feature = "std")]
+impl<Output, Item> BoxedDynFolder<Output, Item> {
+    /// Creates a new [`BoxedDynFolder`] with the provided initial value and folding function,
+    /// which gets boxed.
+    pub fn boxed<F>(initial: Output, func: F) -> Self
+    where
+        F: Fn(Output, Item) -> Output + Send + 'static,
+    {
+        Self::new(initial, Box::new(func))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Output, Item> BoxedSyncDynFolder<Output, Item> {
+    /// Creates a new [`BoxedSyncDynFolder`] with the provided initial value and folding
+    /// function, which gets boxed.
+    pub fn boxed_sync<F>(initial: Output, func: F) -> Self
+    where
+        F: Fn(Output, Item) -> Output + Send + Sync + 'static,
+    {
+        Self::new(initial, Box::new(func))
+    }
+}
+
+impl<Output, Item, Func, Observer> DynFolder<Output, Item, Func, Observer> {
+    /// Sets the observer, a function that gets called with the output after every fold,
+    /// replacing the previous one.
+    ///
+    /// The observer is not called by [`DynFolder::map_inner`].
+    ///
+    /// Example:
+    /// ```
+    /// use autofolder::*;
+    ///
+    /// let mut partials = vec![];
+    /// let mut sum = DynFolder::new(0, |a, b| a + b).with_observer(|sum: &u32| partials.push(*sum));
+    /// sum.extend(1..=4);
+    /// assert_eq!(sum.into_inner(), 10);
+    /// assert_eq!(partials, vec![1, 3, 6, 10]);
+    /// ```
+    pub fn with_observer<NewObserver>(
+        self,
+        observer: NewObserver,
+    ) -> DynFolder<Output, Item, Func, NewObserver>
+    where
+        NewObserver: FnMut(&Output),
+    {
+        DynFolder {
+            output: self.output,
+            count: self.count,
+            function: self.function,
+            observer: Some(observer),
             item: marker::PhantomData,
         }
     }
+    /// Returns the number of items folded in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no item was folded in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
     /// Returns the contained value, consuming the self value.
     pub fn into_inner(self) -> Output {
-        self.output
+        self.output.expect(POISONED)
+    }
+    /// Returns `true` if a previous call to the folding function panicked.
+    pub fn is_poisoned(&self) -> bool {
+        self.output.is_none()
+    }
+    /// Replaces the output with the provided initial value, resetting the count of items, and
+    /// returns the previous output, or `None` if self was poisoned.
+    ///
+    /// The folding function and the observer are kept, which allows reusing self for another
+    /// batch of items.
+    pub fn reset_with(&mut self, initial: Output) -> Option<Output> {
+        self.count = 0;
+        self.output.replace(initial)
+    }
+    /// Deconstruct self and return its state, dropping the folding function.
+    ///
+    /// The state can be checkpointed (for instance, with the `serde` crate) and later restored
+    /// with [`DynFolder::from_state`] alongside a freshly supplied function.
+    pub fn into_state(self) -> Output {
+        self.into_inner()
+    }
+    /// Deconstruct self and return both the output and the folding function.
+    ///
+    /// Along with [`DynFolder::from_parts`], this allows swapping the folding function
+    /// mid-stream. The observer is dropped.
+    pub fn into_parts(self) -> (Output, Func) {
+        (self.output.expect(POISONED), self.function)
     }
-    /// Folds an individual value into self.
+    /// Transforms the output in place with the provided function.
+    ///
+    /// As with the folding function, self becomes poisoned if `func` panics.
+    pub fn map_inner<F>(&mut self, func: F)
+    where
+        F: FnOnce(Output) -> Output,
+    {
+        let current_output = self.output.take().expect(POISONED);
+        self.output = Some(func(current_output));
+    }
+    /// Folds an individual value into self, and then calls the observer, if any.
     pub fn fold(&mut self, item: Item)
     where
         Func: Fn(Output, Item) -> Output,
+        Observer: FnMut(&Output),
+    {
+        // We move the current output to the folding function, leaving `None` behind, which
+        // marks self as poisoned if the function panics:
+        let current_output = self.output.take().expect(POISONED);
+        self.output = Some((self.function)(current_output, item));
+        self.count += 1;
+        if let (Some(observer), Some(output)) = (&mut self.observer, &self.output) {
+            observer(output);
+        }
+    }
+    /// Folds the value behind the ref into self.
+    ///
+    /// This function requires the `Clone` trait, as the folding function takes ownership of
+    /// the item.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Func: Fn(Output, Item) -> Output,
+        Observer: FnMut(&Output),
+        Item: Clone,
     {
-        // SAFETY: we move out current output to the folding function;
-        // to do that, we replace it with an uninitialized value.
-        // This is safe because we immediately put back the new value
-        // returned by the folding function.
-        #[allow(clippy::uninit_assumed_init)]
-        let uninit = unsafe { mem::MaybeUninit::<Output>::uninit().assume_init() };
-        let current_output = mem::replace(&mut self.output, uninit);
-        // self.0 now has the uninitalized value
-        // Give ownership of current_output to self.function, and get new_output:
-        let new_output = (self.function)(current_output, item);
-        // Put new_output in self.0 and get the old uninit back:
-        let uninit = mem::replace(&mut self.output, new_output);
-        // We need to mem::forget it to avoid running destructors on
-        // the uninitialized value:
-        mem::forget(uninit);
+        self.fold(item.clone())
+    }
+    fn fold_iter<It>(&mut self, iter: It)
+    where
+        It: Iterator<Item = Item>,
+        Func: Fn(Output, Item) -> Output,
+        Observer: FnMut(&Output),
+    {
+        // We move the current output out only once, and let the iterator drive the fold, which
+        // allows the optimizer to keep the output in registers. If the folding function
+        // panics, the `None` left behind marks self as poisoned, as in `fold`:
+        let current_output = self.output.take().expect(POISONED);
+        let function = &self.function;
+        let count = &mut self.count;
+        let output = match &mut self.observer {
+            None => iter.fold(current_output, |output, item| {
+                let output = function(output, item);
+                *count += 1;
+                output
+            }),
+            Some(observer) => iter.fold(current_output, |output, item| {
+                let output = function(output, item);
+                *count += 1;
+                observer(&output);
+                output
+            }),
+        };
+        self.output = Some(output);
     }
 }
 
-impl<Output, Item, Func> std::fmt::Debug for DynFolder<Output, Item, Func>
+impl<Output, Item, Func, Observer> core::fmt::Debug for DynFolder<Output, Item, Func, Observer>
 where
-    Output: std::fmt::Debug,
+    Output: core::fmt::Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "DynFolder::<{}, {}, _> {{ output: {:?}, function: {} }}",
-            &std::any::type_name::<Output>(),
-            &std::any::type_name::<Item>(),
-            self.output,
-            &std::any::type_name::<Func>(),
+            "DynFolder::<{}, {}, _, _> {{ output: {:?}, count: {}, function: {}, observer: {} }}",
+            &core::any::type_name::<Output>(),
+            &core::any::type_name::<Item>(),
+            DebugOutput(&self.output),
+            self.count,
+            &core::any::type_name::<Func>(),
+            &core::any::type_name::<Observer>(),
         )
     }
 }
 
-impl<Output, Item, Func> AsRef<Output> for DynFolder<Output, Item, Func> {
+impl<Output, Item, Func, Observer> AsRef<Output> for DynFolder<Output, Item, Func, Observer> {
     fn as_ref(&self) -> &Output {
-        &self.output
+        self.output.as_ref().expect(POISONED)
     }
 }
 
-impl<Output, Item, Func> Extend<Item> for DynFolder<Output, Item, Func>
+impl<Output, Item, Func, Observer> Extend<Item> for DynFolder<Output, Item, Func, Observer>
 where
     Func: Fn(Output, Item) -> Output,
+    Observer: FnMut(&Output),
 {
     fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
-        iter.into_iter().for_each(|i| self.fold(i));
+        self.fold_iter(iter.into_iter());
+    }
+}
+
+impl<'a, Output, Item, Func, Observer> Extend<&'a Item> for DynFolder<Output, Item, Func, Observer>
+where
+    Func: Fn(Output, Item) -> Output,
+    Observer: FnMut(&Output),
+    Item: Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        self.fold_iter(iter.into_iter().cloned());
+    }
+}
+
+impl<Output, Item, Func, Observer> FoldItem<Item> for DynFolder<Output, Item, Func, Observer>
+where
+    Func: Fn(Output, Item) -> Output,
+    Observer: FnMut(&Output),
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Output, Item, Func, Observer> core::ops::AddAssign<Item>
+    for DynFolder<Output, Item, Func, Observer>
+where
+    Func: Fn(Output, Item) -> Output,
+    Observer: FnMut(&Output),
+{
+    fn add_assign(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Output, Item, Func, Observer> Folding for DynFolder<Output, Item, Func, Observer>
+where
+    Func: Fn(Output, Item) -> Output,
+    Observer: FnMut(&Output),
+{
+    type Item = Item;
+    type Output = Output;
+    type Peek<'a>
+        = &'a Output
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Output {
+        self.into_inner()
     }
 }
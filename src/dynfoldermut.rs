@@ -0,0 +1,133 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use core::marker;
+
+/// The `DynFolderMut` type uses a struct field for a folding function that mutates the output
+/// in place.
+///
+/// This is essentially a [`DynFolder`](crate::DynFolder) whose function has the
+/// `FnMut(&mut Output, Item)` signature:
+/// - The output is never moved through the folding function, which is cheaper for large
+///   `Output` types.
+/// - The folding function can be `FnMut`, and thus keep auxiliary state of its own.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that pushes `u16` items into a `Vec`, counting the calls.
+/// let mut calls = 0;
+/// let mut vec = DynFolderMut::<Vec<u16>, u16, _>::new(vec![], |v, i| {
+///     calls += 1;
+///     v.push(i)
+/// });
+///
+/// // We can "fold-in" individual items:
+/// vec.fold(3);
+///
+/// // We can then peek at the running output:
+/// println!("Partial vec is {:?}", vec.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// vec.extend((1..=5));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// println!("Final vec is {:?}", vec.into_inner());
+/// assert_eq!(calls, 6);
+/// ```
+#[derive(Copy, Clone)]
+pub struct DynFolderMut<Output, Item, Func> {
+    output: Output,
+    function: Func,
+    item: marker::PhantomData<Item>,
+}
+
+impl<Output, Item, Func> DynFolderMut<Output, Item, Func> {
+    /// Creates a new `DynFolderMut` with the provided initial value and folding function.
+    pub const fn new(initial: Output, func: Func) -> Self
+    where
+        Func: FnMut(&mut Output, Item),
+    {
+        Self {
+            output: initial,
+            function: func,
+            item: marker::PhantomData,
+        }
+    }
+    /// Returns the contained value, consuming the self value.
+    pub fn into_inner(self) -> Output {
+        self.output
+    }
+    /// Folds an individual value into self.
+    pub fn fold(&mut self, item: Item)
+    where
+        Func: FnMut(&mut Output, Item),
+    {
+        (self.function)(&mut self.output, item);
+    }
+}
+
+impl<Output, Item, Func> core::fmt::Debug for DynFolderMut<Output, Item, Func>
+where
+    Output: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DynFolderMut::<{}, {}, _> {{ output: {:?}, function: {} }}",
+            &core::any::type_name::<Output>(),
+            &core::any::type_name::<Item>(),
+            self.output,
+            &core::any::type_name::<Func>(),
+        )
+    }
+}
+
+impl<Output, Item, Func> AsRef<Output> for DynFolderMut<Output, Item, Func> {
+    fn as_ref(&self) -> &Output {
+        &self.output
+    }
+}
+
+impl<Output, Item, Func> Extend<Item> for DynFolderMut<Output, Item, Func>
+where
+    Func: FnMut(&mut Output, Item),
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<Output, Item, Func> FoldItem<Item> for DynFolderMut<Output, Item, Func>
+where
+    Func: FnMut(&mut Output, Item),
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Output, Item, Func> Folding for DynFolderMut<Output, Item, Func>
+where
+    Func: FnMut(&mut Output, Item),
+{
+    type Item = Item;
+    type Output = Output;
+    type Peek<'a>
+        = &'a Output
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Output {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::dynfolder::DebugOutput;
+use crate::FoldItem;
+use crate::Folding;
+use core::marker;
+use core::ops::ControlFlow;
+
+const POISONED: &str = "DynFolderUntil poisoned by a panicking folding function";
+
+/// The `DynFolderUntil` type uses a struct field for a folding function that can terminate the
+/// folding early.
+///
+/// This is essentially a [`DynFolder`](crate::DynFolder) whose function returns a
+/// [`ControlFlow`]: [`ControlFlow::Continue`] with the new output to keep on folding, or
+/// [`ControlFlow::Break`] with the final output once a terminal state is reached. After that,
+/// further items are ignored and [`Extend::extend`] stops consuming the iterator.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+/// use std::ops::ControlFlow;
+///
+/// // Create an autofolder that keeps the max u8, stopping once it's saturated:
+/// let mut max = DynFolderUntil::new(0_u8, |a: u8, i: u8| {
+///     let max = a.max(i);
+///     if max == u8::MAX {
+///         ControlFlow::Break(max)
+///     } else {
+///         ControlFlow::Continue(max)
+///     }
+/// });
+///
+/// // We can "fold-in" individual items:
+/// max.fold(3);
+///
+/// // We can then peek at the running output:
+/// println!("Partial max is {}", max.as_ref());
+///
+/// // And still keep on folding by processing whole iterators, which stops at the break:
+/// let mut items = vec![7, 255, 9].into_iter();
+/// max.extend(&mut items);
+/// assert!(max.is_done());
+/// assert_eq!(items.next(), Some(9));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// println!("Max value is {}", max.into_inner());
+/// ```
+///
+/// If the folding function panics, the `DynFolderUntil` becomes *poisoned*, just like a
+/// [`DynFolder`](crate::DynFolder).
+#[derive(Copy, Clone)]
+pub struct DynFolderUntil<Output, Item, Func> {
+    output: Option<Output>,
+    done: bool,
+    function: Func,
+    item: marker::PhantomData<Item>,
+}
+
+impl<Output, Item, Func> DynFolderUntil<Output, Item, Func> {
+    /// Creates a new `DynFolderUntil` with the provided initial value and folding function.
+    pub const fn new(initial: Output, func: Func) -> Self
+    where
+        Func: Fn(Output, Item) -> ControlFlow<Output, Output>,
+    {
+        Self {
+            output: Some(initial),
+            done: false,
+            function: func,
+            item: marker::PhantomData,
+        }
+    }
+    /// Returns the contained value, consuming the self value.
+    pub fn into_inner(self) -> Output {
+        self.output.expect(POISONED)
+    }
+    /// Returns `true` if the folding function terminated the folding early.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+    /// Returns `true` if a previous call to the folding function panicked.
+    pub fn is_poisoned(&self) -> bool {
+        self.output.is_none()
+    }
+    /// Folds an individual value into self, unless the folding was already terminated.
+    pub fn fold(&mut self, item: Item)
+    where
+        Func: Fn(Output, Item) -> ControlFlow<Output, Output>,
+    {
+        if self.done {
+            return;
+        }
+        let current_output = self.output.take().expect(POISONED);
+        self.output = Some(match (self.function)(current_output, item) {
+            ControlFlow::Continue(output) => output,
+            ControlFlow::Break(output) => {
+                self.done = true;
+                output
+            }
+        });
+    }
+}
+
+impl<Output, Item, Func> core::fmt::Debug for DynFolderUntil<Output, Item, Func>
+where
+    Output: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "DynFolderUntil::<{}, {}, _> {{ output: {:?}, done: {}, function: {} }}",
+            &core::any::type_name::<Output>(),
+            &core::any::type_name::<Item>(),
+            DebugOutput(&self.output),
+            self.done,
+            &core::any::type_name::<Func>(),
+        )
+    }
+}
+
+impl<Output, Item, Func> AsRef<Output> for DynFolderUntil<Output, Item, Func> {
+    fn as_ref(&self) -> &Output {
+        self.output.as_ref().expect(POISONED)
+    }
+}
+
+impl<Output, Item, Func> Extend<Item> for DynFolderUntil<Output, Item, Func>
+where
+    Func: Fn(Output, Item) -> ControlFlow<Output, Output>,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        let mut iter = iter.into_iter();
+        while !self.done {
+            match iter.next() {
+                Some(item) => self.fold(item),
+                None => break,
+            }
+        }
+    }
+}
+
+impl<Output, Item, Func> FoldItem<Item> for DynFolderUntil<Output, Item, Func>
+where
+    Func: Fn(Output, Item) -> ControlFlow<Output, Output>,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Output, Item, Func> Folding for DynFolderUntil<Output, Item, Func>
+where
+    Func: Fn(Output, Item) -> ControlFlow<Output, Output>,
+{
+    type Item = Item;
+    type Output = Output;
+    type Peek<'a>
+        = &'a Output
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Output {
+        self.into_inner()
+    }
+}
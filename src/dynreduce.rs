@@ -5,6 +5,9 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 
+use crate::FoldItem;
+use crate::Folding;
+
 /// The `DynReduce` type uses a struct field for the folding function.
 ///
 /// This is essentially an [`DynFolder`](crate::DynFolder) that doesn't require an initial
@@ -31,55 +34,209 @@
 /// // And finally conmaxe the autofolder to get the final output value:
 /// println!("Final max is {}", max.into_inner().unwrap());
 /// ```
+///
+/// An observer can be attached with [`DynReduce::with_observer`] to get called with the
+/// running output after every reduce.
 #[derive(Copy, Clone)]
-pub struct DynReduce<Item, Func> {
+pub struct DynReduce<Item, Func, Observer = fn(&Item)> {
     item: Option<Item>,
+    count: usize,
     function: Func,
+    observer: Option<Observer>,
 }
 
 impl<Item, Func> DynReduce<Item, Func> {
     /// Creates a new `DynReduce` with the provided folding function.
-    pub fn new(func: Func) -> Self
+    pub const fn new(func: Func) -> Self
     where
         Func: Fn(Item, Item) -> Item,
     {
         Self {
             item: None,
+            count: 0,
+            function: func,
+            observer: None,
+        }
+    }
+    /// Creates a new `DynReduce` from a state previously returned by
+    /// [`DynReduce::into_state`] and the reduce function.
+    ///
+    /// The count of items is not part of the state; the restored value, if any, counts as a
+    /// single item.
+    pub const fn from_state(state: Option<Item>, func: Func) -> Self
+    where
+        Func: Fn(Item, Item) -> Item,
+    {
+        Self {
+            count: if state.is_some() { 1 } else { 0 },
+            item: state,
             function: func,
+            observer: None,
+        }
+    }
+}
+
+impl<Item, Func, Observer> DynReduce<Item, Func, Observer> {
+    /// Sets the observer, a function that gets called with the reduced value after every
+    /// reduce, replacing the previous one.
+    ///
+    /// The reduce function can return an equal value, and the observer is called anyway.
+    ///
+    /// Example:
+    /// ```
+    /// use autofolder::*;
+    ///
+    /// let mut maxes = vec![];
+    /// let mut max = DynReduce::new(u8::max).with_observer(|max: &u8| maxes.push(*max));
+    /// max.extend(vec![2, 5, 3]);
+    /// assert_eq!(max.into_inner(), Some(5));
+    /// assert_eq!(maxes, vec![2, 5, 5]);
+    /// ```
+    pub fn with_observer<NewObserver>(
+        self,
+        observer: NewObserver,
+    ) -> DynReduce<Item, Func, NewObserver>
+    where
+        NewObserver: FnMut(&Item),
+    {
+        DynReduce {
+            item: self.item,
+            count: self.count,
+            function: self.function,
+            observer: Some(observer),
         }
     }
+    /// Returns the number of items reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no item was reduced in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
     /// Returns the contained value, consuming the self value.
     pub fn into_inner(self) -> Option<Item> {
         self.item
     }
+    /// Deconstruct self and return the inner value, or the provided default if there is none.
+    pub fn into_inner_or(self, default: Item) -> Item {
+        self.into_inner().unwrap_or(default)
+    }
+    /// Deconstruct self and return the inner value, or the result of `func` if there is none.
+    pub fn into_inner_or_else<F>(self, func: F) -> Item
+    where
+        F: FnOnce() -> Item,
+    {
+        self.into_inner().unwrap_or_else(func)
+    }
+    /// Deconstruct self and return the inner value, or the default value of `Item` if there is
+    /// none.
+    pub fn into_inner_or_default(self) -> Item
+    where
+        Item: Default,
+    {
+        self.into_inner().unwrap_or_default()
+    }
     /// Returns a reference to the inner value, if there is one.
     pub fn as_ref(&self) -> Option<&Item> {
         self.item.as_ref()
     }
-    /// Folds an individual value into self.
+    /// Empties self, as if it was created with [`DynReduce::new`].
+    pub fn reset(&mut self) {
+        self.item = None;
+        self.count = 0;
+    }
+    /// Takes the inner value out, leaving self empty.
+    pub fn take(&mut self) -> Option<Item> {
+        self.count = 0;
+        self.item.take()
+    }
+    /// Replaces the inner value with the provided one, which counts as the only item, and
+    /// returns the previous value.
+    pub fn replace(&mut self, item: Item) -> Option<Item> {
+        self.count = 1;
+        self.item.replace(item)
+    }
+    /// Deconstruct self and return its state, dropping the reduce function.
+    ///
+    /// The state can be checkpointed (for instance, with the `serde` crate) and later restored
+    /// with [`DynReduce::from_state`] alongside a freshly supplied function.
+    pub fn into_state(self) -> Option<Item> {
+        self.item
+    }
+    /// Folds an individual value into self, and then calls the observer, if any.
+    ///
+    /// If the reduce function panics, the accumulated value is discarded and self is left
+    /// empty, so that the next item starts a new reduction.
     pub fn reduce(&mut self, item: Item)
     where
         Func: Fn(Item, Item) -> Item,
+        Observer: FnMut(&Item),
     {
+        // The count is reset while the reduce function runs, so that a panic leaves self empty:
+        let count = core::mem::take(&mut self.count);
         if let Some(current_item) = self.item.take() {
             self.item = Some((self.function)(current_item, item));
         } else {
             self.item = Some(item);
         }
+        self.count = count + 1;
+        if let (Some(observer), Some(item)) = (&mut self.observer, &self.item) {
+            observer(item);
+        }
+    }
+    /// Reduce the value behind the ref into self.
+    ///
+    /// This function requires the `Clone` trait, as the reduce function takes ownership of
+    /// the item.
+    pub fn reduce_ref(&mut self, item: &Item)
+    where
+        Func: Fn(Item, Item) -> Item,
+        Observer: FnMut(&Item),
+        Item: Clone,
+    {
+        self.reduce(item.clone())
+    }
+    /// Reduce the value of `other` into self, using the reduce function of self.
+    ///
+    /// The observer of `other` is dropped, and the one of self is called if `other` is not
+    /// empty.
+    pub fn merge_from<OtherObserver>(&mut self, other: DynReduce<Item, Func, OtherObserver>)
+    where
+        Func: Fn(Item, Item) -> Item,
+        Observer: FnMut(&Item),
+    {
+        if let Some(item) = other.item {
+            let count = self.count + other.count;
+            self.reduce(item);
+            self.count = count;
+        }
+    }
+    /// Reduce the value of `other` into self and return the result, using the reduce function
+    /// of self.
+    pub fn merge<OtherObserver>(mut self, other: DynReduce<Item, Func, OtherObserver>) -> Self
+    where
+        Func: Fn(Item, Item) -> Item,
+        Observer: FnMut(&Item),
+    {
+        self.merge_from(other);
+        self
     }
 }
 
-impl<Item, Func> std::fmt::Debug for DynReduce<Item, Func>
+impl<Item, Func, Observer> core::fmt::Debug for DynReduce<Item, Func, Observer>
 where
-    Item: std::fmt::Debug,
+    Item: core::fmt::Debug,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "DynReduce::<{}, _> {{ item: {:?}, function: {} }}",
-            &std::any::type_name::<Item>(),
+            "DynReduce::<{}, _, _> {{ item: {:?}, count: {}, function: {}, observer: {} }}",
+            &core::any::type_name::<Item>(),
             self.item,
-            &std::any::type_name::<Func>(),
+            self.count,
+            &core::any::type_name::<Func>(),
+            &core::any::type_name::<Observer>(),
         )
     }
 }
@@ -93,11 +250,52 @@ where
     }
 }
 
-impl<Item, Func> Extend<Item> for DynReduce<Item, Func>
+impl<Item, Func, Observer> Extend<Item> for DynReduce<Item, Func, Observer>
 where
     Func: Fn(Item, Item) -> Item,
+    Observer: FnMut(&Item),
 {
     fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
         iter.into_iter().for_each(|i| self.reduce(i));
     }
 }
+
+impl<'a, Item, Func, Observer> Extend<&'a Item> for DynReduce<Item, Func, Observer>
+where
+    Func: Fn(Item, Item) -> Item,
+    Observer: FnMut(&Item),
+    Item: Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<Item, Func, Observer> FoldItem<Item> for DynReduce<Item, Func, Observer>
+where
+    Func: Fn(Item, Item) -> Item,
+    Observer: FnMut(&Item),
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item, Func, Observer> Folding for DynReduce<Item, Func, Observer>
+where
+    Func: Fn(Item, Item) -> Item,
+    Observer: FnMut(&Item),
+{
+    type Item = Item;
+    type Output = Option<Item>;
+    type Peek<'a>
+        = Option<&'a Item>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<Item> {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+
+/// The `FanOut` type broadcasts each item to a set of autofolders chosen at runtime.
+///
+/// The autofolders are kept as [`FoldItem`] trait objects. They can be registered by value,
+/// or by mutable reference if we need to get their output after the `FanOut` is dropped.
+/// Each item is cloned for all but the last autofolder, which gets the item itself.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut max = Max::default();
+/// let mut sum = DynFolder::new(0, |a, i| a + i);
+///
+/// // Create a `FanOut` that feeds both autofolders:
+/// let mut fanout = FanOut::new();
+/// fanout.push(&mut max);
+/// fanout.push(&mut sum);
+///
+/// // We can "fold-in" individual items:
+/// fanout.fold(3);
+///
+/// // And still keep on folding by processing whole iterators:
+/// fanout.extend(1..=5);
+///
+/// // And finally drop the `FanOut` to get the outputs of the autofolders:
+/// drop(fanout);
+/// assert_eq!(max.into_inner(), Some(5));
+/// assert_eq!(sum.into_inner(), 18);
+/// ```
+pub struct FanOut<'a, Item> {
+    folders: Vec<Box<dyn FoldItem<Item> + 'a>>,
+}
+
+impl<'a, Item> FanOut<'a, Item> {
+    /// Creates a new `FanOut` with no autofolders.
+    pub const fn new() -> Self {
+        Self {
+            folders: Vec::new(),
+        }
+    }
+    /// Registers an autofolder.
+    pub fn push<F>(&mut self, folder: F)
+    where
+        F: FoldItem<Item> + 'a,
+    {
+        self.folders.push(Box::new(folder));
+    }
+    /// Registers an autofolder and return self, for chaining.
+    pub fn with<F>(mut self, folder: F) -> Self
+    where
+        F: FoldItem<Item> + 'a,
+    {
+        self.push(folder);
+        self
+    }
+    /// Returns the number of registered autofolders.
+    pub fn len(&self) -> usize {
+        self.folders.len()
+    }
+    /// Returns `true` if there are no registered autofolders.
+    pub fn is_empty(&self) -> bool {
+        self.folders.is_empty()
+    }
+    /// Deconstruct self and return the registered autofolders.
+    pub fn into_inner(self) -> Vec<Box<dyn FoldItem<Item> + 'a>> {
+        self.folders
+    }
+    /// Folds an individual item into all registered autofolders.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Clone,
+    {
+        if let Some((last, rest)) = self.folders.split_last_mut() {
+            for folder in rest {
+                folder.fold_item(item.clone());
+            }
+            last.fold_item(item);
+        }
+    }
+    /// Folds a clone of the item behind the ref into all registered autofolders.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Clone,
+    {
+        for folder in self.folders.iter_mut() {
+            folder.fold_item(item.clone());
+        }
+    }
+}
+
+impl<Item> Default for FanOut<'_, Item> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> std::fmt::Debug for FanOut<'_, Item> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FanOut::<{}> {{ folders: {} }}",
+            &std::any::type_name::<Item>(),
+            self.folders.len(),
+        )
+    }
+}
+
+impl<Item> FoldItem<Item> for FanOut<'_, Item>
+where
+    Item: Clone,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item> Extend<Item> for FanOut<'_, Item>
+where
+    Item: Clone,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'b, Item> Extend<&'b Item> for FanOut<'_, Item>
+where
+    Item: Clone + 'b,
+{
+    fn extend<It: IntoIterator<Item = &'b Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use core::marker;
+
+use crate::dynfolder::DebugOutput;
+use crate::FoldItem;
+use crate::Folding;
+
+const POISONED: &str = "FnFolder poisoned by a panicking folding function";
+
+/// The `FnFolder` type uses a plain function pointer for the folding function, provided by a
+/// marker type that implements [`FoldFn`].
+///
+/// - Pros:
+///   - Can be used with `.collect()` if the `output` type implements [`Default`], as the
+///     function is part of the type.
+///   - Unlike [`ImplFolder`](crate::ImplFolder), the trait is implemented for the marker type,
+///     so that the folding function can use any type, builtin or otherwise.
+/// - Cons:
+///   - Each marker type provides a single folding function, that can't capture anything.
+///
+/// The [`autofolder_fn`](crate::autofolder_fn) macro defines a marker type for a function.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Define the marker type for the folding function:
+/// autofolder_fn!(pub struct SumSquares: fn(u64, u32) = |a, b| a + (b * b) as u64);
+///
+/// // Create an autofolder that sums the squares of `u32` items into an `u64` output.
+/// let mut sum = FnFolder::<u64, u32, SumSquares>::default();
+///
+/// // We can "fold-in" individual items:
+/// sum.fold(3);
+///
+/// // We can then peek at the running output:
+/// println!("Partial sum is {}", sum.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// sum.extend((1..=3));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// assert_eq!(sum.into_inner(), 23);
+///
+/// // Or collect the items directly:
+/// let sum = (1..=3).collect::<FnFolder<u64, u32, SumSquares>>();
+/// assert_eq!(sum.into_inner(), 14);
+/// ```
+///
+/// If the folding function panics, the output that was moved into it is lost and the
+/// `FnFolder` becomes *poisoned*: [`FnFolder::is_poisoned`] returns `true` and all other
+/// methods panic, except for [`FnFolder::reset_with`], which recovers it.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FnFolder<Output, Item, F> {
+    output: Option<Output>,
+    count: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    item: marker::PhantomData<Item>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    function: marker::PhantomData<F>,
+}
+
+/// Trait that provides the folding function of [`FnFolder`] as a function pointer.
+///
+/// It is usually implemented with the [`autofolder_fn`](crate::autofolder_fn) macro.
+pub trait FoldFn<Output, Item> {
+    /// The folding function, that should return a new `output` with `item` folded in.
+    const FUNCTION: fn(Output, Item) -> Output;
+}
+
+impl<Output, Item, F> FnFolder<Output, Item, F> {
+    /// Creates a new `FnFolder` with the provided initial value.
+    pub const fn new(initial: Output) -> Self {
+        Self {
+            output: Some(initial),
+            count: 0,
+            item: marker::PhantomData,
+            function: marker::PhantomData,
+        }
+    }
+    /// Returns the number of items folded in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no item was folded in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// Deconstruct self and return the inner value.
+    pub fn into_inner(self) -> Output {
+        self.output.expect(POISONED)
+    }
+    /// Returns `true` if a previous call to the folding function panicked.
+    pub fn is_poisoned(&self) -> bool {
+        self.output.is_none()
+    }
+    /// Replaces the output with the provided initial value, resetting the count of items, and
+    /// returns the previous output, or `None` if self was poisoned.
+    pub fn reset_with(&mut self, initial: Output) -> Option<Output> {
+        self.count = 0;
+        self.output.replace(initial)
+    }
+    /// Folds an individual value into self.
+    pub fn fold(&mut self, item: Item)
+    where
+        F: FoldFn<Output, Item>,
+    {
+        // We move the current output to the folding function, leaving `None` behind, which
+        // marks self as poisoned if the function panics:
+        let current_output = self.output.take().expect(POISONED);
+        self.output = Some(F::FUNCTION(current_output, item));
+        self.count += 1;
+    }
+    /// Folds the value behind the ref into self.
+    ///
+    /// This function requires the `Clone` trait, as the folding function takes ownership of
+    /// the item.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        F: FoldFn<Output, Item>,
+        Item: Clone,
+    {
+        self.fold(item.clone())
+    }
+    fn fold_iter<It>(&mut self, iter: It)
+    where
+        It: Iterator<Item = Item>,
+        F: FoldFn<Output, Item>,
+    {
+        // As in ImplFolder, we move the current output out only once for the whole iterator:
+        let current_output = self.output.take().expect(POISONED);
+        let count = &mut self.count;
+        self.output = Some(iter.fold(current_output, |output, item| {
+            let output = F::FUNCTION(output, item);
+            *count += 1;
+            output
+        }));
+    }
+}
+
+impl<Output, Item, F> From<Output> for FnFolder<Output, Item, F> {
+    fn from(output: Output) -> Self {
+        Self::new(output)
+    }
+}
+
+impl<Output, Item, F> core::fmt::Debug for FnFolder<Output, Item, F>
+where
+    Output: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "FnFolder::<{}, {}, {}> {{ output: {:?}, count: {} }}",
+            &core::any::type_name::<Output>(),
+            &core::any::type_name::<Item>(),
+            &core::any::type_name::<F>(),
+            DebugOutput(&self.output),
+            self.count,
+        )
+    }
+}
+
+impl<Output, Item, F> AsRef<Output> for FnFolder<Output, Item, F> {
+    fn as_ref(&self) -> &Output {
+        self.output.as_ref().expect(POISONED)
+    }
+}
+
+impl<Output, Item, F> Extend<Item> for FnFolder<Output, Item, F>
+where
+    F: FoldFn<Output, Item>,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        self.fold_iter(iter.into_iter());
+    }
+}
+
+impl<'a, Output, Item, F> Extend<&'a Item> for FnFolder<Output, Item, F>
+where
+    F: FoldFn<Output, Item>,
+    Item: Clone + 'a,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        self.fold_iter(iter.into_iter().cloned());
+    }
+}
+
+impl<Output, Item, F> Default for FnFolder<Output, Item, F>
+where
+    Output: Default,
+{
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<Output, Item, F> core::iter::FromIterator<Item> for FnFolder<Output, Item, F>
+where
+    Output: Default,
+    F: FoldFn<Output, Item>,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Output, Item, F> core::iter::FromIterator<&'a Item> for FnFolder<Output, Item, F>
+where
+    Output: Default,
+    F: FoldFn<Output, Item>,
+    Item: Clone + 'a,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+/// Macro that defines a marker type that implements [`FoldFn`] with the provided function.
+///
+/// The function can be a path or a non-capturing closure, and is preceded by the `fn` type
+/// with the output and item types.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// fn push_len(mut lens: Vec<usize>, s: String) -> Vec<usize> {
+///     lens.push(s.len());
+///     lens
+/// }
+///
+/// autofolder_fn!(struct PushLen: fn(Vec<usize>, String) = push_len);
+///
+/// let lens = vec!["a".to_string(), "bcd".to_string()]
+///     .into_iter()
+///     .collect::<FnFolder<_, _, PushLen>>();
+/// assert_eq!(lens.into_inner(), vec![1, 3]);
+/// ```
+#[macro_export]
+macro_rules! autofolder_fn {
+    ($(#[$attr: meta])* $vis: vis struct $name: ident : fn($output_type: ty, $item_type: ty) = $func: expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Copy, Clone, Default)]
+        $vis struct $name;
+
+        impl $crate::FoldFn<$output_type, $item_type> for $name {
+            const FUNCTION: fn($output_type, $item_type) -> $output_type = $func;
+        }
+    };
+}
+
+impl<Output, Item, F> FoldItem<Item> for FnFolder<Output, Item, F>
+where
+    F: FoldFn<Output, Item>,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Output, Item, F> core::ops::AddAssign<Item> for FnFolder<Output, Item, F>
+where
+    F: FoldFn<Output, Item>,
+{
+    fn add_assign(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Output, Item, F> Folding for FnFolder<Output, Item, F>
+where
+    F: FoldFn<Output, Item>,
+{
+    type Item = Item;
+    type Output = Output;
+    type Peek<'a>
+        = &'a Output
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Output {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+/// Object-safe trait implemented by all autofolders, with the single operation of folding-in
+/// an item.
+///
+/// This is the supertrait of [`Folding`] that can be used as a trait object, which allows
+/// feeding a set of autofolders chosen at runtime - see [`FanOut`](crate::FanOut).
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut max = Max::default();
+/// let mut sum = DynFolder::new(0, |a, i| a + i);
+/// {
+///     let mut folders: Vec<&mut dyn FoldItem<u32>> = vec![&mut max, &mut sum];
+///     for folder in folders.iter_mut() {
+///         folder.fold_item(3);
+///     }
+/// }
+/// assert_eq!(max.into_inner(), Some(3));
+/// assert_eq!(sum.into_inner(), 3);
+/// ```
+pub trait FoldItem<Item> {
+    /// Folds an individual item into self.
+    fn fold_item(&mut self, item: Item);
+}
+
+impl<Item, F> FoldItem<Item> for &mut F
+where
+    F: FoldItem<Item> + ?Sized,
+{
+    fn fold_item(&mut self, item: Item) {
+        (**self).fold_item(item)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Item, F> FoldItem<Item> for Box<F>
+where
+    F: FoldItem<Item> + ?Sized,
+{
+    fn fold_item(&mut self, item: Item) {
+        (**self).fold_item(item)
+    }
+}
+
+/// Trait implemented by all autofolders, which allows writing code that is generic over the
+/// folding strategy.
+///
+/// Items are folded in with [`FoldItem::fold_item`], from the supertrait.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // A function that works with any autofolder of `u32` items:
+/// fn fold_evens<F: Folding<Item = u32>>(mut folder: F, items: &[u32]) -> F::Output {
+///     for item in items.iter().filter(|i| *i % 2 == 0) {
+///         folder.fold_item(*item);
+///     }
+///     folder.finish()
+/// }
+///
+/// let items = [3, 8, 2, 7, 4];
+///
+/// // The caller chooses the concrete strategy:
+/// assert_eq!(fold_evens(Max::default(), &items), Some(8));
+/// assert_eq!(fold_evens(MinMax::default(), &items), Some((2, 8)));
+/// assert_eq!(fold_evens(DynFolder::new(0, |a, i| a + i), &items), 14);
+/// ```
+pub trait Folding: FoldItem<<Self as Folding>::Item> {
+    /// Type of the items folded in.
+    type Item;
+    /// Type of the final output, as returned by [`Folding::finish`].
+    type Output;
+    /// Type of the running output, as returned by [`Folding::peek`].
+    type Peek<'a>
+    where
+        Self: 'a;
+    /// Returns a view of the running output.
+    fn peek(&self) -> Self::Peek<'_>;
+    /// Consumes self and returns the final output.
+    fn finish(self) -> Self::Output;
+}
@@ -0,0 +1,205 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::hash_map;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The `GroupFolder` type folds `(key, item)` pairs by routing each item into a per-key
+/// autofolder, kept in a [`HashMap`].
+///
+/// The autofolder of a key is created when the key shows up for the first time, either with
+/// [`Default`] (see [`GroupFolder::new`]) or with a factory closure (see
+/// [`GroupFolder::with_factory`]). Any type that implements [`Folding`] can be used.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the min price per category:
+/// let mut cheapest = GroupFolder::<&str, Min<u32>>::new();
+///
+/// // We can "fold-in" individual pairs:
+/// cheapest.fold(("fruit", 30));
+///
+/// // We can then peek at the autofolder of a key:
+/// println!("Partial fruit min is {:?}", cheapest.get(&"fruit"));
+///
+/// // And still keep on folding by processing whole iterators:
+/// cheapest.extend(vec![("dairy", 25), ("fruit", 12), ("dairy", 40)]);
+///
+/// // And finally consume the autofolder to get the output of each key:
+/// let cheapest = cheapest.into_inner();
+/// assert_eq!(cheapest["fruit"], Some(12));
+/// assert_eq!(cheapest["dairy"], Some(25));
+/// ```
+#[derive(Clone)]
+pub struct GroupFolder<Key, Folder, Factory = fn() -> Folder> {
+    groups: HashMap<Key, Folder>,
+    factory: Factory,
+}
+
+impl<Key, Folder> GroupFolder<Key, Folder> {
+    /// Creates a new empty `GroupFolder` that creates the autofolders with [`Default`].
+    pub fn new() -> Self
+    where
+        Folder: Default,
+    {
+        Self::with_factory(Folder::default)
+    }
+}
+
+impl<Key, Folder, Factory> GroupFolder<Key, Folder, Factory> {
+    /// Creates a new empty `GroupFolder` that creates the autofolders with the provided
+    /// factory closure.
+    pub fn with_factory(factory: Factory) -> Self
+    where
+        Factory: Fn() -> Folder,
+    {
+        Self {
+            groups: HashMap::new(),
+            factory,
+        }
+    }
+    /// Returns the number of keys.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+    /// Returns `true` if no pair was folded in.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+    /// Returns a reference to the autofolder of the key, if it exists.
+    pub fn get(&self, key: &Key) -> Option<&Folder>
+    where
+        Key: Eq + Hash,
+    {
+        self.groups.get(key)
+    }
+    /// Returns an iterator over the keys and their autofolders, in arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Key, Folder> {
+        self.groups.iter()
+    }
+    /// Deconstruct self and return the autofolders of the keys.
+    pub fn into_groups(self) -> HashMap<Key, Folder> {
+        self.groups
+    }
+    /// Deconstruct self and return the output of the autofolder of each key.
+    pub fn into_inner(self) -> HashMap<Key, Folder::Output>
+    where
+        Key: Eq + Hash,
+        Folder: Folding,
+    {
+        self.groups
+            .into_iter()
+            .map(|(key, folder)| (key, folder.finish()))
+            .collect()
+    }
+    /// Folds the item of the pair into the autofolder of the key, creating it if necessary.
+    pub fn fold(&mut self, pair: (Key, Folder::Item))
+    where
+        Key: Eq + Hash,
+        Folder: Folding,
+        Factory: Fn() -> Folder,
+    {
+        let (key, item) = pair;
+        self.groups
+            .entry(key)
+            .or_insert_with(&self.factory)
+            .fold_item(item);
+    }
+}
+
+impl<Key, Folder> Default for GroupFolder<Key, Folder>
+where
+    Folder: Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Key, Folder, Factory> core::fmt::Debug for GroupFolder<Key, Folder, Factory>
+where
+    Key: core::fmt::Debug,
+    Folder: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "GroupFolder::<{}, {}, _> {{ groups: {:?}, factory: {} }}",
+            &core::any::type_name::<Key>(),
+            &core::any::type_name::<Folder>(),
+            self.groups,
+            &core::any::type_name::<Factory>(),
+        )
+    }
+}
+
+impl<'a, Key, Folder, Factory> IntoIterator for &'a GroupFolder<Key, Folder, Factory> {
+    type Item = (&'a Key, &'a Folder);
+    type IntoIter = hash_map::Iter<'a, Key, Folder>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<Key, Folder, Factory> Extend<(Key, Folder::Item)> for GroupFolder<Key, Folder, Factory>
+where
+    Key: Eq + Hash,
+    Folder: Folding,
+    Factory: Fn() -> Folder,
+{
+    fn extend<It: IntoIterator<Item = (Key, Folder::Item)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<Key, Folder> std::iter::FromIterator<(Key, Folder::Item)> for GroupFolder<Key, Folder>
+where
+    Key: Eq + Hash,
+    Folder: Folding + Default,
+{
+    fn from_iter<It: IntoIterator<Item = (Key, Folder::Item)>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Key, Folder, Factory> FoldItem<(Key, Folder::Item)> for GroupFolder<Key, Folder, Factory>
+where
+    Key: Eq + Hash,
+    Folder: Folding,
+    Factory: Fn() -> Folder,
+{
+    fn fold_item(&mut self, item: (Key, Folder::Item)) {
+        self.fold(item)
+    }
+}
+
+impl<Key, Folder, Factory> Folding for GroupFolder<Key, Folder, Factory>
+where
+    Key: Eq + Hash,
+    Folder: Folding,
+    Factory: Fn() -> Folder,
+{
+    type Item = (Key, Folder::Item);
+    type Output = HashMap<Key, Folder::Output>;
+    type Peek<'a>
+        = &'a HashMap<Key, Folder>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        &self.groups
+    }
+    fn finish(self) -> HashMap<Key, Folder::Output> {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::BinaryHeap;
+
+/// The `HeapFold` type folds items into a [`BinaryHeap`], using the [`std::cmp::Ord`] trait.
+///
+/// The heap can optionally be bounded: a bounded `HeapFold` retains only the `bound`
+/// smallest items folded in, which makes [`HeapFold::peek`] return the largest of those.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that collects usize items in a heap:
+/// let mut heap = HeapFold::<usize>::default();
+///
+/// // We can "fold-in" individual items:
+/// heap.fold(3);
+///
+/// // We can then peek at the largest item:
+/// println!("Partial largest is {:?}", heap.peek());
+///
+/// // And still keep on folding by processing whole iterators:
+/// heap.extend((1..=5));
+///
+/// // And finally consume the autofolder to get the items in ascending order:
+/// println!("Sorted items are {:?}", heap.into_sorted_vec());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HeapFold<Item> {
+    heap: BinaryHeap<Item>,
+    bound: Option<usize>,
+}
+
+impl<Item> HeapFold<Item> {
+    /// Creates a new unbounded `HeapFold`.
+    pub const fn new() -> Self
+    where
+        Item: Ord,
+    {
+        Self {
+            heap: BinaryHeap::new(),
+            bound: None,
+        }
+    }
+    /// Creates a new `HeapFold` that retains only the `bound` smallest items.
+    pub fn with_bound(bound: usize) -> Self
+    where
+        Item: Ord,
+    {
+        Self {
+            heap: BinaryHeap::new(),
+            bound: Some(bound),
+        }
+    }
+    /// Returns the bound of the heap, if there is one.
+    pub fn bound(&self) -> Option<usize> {
+        self.bound
+    }
+    /// Deconstruct self and return the inner heap.
+    pub fn into_inner(self) -> BinaryHeap<Item> {
+        self.heap
+    }
+    /// Deconstruct self and return the items in ascending order.
+    pub fn into_sorted_vec(self) -> Vec<Item>
+    where
+        Item: Ord,
+    {
+        self.heap.into_sorted_vec()
+    }
+    /// Returns a reference to the largest item in the heap, if there is one.
+    pub fn peek(&self) -> Option<&Item>
+    where
+        Item: Ord,
+    {
+        self.heap.peek()
+    }
+    /// Returns the number of items in the heap.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    /// Returns `true` if the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    /// Folds an individual value into self.
+    ///
+    /// If the heap is bounded and full, the largest item is dropped.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Ord,
+    {
+        self.heap.push(item);
+        if let Some(bound) = self.bound {
+            if self.heap.len() > bound {
+                self.heap.pop();
+            }
+        }
+    }
+    /// Folds in the value behind the reference, if it will be kept.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if necessary.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Ord + Clone,
+    {
+        if let Some(bound) = self.bound {
+            if self.heap.len() >= bound && self.heap.peek().is_none_or(|top| item >= top) {
+                return;
+            }
+        }
+        self.fold(item.clone());
+    }
+    /// Folds all items of `other` into self, keeping the bound of self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Ord,
+    {
+        if self.bound.is_none() {
+            let mut other = other.heap;
+            self.heap.append(&mut other);
+        } else {
+            self.extend(other.heap);
+        }
+    }
+    /// Folds all items of `other` into self and return the result, keeping the bound of self.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Ord,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for HeapFold<Item>
+where
+    Item: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> From<BinaryHeap<Item>> for HeapFold<Item> {
+    fn from(heap: BinaryHeap<Item>) -> Self {
+        Self { heap, bound: None }
+    }
+}
+
+impl<Item> AsRef<BinaryHeap<Item>> for HeapFold<Item> {
+    fn as_ref(&self) -> &BinaryHeap<Item> {
+        &self.heap
+    }
+}
+
+impl<Item> Extend<Item> for HeapFold<Item>
+where
+    Item: Ord,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for HeapFold<Item>
+where
+    Item: Ord + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Item> for HeapFold<Item>
+where
+    Item: Ord,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> std::iter::FromIterator<&'a Item> for HeapFold<Item>
+where
+    Item: Ord + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for HeapFold<Item>
+where
+    Item: Ord,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item> Folding for HeapFold<Item>
+where
+    Item: Ord,
+{
+    type Item = Item;
+    type Output = BinaryHeap<Item>;
+    type Peek<'a>
+        = Option<&'a Item>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        HeapFold::peek(self)
+    }
+    fn finish(self) -> BinaryHeap<Item> {
+        self.into_inner()
+    }
+}
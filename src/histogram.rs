@@ -0,0 +1,287 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// The `Histogram` type counts the folded values per bin, using the [`std::cmp::PartialOrd`]
+/// trait.
+///
+/// The bins are given by their edges: `n + 1` ascending edges define `n` half-open bins
+/// `[edges[i], edges[i + 1])`. Values below the first edge are counted as underflow, values
+/// at or above the last edge as overflow, and values that can't be compared with the edges,
+/// such as NaN, as unordered.
+///
+/// As the edges are given at runtime, the autofolder can't be created with
+/// [`collect`](Iterator::collect); use [`Histogram::with_items`] instead.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create a histogram with the bins [0, 10), [10, 100) and [100, 1000):
+/// let mut histogram = Histogram::new(vec![0, 10, 100, 1000]);
+///
+/// // We can "fold-in" individual values:
+/// histogram.fold(42);
+///
+/// // We can then peek at the running counts:
+/// println!("Partial counts are {:?}", histogram.counts());
+///
+/// // And still keep on folding by processing whole iterators:
+/// histogram.extend(vec![5, 50, 500, 5000]);
+///
+/// // And finally consume the autofolder to get the counts of the bins:
+/// assert_eq!(histogram.overflow(), 1);
+/// assert_eq!(histogram.into_inner(), vec![1, 2, 1]);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "HistogramState<Item>",
+        bound(deserialize = "Item: serde::Deserialize<'de> + PartialOrd")
+    )
+)]
+pub struct Histogram<Item> {
+    edges: Vec<Item>,
+    counts: Vec<usize>,
+    underflow: usize,
+    overflow: usize,
+    unordered: usize,
+}
+
+/// The deserialized state of a [`Histogram`], which is validated before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct HistogramState<Item> {
+    edges: Vec<Item>,
+    counts: Vec<usize>,
+    underflow: usize,
+    overflow: usize,
+    unordered: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<Item> core::convert::TryFrom<HistogramState<Item>> for Histogram<Item>
+where
+    Item: PartialOrd,
+{
+    type Error = &'static str;
+    fn try_from(state: HistogramState<Item>) -> Result<Self, &'static str> {
+        if let Some(error) = Self::edges_error(&state.edges) {
+            return Err(error);
+        }
+        if state.counts.len() != state.edges.len() - 1 {
+            return Err("a histogram needs one count per bin");
+        }
+        Ok(Self {
+            edges: state.edges,
+            counts: state.counts,
+            underflow: state.underflow,
+            overflow: state.overflow,
+            unordered: state.unordered,
+        })
+    }
+}
+
+impl<Item> Histogram<Item> {
+    /// Creates a new empty `Histogram` with the provided bin edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less than two edges, or if they are not strictly ascending.
+    pub fn new(edges: Vec<Item>) -> Self
+    where
+        Item: PartialOrd,
+    {
+        if let Some(error) = Self::edges_error(&edges) {
+            panic!("{}", error);
+        }
+        Self {
+            counts: vec![0; edges.len() - 1],
+            edges,
+            underflow: 0,
+            overflow: 0,
+            unordered: 0,
+        }
+    }
+    fn edges_error(edges: &[Item]) -> Option<&'static str>
+    where
+        Item: PartialOrd,
+    {
+        if edges.len() < 2 {
+            Some("a histogram needs at least two edges")
+        } else if !edges.windows(2).all(|w| w[0] < w[1]) {
+            Some("histogram edges must be strictly ascending")
+        } else {
+            None
+        }
+    }
+    /// Folds all values of the iterator into self and return it.
+    ///
+    /// This is the builder-style replacement for [`collect`](Iterator::collect):
+    /// `Histogram::new(edges).with_items(iter)`.
+    pub fn with_items<It>(mut self, iter: It) -> Self
+    where
+        It: IntoIterator<Item = Item>,
+        Item: PartialOrd,
+    {
+        self.extend(iter);
+        self
+    }
+    /// Returns the bin edges.
+    pub fn edges(&self) -> &[Item] {
+        &self.edges
+    }
+    /// Returns the counts of the bins.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+    /// Returns the number of values below the first edge.
+    pub fn underflow(&self) -> usize {
+        self.underflow
+    }
+    /// Returns the number of values at or above the last edge.
+    pub fn overflow(&self) -> usize {
+        self.overflow
+    }
+    /// Returns the number of values that couldn't be compared with the edges.
+    pub fn unordered(&self) -> usize {
+        self.unordered
+    }
+    /// Returns the total number of values folded in, including the ones outside the bins.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum::<usize>() + self.underflow + self.overflow + self.unordered
+    }
+    /// Deconstruct self and return the counts of the bins.
+    pub fn into_inner(self) -> Vec<usize> {
+        self.counts
+    }
+    /// Counts the value behind the reference in its bin.
+    ///
+    /// As the value is only compared, this doesn't require the `Clone` trait.
+    pub fn fold_ref(&mut self, value: &Item)
+    where
+        Item: PartialOrd,
+    {
+        let last = self.edges.len() - 1;
+        if value.partial_cmp(&self.edges[0]).is_none() {
+            self.unordered += 1;
+        } else if *value < self.edges[0] {
+            self.underflow += 1;
+        } else if *value >= self.edges[last] {
+            self.overflow += 1;
+        } else {
+            let bin = self.edges.partition_point(|edge| edge <= value) - 1;
+            self.counts[bin] += 1;
+        }
+    }
+    /// Counts the value in its bin.
+    pub fn fold(&mut self, value: Item)
+    where
+        Item: PartialOrd,
+    {
+        self.fold_ref(&value)
+    }
+    /// Adds the counts of `other` into self.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edges are different.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: PartialOrd,
+    {
+        assert!(
+            self.edges == other.edges,
+            "merging histograms with different edges"
+        );
+        for (count, other) in self.counts.iter_mut().zip(other.counts) {
+            *count += other;
+        }
+        self.underflow += other.underflow;
+        self.overflow += other.overflow;
+        self.unordered += other.unordered;
+    }
+    /// Adds the counts of `other` into self and return the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edges are different.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: PartialOrd,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl Histogram<f64> {
+    /// Creates a new empty `Histogram` with `bins` bins of the same width, from `start` to
+    /// `end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is zero or if `start` is not smaller than `end`.
+    pub fn uniform(start: f64, end: f64, bins: usize) -> Self {
+        assert!(bins > 0, "a histogram needs at least one bin");
+        let width = (end - start) / bins as f64;
+        let mut edges = (0..bins)
+            .map(|i| start + width * i as f64)
+            .collect::<Vec<_>>();
+        edges.push(end);
+        Self::new(edges)
+    }
+}
+
+impl<Item> Extend<Item> for Histogram<Item>
+where
+    Item: PartialOrd,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for Histogram<Item>
+where
+    Item: PartialOrd,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+impl<Item> FoldItem<Item> for Histogram<Item>
+where
+    Item: PartialOrd,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item> Folding for Histogram<Item>
+where
+    Item: PartialOrd,
+{
+    type Item = Item;
+    type Output = Vec<usize>;
+    type Peek<'a>
+        = &'a [usize]
+    where
+        Self: 'a;
+    fn peek(&self) -> &[usize] {
+        self.counts()
+    }
+    fn finish(self) -> Vec<usize> {
+        self.into_inner()
+    }
+}
@@ -5,8 +5,13 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 
-use std::marker;
-use std::mem;
+use core::marker;
+
+use crate::dynfolder::DebugOutput;
+use crate::FoldItem;
+use crate::Folding;
+
+const POISONED: &str = "ImplFolder poisoned by a panicking folding function";
 
 /// The `ImplFolder` type uses the [`FolderTrait`] for the folding function.
 ///
@@ -48,9 +53,17 @@ use std::mem;
 /// let total = sum.into_inner();
 /// println!("Total sum is {}", total.0);
 /// ```
-#[derive(Debug, Copy, Clone)]
+///
+/// If the folding function panics, the output that was moved into it is lost and the
+/// `ImplFolder` becomes *poisoned*: [`ImplFolder::is_poisoned`] returns `true` and all other
+/// methods panic, except for [`ImplFolder::reset_with`], which recovers it.
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImplFolder<Output, Item> {
-    output: Output,
+    output: Option<Output>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    count: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
     item: marker::PhantomData<Item>,
 }
 
@@ -63,36 +76,75 @@ pub trait FolderTrait<Output, Item> {
 
 impl<Output, Item> ImplFolder<Output, Item> {
     /// Creates a new `ImplFolder` with the provided initial value.
-    pub fn new(initial: Output) -> Self {
+    pub const fn new(initial: Output) -> Self {
         Self {
-            output: initial,
+            output: Some(initial),
+            count: 0,
             item: marker::PhantomData,
         }
     }
+    /// Returns the number of items folded in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no item was folded in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
     /// Deconstruct self and return the inner value.
     pub fn into_inner(self) -> Output {
-        self.output
+        self.output.expect(POISONED)
+    }
+    /// Returns `true` if a previous call to the folding function panicked.
+    pub fn is_poisoned(&self) -> bool {
+        self.output.is_none()
+    }
+    /// Replaces the output with the provided initial value, resetting the count of items, and
+    /// returns the previous output, or `None` if self was poisoned.
+    pub fn reset_with(&mut self, initial: Output) -> Option<Output> {
+        self.count = 0;
+        self.output.replace(initial)
     }
     /// Folds an individual value into self.
     pub fn fold(&mut self, item: Item)
     where
         Self: FolderTrait<Output, Item>,
     {
-        // SAFETY: we move out current output to the folding function;
-        // to do that, we replace it with an uninitialized value.
-        // This is safe because we immediately put back the new value
-        // returned by the folding function.
-        #[allow(clippy::uninit_assumed_init)]
-        let uninit = unsafe { mem::MaybeUninit::<Output>::uninit().assume_init() };
-        let current_output = mem::replace(&mut self.output, uninit);
-        // self.0 now has the uninitalized value
-        // Give ownership of current_output to self.function, and get new_output:
-        let new_output = <Self as FolderTrait<Output, Item>>::fold(current_output, item);
-        // Put new_output in self.0 and get the old uninit back:
-        let uninit = mem::replace(&mut self.output, new_output);
-        // We need to mem::forget it to avoid running destructors on
-        // the uninitialized value:
-        mem::forget(uninit);
+        // We move the current output to the folding function, leaving `None` behind, which
+        // marks self as poisoned if the function panics:
+        let current_output = self.output.take().expect(POISONED);
+        self.output = Some(<Self as FolderTrait<Output, Item>>::fold(
+            current_output,
+            item,
+        ));
+        self.count += 1;
+    }
+    /// Folds the value behind the ref into self.
+    ///
+    /// This function requires the `Clone` trait, as the folding function takes ownership of
+    /// the item.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Self: FolderTrait<Output, Item>,
+        Item: Clone,
+    {
+        self.fold(item.clone())
+    }
+    fn fold_iter<It>(&mut self, iter: It)
+    where
+        It: Iterator<Item = Item>,
+        Self: FolderTrait<Output, Item>,
+    {
+        // We move the current output out only once, and let the iterator drive the fold, which
+        // allows the optimizer to keep the output in registers. If the folding function
+        // panics, the `None` left behind marks self as poisoned, as in `fold`:
+        let current_output = self.output.take().expect(POISONED);
+        let count = &mut self.count;
+        self.output = Some(iter.fold(current_output, |output, item| {
+            let output = <Self as FolderTrait<Output, Item>>::fold(output, item);
+            *count += 1;
+            output
+        }));
     }
 }
 
@@ -102,9 +154,25 @@ impl<Output, Item> From<Output> for ImplFolder<Output, Item> {
     }
 }
 
+impl<Output, Item> core::fmt::Debug for ImplFolder<Output, Item>
+where
+    Output: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ImplFolder::<{}, {}> {{ output: {:?}, count: {} }}",
+            &core::any::type_name::<Output>(),
+            &core::any::type_name::<Item>(),
+            DebugOutput(&self.output),
+            self.count,
+        )
+    }
+}
+
 impl<Output, Item> AsRef<Output> for ImplFolder<Output, Item> {
     fn as_ref(&self) -> &Output {
-        &self.output
+        self.output.as_ref().expect(POISONED)
     }
 }
 
@@ -113,7 +181,17 @@ where
     ImplFolder<Output, Item>: FolderTrait<Output, Item>,
 {
     fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
-        iter.into_iter().for_each(|i| self.fold(i));
+        self.fold_iter(iter.into_iter());
+    }
+}
+
+impl<'a, Output, Item> Extend<&'a Item> for ImplFolder<Output, Item>
+where
+    ImplFolder<Output, Item>: FolderTrait<Output, Item>,
+    Item: Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        self.fold_iter(iter.into_iter().cloned());
     }
 }
 
@@ -128,7 +206,7 @@ where
     }
 }
 
-impl<Output, Item> std::iter::FromIterator<Item> for ImplFolder<Output, Item>
+impl<Output, Item> core::iter::FromIterator<Item> for ImplFolder<Output, Item>
 where
     Output: Default,
     ImplFolder<Output, Item>: FolderTrait<Output, Item>,
@@ -144,11 +222,114 @@ where
 ///
 /// It extracts the types used in the parameters of the closure to fill in FolderTrait's
 /// arguments, reducing the amount of repetition.
+///
+/// The closure can be preceded by generic parameters and followed by a `where` clause, which
+/// are used in the impl. Lifetimes of reference types have to be named in the generic
+/// parameters.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// pub struct Concat<T>(Vec<T>);
+///
+/// autofolder_impl_foldertrait!(<'a, T> |a: Concat<T>, b: &'a [T]| where T: Clone {
+///     a.0.extend_from_slice(b);
+///     a
+/// });
+///
+/// let mut concat = ImplFolder::new(Concat(vec![1]));
+/// concat.extend(vec![&[2, 3][..], &[4]]);
+/// assert_eq!(concat.into_inner().0, vec![1, 2, 3, 4]);
+/// ```
 #[macro_export]
 macro_rules! autofolder_impl_foldertrait{
-    (|$a:ident : $output_type: ty, $i:ident : $item_type: ty| $body: block) => {
-        impl FolderTrait<$output_type, $item_type> for ImplFolder<$output_type, $item_type> {
+    (@impl [$($generics: tt)*] [$($where: tt)*] $a:ident : $output_type: ty, $i:ident : $item_type: ty, $body: block) => {
+        impl<$($generics)*> $crate::FolderTrait<$output_type, $item_type>
+            for $crate::ImplFolder<$output_type, $item_type>
+        where
+            $($where)*
+        {
             fn fold(mut $a: $output_type, $i: $item_type) -> $output_type $body
         }
+    };
+    (@where [$($generics: tt)*] [$($where: tt)*] [$($sig: tt)*] $body: block) => {
+        $crate::autofolder_impl_foldertrait!(@impl [$($generics)*] [$($where)*] $($sig)* $body);
+    };
+    (@where [$($generics: tt)*] [$($where: tt)*] [$($sig: tt)*] $t: tt $($rest: tt)+) => {
+        $crate::autofolder_impl_foldertrait!(@where [$($generics)*] [$($where)* $t] [$($sig)*] $($rest)+);
+    };
+    (@generics [$($generics: tt)*] |$a:ident : $output_type: ty, $i:ident : $item_type: ty $(,)?| where $($rest: tt)+) => {
+        $crate::autofolder_impl_foldertrait!(@where [$($generics)*] [] [$a: $output_type, $i: $item_type,] $($rest)+);
+    };
+    (@generics [$($generics: tt)*] |$a:ident : $output_type: ty, $i:ident : $item_type: ty $(,)?| $body: block) => {
+        $crate::autofolder_impl_foldertrait!(@impl [$($generics)*] [] $a: $output_type, $i: $item_type, $body);
+    };
+    (< $($rest: tt)+) => {
+        $crate::__autofolder_generics!(autofolder_impl_foldertrait [] [] $($rest)+);
+    };
+    (| $($rest: tt)+) => {
+        $crate::autofolder_impl_foldertrait!(@generics [] | $($rest)+);
+    };
+}
+
+/// Helper macro that splits the generic parameters, up to the matching `>`, from the tokens
+/// that follow them, and then calls back the provided macro with them.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __autofolder_generics{
+    ($callback: ident [$($generics: tt)*] [] > $($rest: tt)+) => {
+        $crate::$callback!(@generics [$($generics)*] $($rest)+);
+    };
+    ($callback: ident [$($generics: tt)*] [$depth: tt] >> $($rest: tt)+) => {
+        $crate::$callback!(@generics [$($generics)* >] $($rest)+);
+    };
+    ($callback: ident [$($generics: tt)*] [$depth: tt $($depths: tt)*] > $($rest: tt)+) => {
+        $crate::__autofolder_generics!($callback [$($generics)* >] [$($depths)*] $($rest)+);
+    };
+    ($callback: ident [$($generics: tt)*] [$depth1: tt $depth2: tt $($depths: tt)*] >> $($rest: tt)+) => {
+        $crate::__autofolder_generics!($callback [$($generics)* >>] [$($depths)*] $($rest)+);
+    };
+    ($callback: ident [$($generics: tt)*] [$($depths: tt)*] < $($rest: tt)+) => {
+        $crate::__autofolder_generics!($callback [$($generics)* <] [< $($depths)*] $($rest)+);
+    };
+    ($callback: ident [$($generics: tt)*] [$($depths: tt)*] $t: tt $($rest: tt)+) => {
+        $crate::__autofolder_generics!($callback [$($generics)* $t] [$($depths)*] $($rest)+);
+    };
+}
+
+impl<Output, Item> FoldItem<Item> for ImplFolder<Output, Item>
+where
+    ImplFolder<Output, Item>: FolderTrait<Output, Item>,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Output, Item> core::ops::AddAssign<Item> for ImplFolder<Output, Item>
+where
+    ImplFolder<Output, Item>: FolderTrait<Output, Item>,
+{
+    fn add_assign(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Output, Item> Folding for ImplFolder<Output, Item>
+where
+    ImplFolder<Output, Item>: FolderTrait<Output, Item>,
+{
+    type Item = Item;
+    type Output = Output;
+    type Peek<'a>
+        = &'a Output
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Output {
+        self.into_inner()
     }
 }
@@ -5,6 +5,9 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 
+use crate::FoldItem;
+use crate::Folding;
+
 /// The `ImplReduce` type uses the [`ReduceTrait`] for the reduce function.
 ///
 /// This is essentially an [`ImplFolder`](crate::ImplFolder) that doesn't require an initial
@@ -42,8 +45,11 @@
 /// println!("Final max is {}", max.0);
 /// ```
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImplReduce<Item> {
     item: Option<Item>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    count: usize,
 }
 
 /// Trait that provides the `reduce` implementation for [`ImplReduce`]
@@ -54,30 +60,110 @@ pub trait ReduceTrait<Item> {
 }
 
 impl<Item> ImplReduce<Item> {
-    /// Creates a new `ImplReduce` with the provided initial value.
-    pub fn new(initial: Item) -> Self {
+    /// Creates a new `ImplReduce` with the provided initial value, which counts as an item.
+    pub const fn new(initial: Item) -> Self {
         Self {
             item: Some(initial),
+            count: 1,
         }
     }
+    /// Returns the number of items reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no item was reduced in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
     /// Deconstruct self and return the inner value.
     pub fn into_inner(self) -> Option<Item> {
         self.item
     }
+    /// Deconstruct self and return the inner value, or the provided default if there is none.
+    pub fn into_inner_or(self, default: Item) -> Item {
+        self.into_inner().unwrap_or(default)
+    }
+    /// Deconstruct self and return the inner value, or the result of `func` if there is none.
+    pub fn into_inner_or_else<F>(self, func: F) -> Item
+    where
+        F: FnOnce() -> Item,
+    {
+        self.into_inner().unwrap_or_else(func)
+    }
+    /// Deconstruct self and return the inner value, or the default value of `Item` if there is
+    /// none.
+    pub fn into_inner_or_default(self) -> Item
+    where
+        Item: Default,
+    {
+        self.into_inner().unwrap_or_default()
+    }
     /// Returns a reference to the inner value, if there is one.
     pub fn as_ref(&self) -> Option<&Item> {
         self.item.as_ref()
     }
+    /// Empties self, as if it was created with [`ImplReduce::default`].
+    pub fn reset(&mut self) {
+        self.item = None;
+        self.count = 0;
+    }
+    /// Takes the inner value out, leaving self empty.
+    pub fn take(&mut self) -> Option<Item> {
+        self.count = 0;
+        self.item.take()
+    }
+    /// Replaces the inner value with the provided one, which counts as the only item, and
+    /// returns the previous value.
+    pub fn replace(&mut self, item: Item) -> Option<Item> {
+        self.count = 1;
+        self.item.replace(item)
+    }
     /// Reduce the given item into the current self item.
+    ///
+    /// If the reduce function panics, the accumulated value is discarded and self is left
+    /// empty, so that the next item starts a new reduction.
     pub fn reduce(&mut self, item: Item)
     where
         Self: ReduceTrait<Item>,
     {
+        // The count is reset while the reduce function runs, so that a panic leaves self empty:
+        let count = core::mem::take(&mut self.count);
         if let Some(current_item) = self.item.take() {
             self.item = Some(<Self as ReduceTrait<Item>>::reduce(current_item, item));
         } else {
             self.item = Some(item);
         }
+        self.count = count + 1;
+    }
+    /// Reduce the value behind the ref into self.
+    ///
+    /// This function requires the `Clone` trait, as the reduce function takes ownership of
+    /// the item.
+    pub fn reduce_ref(&mut self, item: &Item)
+    where
+        Self: ReduceTrait<Item>,
+        Item: Clone,
+    {
+        self.reduce(item.clone())
+    }
+    /// Reduce the value of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Self: ReduceTrait<Item>,
+    {
+        if let Some(item) = other.item {
+            let count = self.count + other.count;
+            self.reduce(item);
+            self.count = count;
+        }
+    }
+    /// Reduce the value of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Self: ReduceTrait<Item>,
+    {
+        self.merge_from(other);
+        self
     }
 }
 
@@ -96,13 +182,26 @@ where
     }
 }
 
+impl<'a, Item> Extend<&'a Item> for ImplReduce<Item>
+where
+    ImplReduce<Item>: ReduceTrait<Item>,
+    Item: Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
 impl<Item> Default for ImplReduce<Item> {
     fn default() -> Self {
-        Self { item: None }
+        Self {
+            item: None,
+            count: 0,
+        }
     }
 }
 
-impl<Item> std::iter::FromIterator<Item> for ImplReduce<Item>
+impl<Item> core::iter::FromIterator<Item> for ImplReduce<Item>
 where
     ImplReduce<Item>: ReduceTrait<Item>,
 {
@@ -113,15 +212,95 @@ where
     }
 }
 
+impl<'a, Item> core::iter::FromIterator<&'a Item> for ImplReduce<Item>
+where
+    ImplReduce<Item>: ReduceTrait<Item>,
+    Item: Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
 /// Macro that implements [`ReduceTrait`] with the provide closure.
 ///
 /// It extracts the types used in the parameters of the closure to fill in ReduceTrait's
 /// arguments, reducing the amount of repetition.
+///
+/// The closure can be preceded by generic parameters, and its return type followed by a
+/// `where` clause, which are used in the impl.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Longest<T>(Vec<T>);
+///
+/// autofolder_impl_reducetrait!(<T> |a, b| -> Longest<T> where T: core::fmt::Debug {
+///     if b.0.len() > a.0.len() { b } else { a }
+/// });
+///
+/// let longest = vec![Longest(vec![1]), Longest(vec![2, 3]), Longest(vec![4, 5])]
+///     .into_iter()
+///     .collect::<ImplReduce<_>>();
+/// assert_eq!(longest.into_inner(), Some(Longest(vec![2, 3])));
+/// ```
 #[macro_export]
 macro_rules! autofolder_impl_reducetrait{
-    (|$a:ident , $i:ident| -> $item_type: ty $body: block) => {
-        impl ReduceTrait<$item_type> for ImplReduce<$item_type> {
+    (@impl [$($generics: tt)*] [$($where: tt)*] $a:ident, $i:ident, $item_type: ty, $body: block) => {
+        impl<$($generics)*> $crate::ReduceTrait<$item_type> for $crate::ImplReduce<$item_type>
+        where
+            $($where)*
+        {
             fn reduce(mut $a: $item_type, $i: $item_type) -> $item_type $body
         }
+    };
+    (@where [$($generics: tt)*] [$($where: tt)*] [$($sig: tt)*] $body: block) => {
+        $crate::autofolder_impl_reducetrait!(@impl [$($generics)*] [$($where)*] $($sig)* $body);
+    };
+    (@where [$($generics: tt)*] [$($where: tt)*] [$($sig: tt)*] $t: tt $($rest: tt)+) => {
+        $crate::autofolder_impl_reducetrait!(@where [$($generics)*] [$($where)* $t] [$($sig)*] $($rest)+);
+    };
+    (@generics [$($generics: tt)*] |$a:ident , $i:ident $(,)?| -> $item_type: ty where $($rest: tt)+) => {
+        $crate::autofolder_impl_reducetrait!(@where [$($generics)*] [] [$a, $i, $item_type,] $($rest)+);
+    };
+    (@generics [$($generics: tt)*] |$a:ident , $i:ident $(,)?| -> $item_type: ty $body: block) => {
+        $crate::autofolder_impl_reducetrait!(@impl [$($generics)*] [] $a, $i, $item_type, $body);
+    };
+    (< $($rest: tt)+) => {
+        $crate::__autofolder_generics!(autofolder_impl_reducetrait [] [] $($rest)+);
+    };
+    (| $($rest: tt)+) => {
+        $crate::autofolder_impl_reducetrait!(@generics [] | $($rest)+);
+    };
+}
+
+impl<Item> FoldItem<Item> for ImplReduce<Item>
+where
+    ImplReduce<Item>: ReduceTrait<Item>,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for ImplReduce<Item>
+where
+    ImplReduce<Item>: ReduceTrait<Item>,
+{
+    type Item = Item;
+    type Output = Option<Item>;
+    type Peek<'a>
+        = Option<&'a Item>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<Item> {
+        self.into_inner()
     }
 }
@@ -0,0 +1,210 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// Extension trait that bolts autofolders onto iterator pipelines.
+///
+/// It's implemented for all iterators.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut max = Max::default();
+/// let mut min = Min::default();
+///
+/// // Fold the items into `min` as they go by, while collecting the even ones:
+/// let evens = (1..=10)
+///     .tee_fold(&mut min)
+///     .filter(|i| i % 2 == 0)
+///     .collect::<Vec<_>>();
+/// assert_eq!(evens, vec![2, 4, 6, 8, 10]);
+///
+/// // Consume an iterator into `max`, getting the number of items:
+/// assert_eq!(evens.into_iter().fold_into(&mut max), 5);
+///
+/// assert_eq!(min.into_inner(), Some(1));
+/// assert_eq!(max.into_inner(), Some(10));
+/// ```
+pub trait AutofolderIteratorExt: Iterator {
+    /// Consumes the iterator, folding all items into the autofolder, and returns the number of
+    /// items.
+    fn fold_into<F>(self, folder: &mut F) -> usize
+    where
+        Self: Sized,
+        F: FoldItem<Self::Item> + ?Sized,
+    {
+        let mut count = 0;
+        for item in self {
+            folder.fold_item(item);
+            count += 1;
+        }
+        count
+    }
+    /// Creates an iterator that yields the items unchanged, while folding clones of them into
+    /// the autofolder.
+    ///
+    /// For iterators of references, only the references are cloned.
+    fn tee_fold<F>(self, folder: &mut F) -> TeeFold<'_, Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FoldItem<Self::Item> + ?Sized,
+    {
+        TeeFold { iter: self, folder }
+    }
+    /// Creates an iterator that folds each item into the autofolder and yields the result of
+    /// calling `func` with a reference to the autofolder.
+    ///
+    /// The callback can be used to get the partial output from the autofolder, for instance
+    /// with `as_ref` or [`Folding::peek`]. The autofolder can be recovered with
+    /// [`ScanWith::into_inner`].
+    fn scan_with<F, Func, R>(self, folder: F, func: Func) -> ScanWith<Self, F, Func>
+    where
+        Self: Sized,
+        F: FoldItem<Self::Item>,
+        Func: FnMut(&F) -> R,
+    {
+        ScanWith {
+            iter: self,
+            folder,
+            func,
+        }
+    }
+    /// Creates an iterator that folds each item into the autofolder and yields the running
+    /// output.
+    ///
+    /// The running output is obtained by finishing a clone of the autofolder, which can be
+    /// expensive for the ones that accumulate more than a single value; see
+    /// [`AutofolderIteratorExt::scan_with`] for an alternative.
+    fn running<F>(self, folder: F) -> Running<Self, F>
+    where
+        Self: Sized,
+        F: Folding<Item = Self::Item> + Clone,
+    {
+        Running { iter: self, folder }
+    }
+}
+
+impl<I> AutofolderIteratorExt for I where I: Iterator {}
+
+/// Iterator adapter returned by [`AutofolderIteratorExt::tee_fold`].
+pub struct TeeFold<'a, I, F: ?Sized> {
+    iter: I,
+    folder: &'a mut F,
+}
+
+impl<I, F> Iterator for TeeFold<'_, I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FoldItem<I::Item> + ?Sized,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        self.folder.fold_item(item.clone());
+        Some(item)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> core::fmt::Debug for TeeFold<'_, I, F>
+where
+    I: core::fmt::Debug,
+    F: ?Sized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "TeeFold {{ iter: {:?}, folder: {} }}",
+            self.iter,
+            &core::any::type_name::<F>(),
+        )
+    }
+}
+
+/// Iterator adapter returned by [`AutofolderIteratorExt::scan_with`].
+pub struct ScanWith<I, F, Func> {
+    iter: I,
+    folder: F,
+    func: Func,
+}
+
+impl<I, F, Func> ScanWith<I, F, Func> {
+    /// Deconstruct self and return the autofolder.
+    pub fn into_inner(self) -> F {
+        self.folder
+    }
+}
+
+impl<I, F, Func, R> Iterator for ScanWith<I, F, Func>
+where
+    I: Iterator,
+    F: FoldItem<I::Item>,
+    Func: FnMut(&F) -> R,
+{
+    type Item = R;
+    fn next(&mut self) -> Option<R> {
+        let item = self.iter.next()?;
+        self.folder.fold_item(item);
+        Some((self.func)(&self.folder))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, Func> core::fmt::Debug for ScanWith<I, F, Func>
+where
+    I: core::fmt::Debug,
+    F: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "ScanWith {{ iter: {:?}, folder: {:?}, func: {} }}",
+            self.iter,
+            self.folder,
+            &core::any::type_name::<Func>(),
+        )
+    }
+}
+
+/// Iterator adapter returned by [`AutofolderIteratorExt::running`].
+#[derive(Debug, Clone)]
+pub struct Running<I, F> {
+    iter: I,
+    folder: F,
+}
+
+impl<I, F> Running<I, F> {
+    /// Deconstruct self and return the autofolder.
+    pub fn into_inner(self) -> F {
+        self.folder
+    }
+}
+
+impl<I, F> Iterator for Running<I, F>
+where
+    I: Iterator,
+    F: Folding<Item = I::Item> + Clone,
+{
+    type Item = F::Output;
+    fn next(&mut self) -> Option<F::Output> {
+        let item = self.iter.next()?;
+        self.folder.fold_item(item);
+        Some(self.folder.clone().finish())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use serde_json::Value;
+
+/// How [`JsonMerge`] deals with arrays present in both values being merged.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum ArrayMerge {
+    /// The newer array replaces the current one.
+    #[default]
+    Replace,
+    /// The newer array is appended to the current one.
+    Concat,
+}
+
+/// The `JsonMerge` type uses the [`serde_json`] crate to deep-merge
+/// [`Value`](serde_json::Value)s.
+///
+/// When merging a newer value into the current one:
+/// - objects are merged recursively, key by key;
+/// - arrays are replaced or concatenated, according to [`ArrayMerge`];
+/// - any other combination is resolved by keeping the newer value.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+/// use serde_json::json;
+///
+/// // Create an autofolder that layers configuration values:
+/// let mut config = JsonMerge::default();
+///
+/// // We can "reduce-in" individual items.
+/// // (note: as this is the first value, we incorporate it as-is)
+/// config.reduce(json!({"log": {"level": "info", "file": "a.log"}}));
+///
+/// // We can then peek at the running output:
+/// println!("Partial config is {:?}", config.as_ref());
+///
+/// // And still keep on reducing by processing whole iterators:
+/// config.extend(vec![json!({"log": {"level": "debug"}})]);
+///
+/// // And finally consume the autofolder to get the final output value:
+/// let config = config.into_inner().unwrap();
+/// assert_eq!(config, json!({"log": {"level": "debug", "file": "a.log"}}));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct JsonMerge {
+    value: Option<Value>,
+    arrays: ArrayMerge,
+}
+
+impl JsonMerge {
+    /// Creates a new `JsonMerge` with the provided initial value.
+    pub fn new(initial: Value) -> Self {
+        Self {
+            value: Some(initial),
+            arrays: ArrayMerge::default(),
+        }
+    }
+    /// Sets the strategy used to merge arrays.
+    pub fn with_arrays(mut self, arrays: ArrayMerge) -> Self {
+        self.arrays = arrays;
+        self
+    }
+    /// Deconstruct self and return the inner value.
+    pub fn into_inner(self) -> Option<Value> {
+        self.value
+    }
+    /// Returns a reference to the inner value, if there is one.
+    pub fn as_ref(&self) -> Option<&Value> {
+        self.value.as_ref()
+    }
+    /// Deep-merge the given value into the current one.
+    pub fn reduce(&mut self, value: Value) {
+        if let Some(current) = self.value.as_mut() {
+            deep_merge(current, value, self.arrays);
+        } else {
+            self.value = Some(value);
+        }
+    }
+    /// Deep-merge the value of `other` into self, using the array strategy of self.
+    pub fn merge_from(&mut self, other: Self) {
+        if let Some(value) = other.value {
+            self.reduce(value);
+        }
+    }
+    /// Deep-merge the value of `other` into self and return the result, using the array
+    /// strategy of self.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+}
+
+fn deep_merge(current: &mut Value, newer: Value, arrays: ArrayMerge) {
+    match (current, newer) {
+        (Value::Object(current), Value::Object(newer)) => {
+            for (key, value) in newer {
+                match current.get_mut(&key) {
+                    Some(existing) => deep_merge(existing, value, arrays),
+                    None => {
+                        current.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(current), Value::Array(mut newer)) if arrays == ArrayMerge::Concat => {
+            current.append(&mut newer);
+        }
+        (current, newer) => {
+            *current = newer;
+        }
+    }
+}
+
+impl From<Value> for JsonMerge {
+    fn from(value: Value) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Extend<Value> for JsonMerge {
+    fn extend<It: IntoIterator<Item = Value>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|v| self.reduce(v));
+    }
+}
+
+impl std::iter::FromIterator<Value> for JsonMerge {
+    fn from_iter<It: IntoIterator<Item = Value>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl FoldItem<Value> for JsonMerge {
+    fn fold_item(&mut self, item: Value) {
+        self.reduce(item)
+    }
+}
+
+impl Folding for JsonMerge {
+    type Item = Value;
+    type Output = Option<Value>;
+    type Peek<'a>
+        = Option<&'a Value>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<Value> {
+        self.into_inner()
+    }
+}
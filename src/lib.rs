@@ -2,6 +2,7 @@
 // This file is subject to the terms and conditions defined in
 // file 'LICENSE', which is part of this source code package.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
@@ -62,11 +63,22 @@
 //! autofolder.fold(value);
 //! ```
 //!
+//! Autofolders that implement [`FromIterator`](std::iter::FromIterator) can also be collected from iterators of
+//! [`Result`]s, just like [`Vec`]; the collection stops at the first error:
+//!
+//! ```rust
+//! # use autofolder::*;
+//! let max = vec!["3", "7", "5"].into_iter().map(str::parse::<u32>).collect::<Result<Max<_>, _>>();
+//! assert_eq!(max.unwrap().into_inner(), Some(7));
+//! let max = vec!["3", "x", "5"].into_iter().map(str::parse::<u32>).collect::<Result<Max<_>, _>>();
+//! assert!(max.is_err());
+//! ```
+//!
 //! # Types of folders
 //!
 //! ## By binding strategy
 //!
-//! This crate provides two types of autofolders with different function binding strategies:
+//! This crate provides the following types of autofolders with different function binding strategies:
 //! - [`DynFolder`]: the folding function is provided as a closure
 //!   that is kept in a struct field. Characteristics:
 //!   - Folding function can use any type, builtin or otherwise.
@@ -82,6 +94,23 @@
 //!     [`.collect()`](Iterator::collect) if the `output` type implements [`Default`]
 //!   - Slighly more efficient than `DynFolder` due to monomorphization, which turns `.fold`
 //!     calls into direct function calls.
+//! - [`FnFolder`]: the folding function is a plain function pointer provided by a marker type.
+//!   - Can be used with [`.collect()`](Iterator::collect) if the `output` type implements
+//!     [`Default`], as with `ImplFolder`, without the limitation on the types used.
+//!   - The [`autofolder_fn`] macro defines the marker type for a function or non-capturing
+//!     closure.
+//! - [`BoxedDynFolder`] and [`BoxedSyncDynFolder`]: `DynFolder` with a boxed folding function.
+//!   - Folders with different closures have the same type, and can be stored together.
+//! - [`DynFolderMut`]: variant of `DynFolder` whose folding function mutates the output in place.
+//!   - The folding function has the `FnMut(&mut Output, Item)` signature, and can thus keep
+//!     auxiliary state of its own.
+//!   - Avoids moving large outputs through the folding function on every item.
+//! - [`TryDynFolder`]: variant of `DynFolder` whose folding function is fallible.
+//!   - The folding function has the `Fn(&Output, Item) -> Result<Output, E>` signature.
+//!   - `try_fold` and `try_extend` stop at the first error, keeping the last good output.
+//! - [`DynFolderUntil`]: variant of `DynFolder` whose folding function can stop the folding early.
+//!   - The folding function returns a `ControlFlow<Output, Output>`.
+//!   - After a `Break`, further items are ignored and `extend` stops consuming the iterator.
 //!
 //! ## By aggregation strategy
 //!
@@ -107,8 +136,134 @@
 //! - [`Min`]: container that keeps only the minimal value iterated, as given by [`std::cmp::PartialOrd`].
 //! - [`Max`]: analogous to `Max`, but for the max value.
 //! - [`MinMax`]: container that keeps a tuple with both the min and max values.
+//!   - Since 0.6.0 `MinMax` is a struct, no longer an enum; the `None`, `Single` and `Both`
+//!     variants moved to [`Extremes`], returned by [`MinMax::extremes`] and
+//!     [`MinMax::into_extremes`].
+//! - [`MinFloat`] and [`MaxFloat`]: analogous to `Min` and `Max`, but for `f32` and `f64`, with
+//!   an explicit [`NanPolicy`] that ignores, propagates or totally orders `NaN`s.
+//! - [`ClosestTo`]: container that keeps the item closest to a target, as given by a distance
+//!   function; see also `levenshtein` (feature `levenshtein`) for strings.
+//! - [`Mean`]: container that keeps the running mean of the values iterated, without storing them.
+//! - [`Stats`]: container that keeps the count, mean and variance of the values iterated, using
+//!   Welford's online algorithm.
+//! - [`LinReg`]: container that keeps the covariance, correlation and least-squares line of the
+//!   `(x, y)` pairs iterated, using the same online algorithm.
+//! - [`SumKahan`]: container that sums `f32` or `f64` values using compensated summation, which
+//!   keeps long float reductions accurate.
+//! - [`Adder`], [`Multiplier`], [`BitAnder`], [`BitOrer`] and [`BitXorer`]: containers that
+//!   accumulate the values iterated with the corresponding operator-assign trait.
+//! - [`CheckedAdder`], [`SaturatingAdder`] and [`WrappingAdder`]: containers that sum primitive
+//!   integers, handling overflows explicitly.
+//! - [`Any`] and [`All`]: containers that check if any/all the values iterated satisfy a
+//!   predicate, with an `extend` that stops consuming the iterator once the result is known.
+//! - [`Product`] and [`CheckedProduct`]: containers that multiply the values iterated; `Product`
+//!   is an alias for `Multiplier`, and the checked variant works with primitive integers and
+//!   records overflows.
+//! - [`MinBy`] and [`MaxBy`]: containers that keep the value with the smallest/largest key, as
+//!   given by a key-extraction function, caching the key of the current value.
+//! - [`ArgMin`] and [`ArgMax`]: containers that keep the min/max value along with the index at
+//!   which it was iterated.
+//! - [`MinMaxByKey`]: container that keeps both the `(key, value)` pairs with the min and max
+//!   keys, comparing only the keys.
+//! - [`ElementwiseMinMax`]: container that keeps the min and max of each component of the `[T; N]`
+//!   arrays iterated, like the bounding box of a set of points.
+//!
+//! ## Collection autofolders
+//!
+//! Some autofolders accumulate more than a single value:
+//! - [`HeapFold`]: container that keeps the items in a [`BinaryHeap`](std::collections::BinaryHeap),
+//!   optionally bounded.
+//! - [`Median`]: container that keeps the exact running median of the items in two binary heaps.
+//! - [`DistinctSorted`]: container that keeps the distinct items in a
+//!   [`BTreeSet`](std::collections::BTreeSet), in ascending order.
+//! - [`SortedMerge`]: reducer that k-way merges already-sorted runs into a single sorted one.
+//! - [`UnionFold`] and [`IntersectionFold`]: reducers that keep the union and intersection of
+//!   the sets reduced in, respectively.
+//! - [`MapMerge`]: reducer that merges [`HashMap`](std::collections::HashMap)s, resolving key collisions
+//!   with a user-supplied function.
+//! - [`TopKScored`]: container that keeps the `k` highest-scoring items along with their scores.
+//! - [`TopK`] and [`BottomK`]: containers that keep the `k` largest and smallest items,
+//!   respectively.
+//! - [`MinMaxN`]: container that keeps both the `n` smallest and the `n` largest items.
+//! - [`Mode`]: container that counts the occurrences of the items in a
+//!   [`HashMap`](std::collections::HashMap) and keeps the most frequent one.
+//! - [`Counter`]: container that counts the occurrences of each item in a
+//!   [`HashMap`](std::collections::HashMap), and returns the most common ones.
+//! - [`CountDistinct`]: container that counts the distinct items, keeping them in a
+//!   [`HashSet`](std::collections::HashSet).
+//! - [`Histogram`]: container that counts the values per bin, given by explicit or uniform
+//!   bin edges, also tracking underflow and overflow.
+//! - [`Reservoir`]: container that keeps a uniform random sample of `k` items, using reservoir
+//!   sampling; the `rand` feature allows using any `rand` generator, wrapped in `RandRng`.
+//! - [`Windowed`]: container that keeps the last `n` items in a ring buffer, and reduces the
+//!   current window on demand, for rolling sums, minimums, etc.
+//! - [`WindowedMinMax`]: container that keeps the min and max of the items within a time window,
+//!   evicting the expired ones, using monotonic deques.
+//! - [`GroupFolder`]: container that folds `(key, item)` pairs into a per-key autofolder, for
+//!   instance to get the min per category in a single pass.
+//!
+//! ## Feature-gated autofolders
+//!
+//! The following autofolders require enabling the corresponding crate feature:
+//! - `SumNum`, `ProductNum` and `MeanNum` (feature `num`): containers that sum, multiply and
+//!   average the items using the `num` traits, starting from zero and one where applicable.
+//! - `JsonMerge` (feature `json`): reducer that deep-merges `serde_json` values.
+//! - `RoaringFold` (feature `roaring`): folder that unites IDs into a `roaring` bitmap.
+//! - `ApproxDistinct` (feature `hyperloglog`): reducer that estimates the number of distinct items
+//!   with HyperLogLog, using constant memory.
+//! - `TDigest` (feature `tdigest`): reducer that estimates quantiles with a t-digest, which can be
+//!   merged across shards.
+//! - `Snapshot` (feature `snapshot`): not an autofolder, but a trait that writes and reads the state
+//!   of autofolders in a compact binary format; the `serde` feature provides the underlying
+//!   `Serialize`/`Deserialize` implementations.
+//! - `futures` feature: not an autofolder either, but implementations of `futures::Sink` for
+//!   [`DynFolder`], [`ImplFolder`], [`DynReduce`], [`ImplReduce`] and the min/max types, which
+//!   allow forwarding a stream directly into them.
+//! - `derive` feature: provides the `#[fold]` attribute for free functions and `#[derive(Reduce)]`
+//!   for types, which implement [`FolderTrait`] and [`ReduceTrait`] respectively, carrying over
+//!   generic parameters; they are a more flexible alternative to the `autofolder_impl_*` macros.
+//!
+//! ## Generic code
+//!
+//! All autofolders implement the [`Folding`] trait, which allows writing code that is generic
+//! over the folding strategy: items are folded in with [`FoldItem::fold_item`], the running
+//! output is available with [`Folding::peek`], and the final output with [`Folding::finish`].
+//!
+//! The object-safe [`FoldItem`] supertrait can also be used to feed autofolders chosen at
+//! runtime; [`FanOut`] does that by broadcasting each item to a set of them.
+//!
+//! Tuples of autofolders that share the item type are autofolders too, that fold each item into
+//! all members; [`MultiFold`] wraps them to also provide `extend` and `collect`, which allows
+//! computing, for instance, the min, max and sum of an iterator in a single pass.
+//!
+//! The [`AutofolderIteratorExt`] extension trait bolts autofolders onto existing iterator
+//! pipelines: `fold_into` consumes an iterator into an autofolder, `tee_fold` folds the items
+//! into an autofolder as they go by, and `running` and `scan_with` yield the partial output of
+//! an autofolder after each item.
+//!
+//! [`fold_chunks_scoped`] folds the chunks of a slice in parallel with scoped threads, and
+//! combines the partial autofolders with their `merge` method, without depending on an external
+//! thread pool.
+//!
+//! The [`FoldingExt`] extension trait adapts autofolders to other item types instead: `premap`
+//! transforms the items before folding them, and `filter` skips the ones that don't match a
+//! predicate, so that a `Max<f64>` can consume log records directly, for instance. It also
+//! provides `fold_opt` and `extend_flatten`, that fold `Option`s and skip the `None`s.
+//!
+//! [`DynFolder`], [`ImplFolder`], [`Min`], [`Max`], [`MinMax`] and the operator-assign
+//! autofolders, like [`Adder`], also implement [`AddAssign`](core::ops::AddAssign) for their
+//! items, so that `max += value` folds `value` into `max`.
+//!
+//! ## `no_std` support
+//!
+//! The crate is `no_std` compatible when the default `std` feature is disabled. The collection
+//! autofolders, [`FanOut`], [`fold_chunks_scoped`] and the `json`, `roaring`, `levenshtein`,
+//! `hyperloglog`, `tdigest`, `rand` and `snapshot` features require `std`.
 //!
 
+mod folding;
+pub use self::folding::*;
+
 mod dynfolder;
 pub use self::dynfolder::*;
 
@@ -118,14 +273,213 @@ pub use self::dynreduce::*;
 mod implfolder;
 pub use self::implfolder::*;
 
+mod fnfolder;
+pub use self::fnfolder::*;
+
 mod implreduce;
 pub use self::implreduce::*;
 
+#[cfg(feature = "derive")]
+pub use autofolder_derive::{fold, Reduce};
+
 mod minmax;
 pub use self::minmax::*;
 
+mod minmaxfloat;
+pub use self::minmaxfloat::*;
+
+#[cfg(feature = "std")]
+mod heapfold;
+#[cfg(feature = "std")]
+pub use self::heapfold::*;
+
+#[cfg(feature = "std")]
+mod median;
+#[cfg(feature = "std")]
+pub use self::median::*;
+
 #[cfg(feature = "num")]
 mod minmaxnum;
 
 #[cfg(feature = "num")]
 pub use self::minmaxnum::*;
+
+#[cfg(feature = "num")]
+mod sumnum;
+#[cfg(feature = "num")]
+pub use self::sumnum::*;
+
+#[cfg(feature = "num")]
+mod meannum;
+#[cfg(feature = "num")]
+pub use self::meannum::*;
+
+#[cfg(feature = "std")]
+mod distinctsorted;
+#[cfg(feature = "std")]
+pub use self::distinctsorted::*;
+
+#[cfg(feature = "std")]
+mod sortedmerge;
+#[cfg(feature = "std")]
+pub use self::sortedmerge::*;
+
+#[cfg(feature = "std")]
+mod setfold;
+#[cfg(feature = "std")]
+pub use self::setfold::*;
+
+#[cfg(feature = "std")]
+mod mapmerge;
+#[cfg(feature = "std")]
+pub use self::mapmerge::*;
+
+#[cfg(feature = "json")]
+mod jsonmerge;
+#[cfg(feature = "json")]
+pub use self::jsonmerge::*;
+
+#[cfg(feature = "roaring")]
+mod roaringfold;
+#[cfg(feature = "roaring")]
+pub use self::roaringfold::*;
+
+mod closestto;
+pub use self::closestto::*;
+
+#[cfg(feature = "std")]
+mod topkscored;
+#[cfg(feature = "std")]
+pub use self::topkscored::*;
+
+#[cfg(feature = "snapshot")]
+mod snapshot;
+#[cfg(feature = "snapshot")]
+pub use self::snapshot::*;
+
+mod dynfoldermut;
+pub use self::dynfoldermut::*;
+
+#[cfg(feature = "std")]
+mod fanout;
+#[cfg(feature = "std")]
+pub use self::fanout::*;
+
+#[cfg(feature = "futures")]
+mod sink;
+
+mod trydynfolder;
+pub use self::trydynfolder::*;
+
+mod dynfolderuntil;
+pub use self::dynfolderuntil::*;
+
+mod mean;
+pub use self::mean::*;
+
+mod stats;
+pub use self::stats::*;
+
+mod linreg;
+pub use self::linreg::*;
+
+mod sumkahan;
+pub use self::sumkahan::*;
+
+mod opassign;
+pub use self::opassign::*;
+
+mod adder;
+pub use self::adder::*;
+
+mod anyall;
+pub use self::anyall::*;
+
+mod product;
+pub use self::product::*;
+
+mod minmaxby;
+pub use self::minmaxby::*;
+
+mod argminmax;
+pub use self::argminmax::*;
+
+mod minmaxbykey;
+pub use self::minmaxbykey::*;
+
+mod minmaxelementwise;
+pub use self::minmaxelementwise::*;
+
+#[cfg(feature = "std")]
+mod topk;
+#[cfg(feature = "std")]
+pub use self::topk::*;
+
+#[cfg(feature = "std")]
+mod minmaxn;
+#[cfg(feature = "std")]
+pub use self::minmaxn::*;
+
+#[cfg(feature = "std")]
+mod mode;
+#[cfg(feature = "std")]
+pub use self::mode::*;
+
+#[cfg(feature = "std")]
+mod counter;
+#[cfg(feature = "std")]
+pub use self::counter::*;
+
+#[cfg(feature = "std")]
+mod countdistinct;
+#[cfg(feature = "std")]
+pub use self::countdistinct::*;
+
+#[cfg(feature = "hyperloglog")]
+mod approxdistinct;
+#[cfg(feature = "hyperloglog")]
+pub use self::approxdistinct::*;
+
+#[cfg(feature = "tdigest")]
+mod tdigest;
+#[cfg(feature = "tdigest")]
+pub use self::tdigest::*;
+
+#[cfg(feature = "std")]
+mod histogram;
+#[cfg(feature = "std")]
+pub use self::histogram::*;
+
+#[cfg(feature = "std")]
+mod reservoir;
+#[cfg(feature = "std")]
+pub use self::reservoir::*;
+
+#[cfg(feature = "std")]
+mod windowed;
+#[cfg(feature = "std")]
+pub use self::windowed::*;
+
+#[cfg(feature = "std")]
+mod windowedminmax;
+#[cfg(feature = "std")]
+pub use self::windowedminmax::*;
+
+#[cfg(feature = "std")]
+mod groupfolder;
+#[cfg(feature = "std")]
+pub use self::groupfolder::*;
+
+mod multifold;
+pub use self::multifold::*;
+
+mod iterext;
+pub use self::iterext::*;
+
+#[cfg(feature = "std")]
+mod scoped;
+#[cfg(feature = "std")]
+pub use self::scoped::*;
+
+mod combinators;
+pub use self::combinators::*;
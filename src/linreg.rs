@@ -0,0 +1,277 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use core::marker;
+
+/// The `LinReg` type keeps the running covariance and simple linear regression of the iterated
+/// `(x, y)` pairs, converted to `f64`, using an online algorithm.
+///
+/// This extends the Welford algorithm of [`Stats`](crate::Stats) to pairs: the means, variances
+/// and covariance are updated incrementally, without storing the pairs, in a numerically
+/// stable way. Partial regressions can be combined with [`LinReg::merge`].
+///
+/// The correlation requires the `std` feature, for the square root.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that fits a line to (u32, f64) pairs.
+/// let mut linreg = LinReg::<u32, f64>::default();
+///
+/// // We can "reduce-in" individual pairs:
+/// linreg.reduce((0, 1.0));
+///
+/// // We can then peek at the running statistics:
+/// println!("Partial mean of y is {:?}", linreg.mean_y());
+///
+/// // And still keep on folding by processing whole iterators:
+/// linreg.extend(vec![(1, 3.0), (2, 5.0), (3, 7.0)]);
+///
+/// // And finally query the regression:
+/// assert_eq!(linreg.count(), 4);
+/// assert_eq!(linreg.slope(), Some(2.0));
+/// assert_eq!(linreg.intercept(), Some(1.0));
+/// assert_eq!(linreg.predict(10.0), Some(21.0));
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinReg<X, Y> {
+    count: usize,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    c_xy: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    item: marker::PhantomData<(X, Y)>,
+}
+
+impl<X, Y> LinReg<X, Y> {
+    /// Creates a new empty `LinReg`.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+            c_xy: 0.0,
+            item: marker::PhantomData,
+        }
+    }
+    /// Returns the number of pairs reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns the mean of the `x` values, if any pair was reduced in.
+    pub fn mean_x(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean_x)
+        }
+    }
+    /// Returns the mean of the `y` values, if any pair was reduced in.
+    pub fn mean_y(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean_y)
+        }
+    }
+    /// Returns the population variance of the `x` values, if any pair was reduced in.
+    pub fn variance_x(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.m2_x / self.count as f64)
+        }
+    }
+    /// Returns the population variance of the `y` values, if any pair was reduced in.
+    pub fn variance_y(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.m2_y / self.count as f64)
+        }
+    }
+    /// Returns the population covariance, if any pair was reduced in.
+    pub fn covariance(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.c_xy / self.count as f64)
+        }
+    }
+    /// Returns the sample covariance, if at least two pairs were reduced in.
+    pub fn sample_covariance(&self) -> Option<f64> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.c_xy / (self.count - 1) as f64)
+        }
+    }
+    /// Returns the Pearson correlation coefficient, if the `x` and `y` values are not constant.
+    #[cfg(feature = "std")]
+    pub fn correlation(&self) -> Option<f64> {
+        let denominator = (self.m2_x * self.m2_y).sqrt();
+        if denominator == 0.0 {
+            None
+        } else {
+            Some(self.c_xy / denominator)
+        }
+    }
+    /// Returns the slope of the least-squares line, if the `x` values are not constant.
+    pub fn slope(&self) -> Option<f64> {
+        if self.m2_x == 0.0 {
+            None
+        } else {
+            Some(self.c_xy / self.m2_x)
+        }
+    }
+    /// Returns the intercept of the least-squares line, if the `x` values are not constant.
+    pub fn intercept(&self) -> Option<f64> {
+        self.slope().map(|slope| self.mean_y - slope * self.mean_x)
+    }
+    /// Returns the `y` value of the least-squares line at `x`, if the `x` values are not
+    /// constant.
+    pub fn predict(&self, x: f64) -> Option<f64> {
+        self.slope()
+            .map(|slope| self.mean_y + slope * (x - self.mean_x))
+    }
+    /// Updates the statistics with the given pair.
+    pub fn reduce(&mut self, item: (X, Y))
+    where
+        X: Into<f64>,
+        Y: Into<f64>,
+    {
+        let (x, y) = (item.0.into(), item.1.into());
+        self.count += 1;
+        let count = self.count as f64;
+        let delta_x = x - self.mean_x;
+        let delta_y = y - self.mean_y;
+        self.mean_x += delta_x / count;
+        self.mean_y += delta_y / count;
+        self.m2_x += delta_x * (x - self.mean_x);
+        self.m2_y += delta_y * (y - self.mean_y);
+        self.c_xy += delta_x * (y - self.mean_y);
+    }
+    /// Updates the statistics with the pair behind the ref.
+    ///
+    /// This function requires the `Clone` trait.
+    pub fn reduce_ref(&mut self, item: &(X, Y))
+    where
+        X: Into<f64> + Clone,
+        Y: Into<f64> + Clone,
+    {
+        self.reduce(item.clone())
+    }
+    /// Combine the statistics of `other` into self.
+    pub fn merge_from(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta_x = other.mean_x - self.mean_x;
+        let delta_y = other.mean_y - self.mean_y;
+        let weight = other.count as f64 / count as f64;
+        let cross = self.count as f64 * weight;
+        self.mean_x += delta_x * weight;
+        self.mean_y += delta_y * weight;
+        self.m2_x += other.m2_x + delta_x * delta_x * cross;
+        self.m2_y += other.m2_y + delta_y * delta_y * cross;
+        self.c_xy += other.c_xy + delta_x * delta_y * cross;
+        self.count = count;
+    }
+    /// Combine the statistics of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<X, Y> Default for LinReg<X, Y> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<X, Y> Extend<(X, Y)> for LinReg<X, Y>
+where
+    X: Into<f64>,
+    Y: Into<f64>,
+{
+    fn extend<It: IntoIterator<Item = (X, Y)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, X, Y> Extend<&'a (X, Y)> for LinReg<X, Y>
+where
+    X: Into<f64> + Clone + 'a,
+    Y: Into<f64> + Clone + 'a,
+{
+    fn extend<It: IntoIterator<Item = &'a (X, Y)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<X, Y> core::iter::FromIterator<(X, Y)> for LinReg<X, Y>
+where
+    X: Into<f64>,
+    Y: Into<f64>,
+{
+    fn from_iter<It: IntoIterator<Item = (X, Y)>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, X, Y> core::iter::FromIterator<&'a (X, Y)> for LinReg<X, Y>
+where
+    X: Into<f64> + Clone + 'a,
+    Y: Into<f64> + Clone + 'a,
+{
+    fn from_iter<It: IntoIterator<Item = &'a (X, Y)>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<X, Y> FoldItem<(X, Y)> for LinReg<X, Y>
+where
+    X: Into<f64>,
+    Y: Into<f64>,
+{
+    fn fold_item(&mut self, item: (X, Y)) {
+        self.reduce(item)
+    }
+}
+
+impl<X, Y> Folding for LinReg<X, Y>
+where
+    X: Into<f64>,
+    Y: Into<f64>,
+{
+    type Item = (X, Y);
+    type Output = Self;
+    type Peek<'a>
+        = &'a Self
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self
+    }
+    fn finish(self) -> Self {
+        self
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The `MapMerge` type reduces [`HashMap`]s into a single one, using a struct field for the
+/// function that resolves key collisions.
+///
+/// When a key is present in both the current map and the one being reduced in, the function
+/// gets the current value and the new one, and returns the value to keep.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+/// use std::collections::HashMap;
+///
+/// // Create an autofolder that merges word counts by adding them up:
+/// let mut counts = MapMerge::<&str, usize, _>::new(|a, b| a + b);
+///
+/// // We can "reduce-in" individual maps:
+/// counts.reduce(vec![("a", 1), ("b", 2)].into_iter().collect());
+///
+/// // Or individual entries:
+/// counts.reduce_entry("a", 3);
+///
+/// // We can then peek at the running output:
+/// println!("Partial count of a is {:?}", counts.as_ref().get("a"));
+///
+/// // And still keep on reducing by processing whole iterators:
+/// counts.extend(vec![vec![("b", 1)].into_iter().collect::<HashMap<_, _>>()]);
+///
+/// // And finally consume the autofolder to get the final output value:
+/// println!("Final counts are {:?}", counts.into_inner());
+/// ```
+#[derive(Clone)]
+pub struct MapMerge<K, V, Func> {
+    map: HashMap<K, V>,
+    function: Func,
+}
+
+impl<K, V, Func> MapMerge<K, V, Func> {
+    /// Creates a new empty `MapMerge` with the provided collision function.
+    pub fn new(func: Func) -> Self
+    where
+        Func: Fn(V, V) -> V,
+    {
+        Self {
+            map: HashMap::new(),
+            function: func,
+        }
+    }
+    /// Creates a new `MapMerge` with the provided initial map and collision function.
+    pub fn with_initial(initial: HashMap<K, V>, func: Func) -> Self
+    where
+        Func: Fn(V, V) -> V,
+    {
+        Self {
+            map: initial,
+            function: func,
+        }
+    }
+    /// Returns the contained map, consuming the self value.
+    pub fn into_inner(self) -> HashMap<K, V> {
+        self.map
+    }
+    /// Reduce an individual key-value pair into self.
+    pub fn reduce_entry(&mut self, key: K, value: V)
+    where
+        K: Eq + Hash,
+        Func: Fn(V, V) -> V,
+    {
+        match self.map.entry(key) {
+            Entry::Occupied(entry) => {
+                let (key, current) = entry.remove_entry();
+                let new = (self.function)(current, value);
+                self.map.insert(key, new);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+    }
+    /// Reduce all entries of the given map into self.
+    pub fn reduce(&mut self, map: HashMap<K, V>)
+    where
+        K: Eq + Hash,
+        Func: Fn(V, V) -> V,
+    {
+        if self.map.is_empty() {
+            self.map = map;
+        } else {
+            map.into_iter().for_each(|(k, v)| self.reduce_entry(k, v));
+        }
+    }
+    /// Reduce the map of `other` into self, using the collision function of self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        K: Eq + Hash,
+        Func: Fn(V, V) -> V,
+    {
+        self.reduce(other.map);
+    }
+    /// Reduce the map of `other` into self and return the result, using the collision
+    /// function of self.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        K: Eq + Hash,
+        Func: Fn(V, V) -> V,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<K, V, Func> std::fmt::Debug for MapMerge<K, V, Func>
+where
+    K: std::fmt::Debug,
+    V: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MapMerge::<{}, {}, _> {{ map: {:?}, function: {} }}",
+            &std::any::type_name::<K>(),
+            &std::any::type_name::<V>(),
+            self.map,
+            &std::any::type_name::<Func>(),
+        )
+    }
+}
+
+impl<K, V, Func> From<Func> for MapMerge<K, V, Func>
+where
+    Func: Fn(V, V) -> V,
+{
+    fn from(func: Func) -> Self {
+        Self::new(func)
+    }
+}
+
+impl<K, V, Func> AsRef<HashMap<K, V>> for MapMerge<K, V, Func> {
+    fn as_ref(&self) -> &HashMap<K, V> {
+        &self.map
+    }
+}
+
+impl<K, V, Func> Extend<HashMap<K, V>> for MapMerge<K, V, Func>
+where
+    K: Eq + Hash,
+    Func: Fn(V, V) -> V,
+{
+    fn extend<It: IntoIterator<Item = HashMap<K, V>>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|m| self.reduce(m));
+    }
+}
+
+impl<K, V, Func> Extend<(K, V)> for MapMerge<K, V, Func>
+where
+    K: Eq + Hash,
+    Func: Fn(V, V) -> V,
+{
+    fn extend<It: IntoIterator<Item = (K, V)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|(k, v)| self.reduce_entry(k, v));
+    }
+}
+
+impl<K, V, Func> FoldItem<HashMap<K, V>> for MapMerge<K, V, Func>
+where
+    K: Eq + Hash,
+    Func: Fn(V, V) -> V,
+{
+    fn fold_item(&mut self, item: HashMap<K, V>) {
+        self.reduce(item)
+    }
+}
+
+impl<K, V, Func> Folding for MapMerge<K, V, Func>
+where
+    K: Eq + Hash,
+    Func: Fn(V, V) -> V,
+{
+    type Item = HashMap<K, V>;
+    type Output = HashMap<K, V>;
+    type Peek<'a>
+        = &'a HashMap<K, V>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> HashMap<K, V> {
+        self.into_inner()
+    }
+}
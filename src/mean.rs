@@ -0,0 +1,174 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use core::marker;
+
+/// The `Mean` type keeps the running mean of the iterated values, converted to `f64`.
+///
+/// The mean is updated incrementally, without storing the items or their sum.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the mean of u32 items.
+/// let mut mean = Mean::<u32>::default();
+///
+/// // We can "reduce-in" individual items:
+/// mean.reduce(2);
+///
+/// // We can then peek at the running output:
+/// println!("Partial mean is {:?}", mean.mean());
+///
+/// // And still keep on folding by processing whole iterators:
+/// mean.extend((1..=5));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// let mean = mean.into_inner().unwrap();
+/// println!("Final mean is {}", mean);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mean<Item> {
+    count: usize,
+    mean: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    item: marker::PhantomData<Item>,
+}
+
+impl<Item> Mean<Item> {
+    /// Creates a new empty `Mean`.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            item: marker::PhantomData,
+        }
+    }
+    /// Deconstruct self and return the mean, if any item was reduced in.
+    pub fn into_inner(self) -> Option<f64> {
+        self.mean()
+    }
+    /// Returns the mean, if any item was reduced in.
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean)
+        }
+    }
+    /// Returns the number of items reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Updates the mean with the given item.
+    pub fn reduce(&mut self, item: Item)
+    where
+        Item: Into<f64>,
+    {
+        self.count += 1;
+        self.mean += (item.into() - self.mean) / self.count as f64;
+    }
+    /// Updates the mean with the item behind the ref.
+    ///
+    /// This function requires the `Clone` trait.
+    pub fn reduce_ref(&mut self, item: &Item)
+    where
+        Item: Into<f64> + Clone,
+    {
+        self.reduce(item.clone())
+    }
+    /// Combine the mean of `other` into self.
+    pub fn merge_from(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        self.mean += (other.mean - self.mean) * (other.count as f64 / count as f64);
+        self.count = count;
+    }
+    /// Combine the mean of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for Mean<Item> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> Extend<Item> for Mean<Item>
+where
+    Item: Into<f64>,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for Mean<Item>
+where
+    Item: Into<f64> + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<Item> core::iter::FromIterator<Item> for Mean<Item>
+where
+    Item: Into<f64>,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> core::iter::FromIterator<&'a Item> for Mean<Item>
+where
+    Item: Into<f64> + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for Mean<Item>
+where
+    Item: Into<f64>,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for Mean<Item>
+where
+    Item: Into<f64>,
+{
+    type Item = Item;
+    type Output = Option<f64>;
+    type Peek<'a>
+        = Option<f64>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.mean()
+    }
+    fn finish(self) -> Option<f64> {
+        self.into_inner()
+    }
+}
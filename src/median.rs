@@ -0,0 +1,241 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `Median` type keeps the exact running median of the iterated values, using the
+/// [`std::cmp::Ord`] trait.
+///
+/// The items are kept in two [`BinaryHeap`]s: a max-heap with the lower half and a min-heap with
+/// the upper half, so that the median is always available in constant time and each item is
+/// reduced in in logarithmic time. All items are stored.
+///
+/// When the number of items is even there are two middle values: [`Median::low`], which is also
+/// the one returned by [`Median::as_ref`] and [`Median::into_inner`], and [`Median::high`].
+/// [`Median::average`] returns the average of both, for numeric items.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the median of u32 items.
+/// let mut median = Median::<u32>::default();
+///
+/// // We can "reduce-in" individual items:
+/// median.reduce(7);
+///
+/// // We can then peek at the running output:
+/// println!("Partial median is {:?}", median.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// median.extend(vec![1, 9, 4]);
+/// assert_eq!(median.low(), Some(&4));
+/// assert_eq!(median.high(), Some(&7));
+/// assert_eq!(median.average(), Some(5.5));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// median.reduce(5);
+/// assert_eq!(median.into_inner(), Some(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Median<Item> {
+    // Invariant: `lower` has the same number of items as `upper`, or one more.
+    lower: BinaryHeap<Item>,
+    upper: BinaryHeap<Reverse<Item>>,
+}
+
+impl<Item> Median<Item> {
+    /// Creates a new empty `Median`.
+    pub const fn new() -> Self
+    where
+        Item: Ord,
+    {
+        Self {
+            lower: BinaryHeap::new(),
+            upper: BinaryHeap::new(),
+        }
+    }
+    /// Returns the number of items reduced in.
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+    /// Returns `true` if no item was reduced in.
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+    /// Returns a reference to the median, which is the lower of the two middle values when the
+    /// number of items is even.
+    pub fn as_ref(&self) -> Option<&Item>
+    where
+        Item: Ord,
+    {
+        self.low()
+    }
+    /// Returns a reference to the lower median: the middle value, or the lower of the two middle
+    /// values when the number of items is even.
+    pub fn low(&self) -> Option<&Item>
+    where
+        Item: Ord,
+    {
+        self.lower.peek()
+    }
+    /// Returns a reference to the upper median: the middle value, or the higher of the two middle
+    /// values when the number of items is even.
+    pub fn high(&self) -> Option<&Item>
+    where
+        Item: Ord,
+    {
+        if self.upper.len() == self.lower.len() {
+            self.upper.peek().map(|Reverse(item)| item)
+        } else {
+            self.lower.peek()
+        }
+    }
+    /// Returns the median converted to `f64`, which is the average of the two middle values when
+    /// the number of items is even.
+    pub fn average(&self) -> Option<f64>
+    where
+        Item: Ord + Into<f64> + Clone,
+    {
+        let low = self.low()?.clone().into();
+        let high = self.high()?.clone().into();
+        Some(low + (high - low) / 2.0)
+    }
+    /// Deconstruct self and return the median, which is the lower of the two middle values when
+    /// the number of items is even.
+    pub fn into_inner(mut self) -> Option<Item>
+    where
+        Item: Ord,
+    {
+        self.lower.pop()
+    }
+    /// Reduces the given item into self.
+    pub fn reduce(&mut self, item: Item)
+    where
+        Item: Ord,
+    {
+        if self.lower.peek().is_none_or(|low| item <= *low) {
+            self.lower.push(item);
+            if self.lower.len() > self.upper.len() + 1 {
+                if let Some(low) = self.lower.pop() {
+                    self.upper.push(Reverse(low));
+                }
+            }
+        } else {
+            self.upper.push(Reverse(item));
+            if self.upper.len() > self.lower.len() {
+                if let Some(Reverse(high)) = self.upper.pop() {
+                    self.lower.push(high);
+                }
+            }
+        }
+    }
+    /// Reduces the item behind the ref into self.
+    ///
+    /// This function requires the `Clone` trait, as all items are stored.
+    pub fn reduce_ref(&mut self, item: &Item)
+    where
+        Item: Ord + Clone,
+    {
+        self.reduce(item.clone())
+    }
+    /// Reduces all items of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Ord,
+    {
+        self.extend(other.lower);
+        self.extend(other.upper.into_iter().map(|Reverse(item)| item));
+    }
+    /// Reduces all items of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Ord,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for Median<Item>
+where
+    Item: Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> Extend<Item> for Median<Item>
+where
+    Item: Ord,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for Median<Item>
+where
+    Item: Ord + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Item> for Median<Item>
+where
+    Item: Ord,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> std::iter::FromIterator<&'a Item> for Median<Item>
+where
+    Item: Ord + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for Median<Item>
+where
+    Item: Ord,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for Median<Item>
+where
+    Item: Ord,
+{
+    type Item = Item;
+    type Output = Option<Item>;
+    type Peek<'a>
+        = Option<&'a Item>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<Item> {
+        self.into_inner()
+    }
+}
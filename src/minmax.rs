@@ -5,9 +5,15 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 
+use crate::FoldItem;
+use crate::Folding;
+
 /// The `Max` type uses the [`std::cmp::PartialOrd`] trait to contain only the largest iterated
 /// value.
 ///
+/// Ties are resolved by the [`Tie`] policy given to [`Max::with_tie`]; the default is
+/// [`Tie::First`].
+///
 /// Example:
 /// ```
 /// use autofolder::*;
@@ -33,14 +39,25 @@
 /// let max = max.into_inner().unwrap();
 /// println!("Final max is {}", max);
 /// ```
+///
+/// Comparisons and hashing only take the inner value into account, not the count nor the tie
+/// policy: `Max`es with different policies are equal if they hold the same value.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Max<Item> {
     item: Option<Item>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    count: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    tie: Tie,
 }
 
 /// The `Min` type uses the [`std::cmp::PartialOrd`] trait to contain only the smallest iterated
 /// value.
 ///
+/// Ties are resolved by the [`Tie`] policy given to [`Min::with_tie`]; the default is
+/// [`Tie::First`].
+///
 /// Example:
 /// ```
 /// use autofolder::*;
@@ -66,42 +83,245 @@ pub struct Max<Item> {
 /// let min = min.into_inner().unwrap();
 /// println!("Final min is {}", min);
 /// ```
+///
+/// Comparisons and hashing only take the inner value into account, not the count nor the tie
+/// policy: `Min`s with different policies are equal if they hold the same value.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Min<Item> {
     item: Option<Item>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    count: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    tie: Tie,
+}
+
+/// The policy that [`Min`], [`Max`], [`MinBy`](crate::MinBy) and [`MaxBy`](crate::MaxBy) use
+/// to choose between items that compare equal.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tie {
+    /// Keep the first item, which is only replaced by strictly greater/smaller ones.
+    #[default]
+    First,
+    /// Keep the last item, which is also replaced by equal ones.
+    Last,
+}
+
+impl Tie {
+    /// Returns `true` if an item with the `ordering` relative to the current one replaces it,
+    /// `target` being the ordering that always does.
+    pub(crate) fn replaces(
+        self,
+        ordering: Option<core::cmp::Ordering>,
+        target: core::cmp::Ordering,
+    ) -> bool {
+        match ordering {
+            Some(core::cmp::Ordering::Equal) => self == Tie::Last,
+            Some(ordering) => ordering == target,
+            None => false,
+        }
+    }
+}
+
+/// Error returned by the `try_reduce` and `extend_strict` methods of [`Min`], [`Max`] and
+/// [`MinMax`] when an item can't be compared with the current values.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut max = Max::default();
+/// let error = max.extend_strict(vec![1.0, f64::NAN, 3.0]).unwrap_err();
+/// assert!(error.into_item().is_nan());
+/// assert_eq!(max.into_inner(), Some(1.0));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IncomparableError<Item> {
+    item: Item,
+}
+
+impl<Item> IncomparableError<Item> {
+    fn new(item: Item) -> Self {
+        Self { item }
+    }
+    /// Returns a reference to the item that couldn't be compared.
+    pub fn item(&self) -> &Item {
+        &self.item
+    }
+    /// Deconstruct self and return the item that couldn't be compared.
+    pub fn into_item(self) -> Item {
+        self.item
+    }
+}
+
+impl<Item> core::fmt::Display for IncomparableError<Item>
+where
+    Item: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "item {:?} can't be compared with the current values",
+            self.item
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Item> std::error::Error for IncomparableError<Item> where Item: core::fmt::Debug {}
+
+/// Returns `true` if `item` can be compared with `current`, or with itself if there is none.
+fn comparable<Item>(current: Option<&Item>, item: &Item) -> bool
+where
+    Item: PartialOrd,
+{
+    item.partial_cmp(current.unwrap_or(item)).is_some()
+}
+
+/// Number of independent accumulators used by [`slice_extreme`].
+const LANES: usize = 8;
+
+/// Returns the item that replaces `current` after reducing all `items` as `reduce` does, with the
+/// `target` ordering and the `tie` policy.
+///
+/// The extreme value is found with independent accumulators, which breaks the dependency between
+/// consecutive comparisons and allows vectorization, and then the slice is scanned for the item
+/// that the tie policy would have kept. This gives the same result as reducing the items one by
+/// one as long as the comparable items are totally ordered, which is the case for the primitive
+/// types, including floats.
+pub(crate) fn slice_extreme<Item>(
+    current: Item,
+    items: &[Item],
+    target: core::cmp::Ordering,
+    tie: Tie,
+) -> Item
+where
+    Item: PartialOrd + Copy,
+{
+    let mut lanes = [current; LANES];
+    let chunks = items.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        for (lane, &item) in lanes.iter_mut().zip(chunk) {
+            if item.partial_cmp(lane) == Some(target) {
+                *lane = item;
+            }
+        }
+    }
+    let mut extreme = current;
+    for &item in lanes.iter().chain(remainder) {
+        if item.partial_cmp(&extreme) == Some(target) {
+            extreme = item;
+        }
+    }
+    let equal = |item: &&Item| item.partial_cmp(&&extreme) == Some(core::cmp::Ordering::Equal);
+    let found = match tie {
+        Tie::First => items.iter().find(equal),
+        Tie::Last => items.iter().rev().find(equal),
+    };
+    match found {
+        Some(item) if tie.replaces(item.partial_cmp(&current), target) => *item,
+        _ => current,
+    }
 }
 
 macro_rules! impl_minmax {
     ($name: ident, $cmpval: expr) => {
         impl<Item> $name<Item> {
-            /// Creates a new `$name` with the provided initial value.
-            pub fn new(initial: Item) -> Self {
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the provided initial value, which counts as an item.")]
+            pub const fn new(initial: Item) -> Self {
                 Self {
                     item: Some(initial),
+                    count: 1,
+                    tie: Tie::First,
+                }
+            }
+            #[doc = concat!("Creates a new empty `", stringify!($name), "`, the same as [`Default::default`].")]
+            ///
+            /// As a `const fn`, this can be used to initialize statics:
+            /// ```
+            /// use autofolder::*;
+            /// use std::sync::Mutex;
+            ///
+            #[doc = concat!("static EXTREME: Mutex<", stringify!($name), "<u64>> = Mutex::new(", stringify!($name), "::empty());")]
+            ///
+            /// EXTREME.lock().unwrap().reduce(5);
+            /// assert_eq!(EXTREME.lock().unwrap().as_ref(), Some(&5));
+            /// ```
+            pub const fn empty() -> Self {
+                Self::with_tie(Tie::First)
+            }
+            #[doc = concat!("Creates a new empty `", stringify!($name), "` with the provided tie policy.")]
+            pub const fn with_tie(tie: Tie) -> Self {
+                Self {
+                    item: None,
+                    count: 0,
+                    tie,
                 }
             }
+            /// Returns the tie policy.
+            pub fn tie(&self) -> Tie {
+                self.tie
+            }
+            /// Returns the number of items reduced in.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Returns `true` if no item was reduced in.
+            pub fn is_empty(&self) -> bool {
+                self.count == 0
+            }
             /// Deconstruct self and return the inner value.
             pub fn into_inner(self) -> Option<Item> {
                 self.item
             }
+            /// Deconstruct self and return the inner value, or the provided default if there is none.
+            pub fn into_inner_or(self, default: Item) -> Item {
+                self.into_inner().unwrap_or(default)
+            }
+            /// Deconstruct self and return the inner value, or the result of `func` if there is none.
+            pub fn into_inner_or_else<F>(self, func: F) -> Item
+            where
+                F: FnOnce() -> Item,
+            {
+                self.into_inner().unwrap_or_else(func)
+            }
+            /// Deconstruct self and return the inner value, or the default value of `Item` if there is
+            /// none.
+            pub fn into_inner_or_default(self) -> Item
+            where
+                Item: Default,
+            {
+                self.into_inner().unwrap_or_default()
+            }
             /// Returns a reference to the inner value, if there is one.
             pub fn as_ref(&self) -> Option<&Item> {
                 self.item.as_ref()
             }
-            /// Replaces the current value with the new one if the new one is greater/smaller.
+            #[doc = concat!("Empties self, as if it was created with [`", stringify!($name), "::default`].")]
+            pub fn reset(&mut self) {
+                self.item = None;
+                self.count = 0;
+            }
+            /// Takes the inner value out, leaving self empty.
+            pub fn take(&mut self) -> Option<Item> {
+                self.count = 0;
+                self.item.take()
+            }
+            /// Replaces the inner value with the provided one, which counts as the only item, and
+            /// returns the previous value.
+            pub fn replace(&mut self, item: Item) -> Option<Item> {
+                self.count = 1;
+                self.item.replace(item)
+            }
+            /// Replaces the current value with the new one if the new one is greater/smaller, or
+            /// equal with the [`Tie::Last`] policy.
             pub fn reduce(&mut self, item: Item)
             where
                 Item: PartialOrd,
             {
-                if self.item.is_none()
-                    || self
-                        .item
-                        .as_ref()
-                        .map(|i| item.partial_cmp(i) == Some($cmpval))
-                        == Some(true)
-                {
-                    self.item = Some(item);
-                }
+                self.reduce_check(item);
             }
             /// Replaces the current value with the one behing the ref if it is greater/smaller.
             ///
@@ -110,35 +330,120 @@ macro_rules! impl_minmax {
             where
                 Item: PartialOrd + Clone,
             {
-                if self.item.is_none()
-                    || self
-                        .item
-                        .as_ref()
-                        .map(|i| item.partial_cmp(i) == Some($cmpval))
-                        == Some(true)
-                {
+                self.reduce_ref_check(item);
+            }
+            #[doc = concat!("Same as [`", stringify!($name), "::reduce`], but returns `true` if the value was replaced.")]
+            pub fn reduce_check(&mut self, item: Item) -> bool
+            where
+                Item: PartialOrd,
+            {
+                self.count += 1;
+                if self.replaces(&item) {
+                    self.item = Some(item);
+                    true
+                } else {
+                    false
+                }
+            }
+            #[doc = concat!("Same as [`", stringify!($name), "::reduce_ref`], but returns `true` if the value was replaced.")]
+            pub fn reduce_ref_check(&mut self, item: &Item) -> bool
+            where
+                Item: PartialOrd + Clone,
+            {
+                self.count += 1;
+                if self.replaces(item) {
                     self.item = Some(item.clone());
+                    true
+                } else {
+                    false
                 }
             }
-            /// Alias for [`$name::reduce`]
+            fn replaces(&self, item: &Item) -> bool
+            where
+                Item: PartialOrd,
+            {
+                match &self.item {
+                    None => true,
+                    Some(i) => self.tie.replaces(item.partial_cmp(i), $cmpval),
+                }
+            }
+            #[doc = concat!("Alias for [`", stringify!($name), "::reduce`]")]
             pub fn eval(&mut self, item: Item)
             where
                 Item: PartialOrd,
             {
                 self.reduce(item)
             }
-            /// Alias for [`$name::reduce_ref`]
+            #[doc = concat!("Alias for [`", stringify!($name), "::reduce_ref`]")]
             pub fn eval_ref(&mut self, item: &Item)
             where
                 Item: PartialOrd + Clone,
             {
                 self.reduce_ref(item)
             }
+            #[doc = concat!("Same as [`", stringify!($name), "::reduce`], but returns an error with the item instead of ignoring it if it can't be compared with the current value.")]
+            ///
+            /// When self is empty, the item is checked against itself, which rejects `NaN`s.
+            pub fn try_reduce(&mut self, item: Item) -> Result<(), IncomparableError<Item>>
+            where
+                Item: PartialOrd,
+            {
+                if comparable(self.item.as_ref(), &item) {
+                    self.reduce(item);
+                    Ok(())
+                } else {
+                    Err(IncomparableError::new(item))
+                }
+            }
+            #[doc = concat!("Reduces the items with [`", stringify!($name), "::try_reduce`], stopping at the first one that can't be compared.")]
+            pub fn extend_strict<It>(&mut self, iter: It) -> Result<(), IncomparableError<Item>>
+            where
+                It: IntoIterator<Item = Item>,
+                Item: PartialOrd,
+            {
+                iter.into_iter().try_for_each(|i| self.try_reduce(i))
+            }
+            /// Reduces all items of the slice, with the same result as `extend` for totally ordered
+            /// items, including floats.
+            ///
+            /// This compares copies of the items with independent accumulators, which is faster
+            /// than `extend` for primitive types.
+            pub fn reduce_slice(&mut self, items: &[Item])
+            where
+                Item: PartialOrd + Copy,
+            {
+                let (current, rest) = match (self.item, items.split_first()) {
+                    (Some(current), _) => (current, items),
+                    (None, Some((first, rest))) => (*first, rest),
+                    (None, None) => return,
+                };
+                self.item = Some(slice_extreme(current, rest, $cmpval, self.tie));
+                self.count += items.len();
+            }
+            /// Reduce the value of `other` into self.
+            pub fn merge_from(&mut self, other: Self)
+            where
+                Item: PartialOrd,
+            {
+                if let Some(item) = other.item {
+                    let count = self.count + other.count;
+                    self.reduce(item);
+                    self.count = count;
+                }
+            }
+            /// Reduce the value of `other` into self and return the result.
+            pub fn merge(mut self, other: Self) -> Self
+            where
+                Item: PartialOrd,
+            {
+                self.merge_from(other);
+                self
+            }
         }
 
         impl<Item> Default for $name<Item> {
             fn default() -> Self {
-                Self { item: None }
+                Self::empty()
             }
         }
 
@@ -148,6 +453,44 @@ macro_rules! impl_minmax {
             }
         }
 
+        impl<Item> PartialEq for $name<Item>
+        where
+            Item: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.item == other.item
+            }
+        }
+
+        impl<Item> Eq for $name<Item> where Item: Eq {}
+
+        impl<Item> PartialOrd for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.item.partial_cmp(&other.item)
+            }
+        }
+
+        impl<Item> Ord for $name<Item>
+        where
+            Item: Ord,
+        {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.item.cmp(&other.item)
+            }
+        }
+
+        impl<Item> core::hash::Hash for $name<Item>
+        where
+            Item: core::hash::Hash,
+        {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.item.hash(state)
+            }
+        }
+
         impl<Item> Extend<Item> for $name<Item>
         where
             Item: PartialOrd,
@@ -166,7 +509,7 @@ macro_rules! impl_minmax {
             }
         }
 
-        impl<Item> std::iter::FromIterator<Item> for $name<Item>
+        impl<Item> core::iter::FromIterator<Item> for $name<Item>
         where
             Item: PartialOrd,
         {
@@ -177,7 +520,7 @@ macro_rules! impl_minmax {
             }
         }
 
-        impl<'a, Item> std::iter::FromIterator<&'a Item> for $name<Item>
+        impl<'a, Item> core::iter::FromIterator<&'a Item> for $name<Item>
         where
             Item: PartialOrd + Clone,
         {
@@ -187,11 +530,47 @@ macro_rules! impl_minmax {
                 autofolder
             }
         }
+
+        impl<Item> FoldItem<Item> for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            fn fold_item(&mut self, item: Item) {
+                self.reduce(item)
+            }
+        }
+
+        impl<Item> core::ops::AddAssign<Item> for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            fn add_assign(&mut self, item: Item) {
+                self.reduce(item)
+            }
+        }
+
+        impl<Item> Folding for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            type Item = Item;
+            type Output = Option<Item>;
+            type Peek<'a>
+                = Option<&'a Item>
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Option<Item> {
+                self.into_inner()
+            }
+        }
     };
 }
 
-impl_minmax!(Max, std::cmp::Ordering::Greater);
-impl_minmax!(Min, std::cmp::Ordering::Less);
+impl_minmax!(Max, core::cmp::Ordering::Greater);
+impl_minmax!(Min, core::cmp::Ordering::Less);
 
 /// The `MinMax` type uses the [`std::cmp::PartialOrd`] trait to
 /// contain both the smallest and largest iterated values.
@@ -226,57 +605,308 @@ impl_minmax!(Min, std::cmp::Ordering::Less);
 /// let (min, max) = minmax.to_inner().unwrap();
 /// println!("Final min is {}, max is {}", min, max);
 /// ```
-#[derive(Debug, Copy, Clone, Default)]
-pub enum MinMax<Item> {
+///
+/// The values kept can be matched on with [`MinMax::extremes`] and [`MinMax::into_extremes`].
+///
+/// Comparisons and hashing only take the inner values into account, not the count.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinMax<Item> {
+    extremes: Extremes<Item>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    count: usize,
+}
+
+/// The values kept by [`MinMax`], as returned by [`MinMax::extremes`] and
+/// [`MinMax::into_extremes`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Extremes<Item> {
     /// Empty; no item evaluated.
     #[default]
     None,
-    /// Single item evaluated.
+    /// Single distinct item evaluated.
     Single(Item),
-    /// Two or more items evaluated - min and max values.
+    /// Two or more distinct items evaluated - min and max values.
     Both(Item, Item),
 }
 
+impl<Item> Extremes<Item> {
+    fn reduce(&mut self, item: Item) -> MinMaxUpdate
+    where
+        Item: PartialOrd,
+    {
+        let old = core::mem::take(self);
+        let (new, update) = match old {
+            Self::None => (Self::Single(item), MinMaxUpdate::Both),
+            Self::Single(olditem) => {
+                if item.partial_cmp(&olditem) == Some(core::cmp::Ordering::Less) {
+                    (Self::Both(item, olditem), MinMaxUpdate::Min)
+                } else if item.partial_cmp(&olditem) == Some(core::cmp::Ordering::Greater) {
+                    (Self::Both(olditem, item), MinMaxUpdate::Max)
+                } else {
+                    (Self::Single(olditem), MinMaxUpdate::Unchanged)
+                }
+            }
+            Self::Both(oldmin, oldmax) => {
+                if item.partial_cmp(&oldmin) == Some(core::cmp::Ordering::Less) {
+                    (Self::Both(item, oldmax), MinMaxUpdate::Min)
+                } else if item.partial_cmp(&oldmax) == Some(core::cmp::Ordering::Greater) {
+                    (Self::Both(oldmin, item), MinMaxUpdate::Max)
+                } else {
+                    (Self::Both(oldmin, oldmax), MinMaxUpdate::Unchanged)
+                }
+            }
+        };
+        *self = new;
+        update
+    }
+    fn reduce_ref(&mut self, item: &Item) -> MinMaxUpdate
+    where
+        Item: PartialOrd + Clone,
+    {
+        let old = core::mem::take(self);
+        let (new, update) = match old {
+            Self::None => (Self::Single(item.clone()), MinMaxUpdate::Both),
+            Self::Single(olditem) => {
+                if item.partial_cmp(&olditem) == Some(core::cmp::Ordering::Less) {
+                    (Self::Both(item.clone(), olditem), MinMaxUpdate::Min)
+                } else if item.partial_cmp(&olditem) == Some(core::cmp::Ordering::Greater) {
+                    (Self::Both(olditem, item.clone()), MinMaxUpdate::Max)
+                } else {
+                    (Self::Single(olditem), MinMaxUpdate::Unchanged)
+                }
+            }
+            Self::Both(oldmin, oldmax) => {
+                if item.partial_cmp(&oldmin) == Some(core::cmp::Ordering::Less) {
+                    (Self::Both(item.clone(), oldmax), MinMaxUpdate::Min)
+                } else if item.partial_cmp(&oldmax) == Some(core::cmp::Ordering::Greater) {
+                    (Self::Both(oldmin, item.clone()), MinMaxUpdate::Max)
+                } else {
+                    (Self::Both(oldmin, oldmax), MinMaxUpdate::Unchanged)
+                }
+            }
+        };
+        *self = new;
+        update
+    }
+}
+
+/// Which of the values of a [`MinMax`] or `MinMaxNum` were replaced by an item, as returned by
+/// [`MinMax::reduce_check`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MinMaxUpdate {
+    /// Neither value was replaced.
+    Unchanged,
+    /// The min value was replaced.
+    Min,
+    /// The max value was replaced.
+    Max,
+    /// Both values were replaced, which happens with the first item.
+    Both,
+}
+
+impl MinMaxUpdate {
+    #[cfg(feature = "num")]
+    pub(crate) fn from_flags(min: bool, max: bool) -> Self {
+        match (min, max) {
+            (false, false) => Self::Unchanged,
+            (true, false) => Self::Min,
+            (false, true) => Self::Max,
+            (true, true) => Self::Both,
+        }
+    }
+    /// Returns `true` if any value was replaced.
+    pub fn is_changed(self) -> bool {
+        self != Self::Unchanged
+    }
+    /// Returns `true` if the min value was replaced.
+    pub fn min_changed(self) -> bool {
+        matches!(self, Self::Min | Self::Both)
+    }
+    /// Returns `true` if the max value was replaced.
+    pub fn max_changed(self) -> bool {
+        matches!(self, Self::Max | Self::Both)
+    }
+}
+
 impl<Item> MinMax<Item> {
-    /// Creates a new `MinMax` with the provided initial values.
-    pub fn new(initial: Item) -> Self {
-        Self::Single(initial)
+    /// Creates a new `MinMax` with the provided initial value, which counts as an item.
+    pub const fn new(initial: Item) -> Self {
+        Self {
+            extremes: Extremes::Single(initial),
+            count: 1,
+        }
+    }
+    /// Creates a new empty `MinMax`, the same as [`Default::default`], which can be used to
+    /// initialize statics.
+    pub const fn empty() -> Self {
+        Self {
+            extremes: Extremes::None,
+            count: 0,
+        }
+    }
+    /// Creates a new `MinMax` with the provided values, which count as two items.
+    ///
+    /// The values are swapped if `min` is greater than `max`; if they are not comparable, `max`
+    /// is ignored, just as when reducing it.
+    pub fn both(min: Item, max: Item) -> Self
+    where
+        Item: PartialOrd,
+    {
+        let mut minmax = Self::new(min);
+        minmax.reduce(max);
+        minmax
+    }
+    /// Returns the number of items reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no item was reduced in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// Returns a reference to the values kept, which can be matched on.
+    pub fn extremes(&self) -> &Extremes<Item> {
+        &self.extremes
+    }
+    /// Deconstruct self and return the values kept, which can be matched on.
+    pub fn into_extremes(self) -> Extremes<Item> {
+        self.extremes
+    }
+    /// Deconstruct self and return the min inner value, if it exists.
+    pub fn into_min(self) -> Option<Item> {
+        match self.extremes {
+            Extremes::None => None,
+            Extremes::Single(item) => Some(item),
+            Extremes::Both(min, _) => Some(min),
+        }
+    }
+    /// Deconstruct self and return the max inner value, if it exists.
+    pub fn into_max(self) -> Option<Item> {
+        match self.extremes {
+            Extremes::None => None,
+            Extremes::Single(item) => Some(item),
+            Extremes::Both(_, max) => Some(max),
+        }
     }
     /// Deconstruct self and return the inner values that were found.
     pub fn to_inner(self) -> Option<(Item, Item)>
     where
         Item: Clone,
     {
-        match self {
-            Self::None => None,
-            Self::Single(item) => Some((item.clone(), item)),
-            Self::Both(min, max) => Some((min, max)),
+        match self.extremes {
+            Extremes::None => None,
+            Extremes::Single(item) => Some((item.clone(), item)),
+            Extremes::Both(min, max) => Some((min, max)),
         }
     }
+    /// Deconstruct self and return the inner values, or the provided defaults if there are
+    /// none.
+    pub fn to_inner_or(self, default: (Item, Item)) -> (Item, Item)
+    where
+        Item: Clone,
+    {
+        self.to_inner().unwrap_or(default)
+    }
+    /// Deconstruct self and return the inner values, or the result of `func` if there are none.
+    pub fn to_inner_or_else<F>(self, func: F) -> (Item, Item)
+    where
+        Item: Clone,
+        F: FnOnce() -> (Item, Item),
+    {
+        self.to_inner().unwrap_or_else(func)
+    }
+    /// Deconstruct self and return the inner values, or the default value of `Item` for both
+    /// if there are none.
+    pub fn to_inner_or_default(self) -> (Item, Item)
+    where
+        Item: Clone + Default,
+    {
+        self.to_inner().unwrap_or_default()
+    }
     /// Returns a reference to the inner values, if they exist.
     pub fn as_ref(&self) -> Option<(&Item, &Item)> {
-        match self {
-            Self::None => None,
-            Self::Single(item) => Some((item, item)),
-            Self::Both(min, max) => Some((min, max)),
+        match &self.extremes {
+            Extremes::None => None,
+            Extremes::Single(item) => Some((item, item)),
+            Extremes::Both(min, max) => Some((min, max)),
+        }
+    }
+    /// Deconstruct self and return the range between the inner values, if they exist.
+    ///
+    /// As with [`MinMax::to_inner`], this function requires the `Clone` trait.
+    ///
+    /// Example:
+    /// ```
+    /// use autofolder::*;
+    ///
+    /// let minmax = vec![3, 7, 5].into_iter().collect::<MinMax<usize>>();
+    /// let range = minmax.into_range().unwrap();
+    /// assert_eq!(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9][range], &[3, 4, 5, 6, 7]);
+    /// ```
+    pub fn into_range(self) -> Option<core::ops::RangeInclusive<Item>>
+    where
+        Item: Clone,
+    {
+        self.to_inner().map(|(min, max)| min..=max)
+    }
+    /// Returns the range between references to the inner values, if they exist.
+    pub fn as_range(&self) -> Option<core::ops::RangeInclusive<&Item>> {
+        self.as_ref().map(|(min, max)| min..=max)
+    }
+    /// Returns `true` if `item` is between the inner values, inclusive.
+    ///
+    /// Always returns `false` if self is empty.
+    pub fn contains(&self, item: &Item) -> bool
+    where
+        Item: PartialOrd,
+    {
+        match self.as_ref() {
+            None => false,
+            Some((min, max)) => min <= item && item <= max,
         }
     }
     /// Returns a reference to the min inner values, if it exist.
     pub fn min_as_ref(&self) -> Option<&Item> {
-        match self {
-            Self::None => None,
-            Self::Single(item) => Some(item),
-            Self::Both(min, _) => Some(min),
+        match &self.extremes {
+            Extremes::None => None,
+            Extremes::Single(item) => Some(item),
+            Extremes::Both(min, _) => Some(min),
         }
     }
     /// Returns a reference to the max inner values, if it exist.
     pub fn max_as_ref(&self) -> Option<&Item> {
-        match self {
-            Self::None => None,
-            Self::Single(item) => Some(item),
-            Self::Both(_, max) => Some(max),
+        match &self.extremes {
+            Extremes::None => None,
+            Extremes::Single(item) => Some(item),
+            Extremes::Both(_, max) => Some(max),
         }
     }
+    /// Empties self, as if it was created with [`MinMax::default`].
+    pub fn reset(&mut self) {
+        self.extremes = Extremes::None;
+        self.count = 0;
+    }
+    /// Takes the inner values out, leaving self empty.
+    ///
+    /// As with [`MinMax::to_inner`], this function requires the `Clone` trait.
+    pub fn take(&mut self) -> Option<(Item, Item)>
+    where
+        Item: Clone,
+    {
+        core::mem::take(self).to_inner()
+    }
+    /// Replaces the inner values with the provided item, which counts as the only item, and
+    /// returns the previous values.
+    ///
+    /// As with [`MinMax::to_inner`], this function requires the `Clone` trait.
+    pub fn replace(&mut self, item: Item) -> Option<(Item, Item)>
+    where
+        Item: Clone,
+    {
+        core::mem::replace(self, Self::new(item)).to_inner()
+    }
     /// Replaces a current value with the new one if the new one is greater/smaller.
     ///
     /// When we have a single value, `min` is always filled up first,
@@ -285,28 +915,7 @@ impl<Item> MinMax<Item> {
     where
         Item: PartialOrd,
     {
-        let old = std::mem::take(self);
-        *self = match old {
-            Self::None => Self::Single(item),
-            Self::Single(olditem) => {
-                if item.partial_cmp(&olditem) == Some(std::cmp::Ordering::Less) {
-                    Self::Both(item, olditem)
-                } else if item.partial_cmp(&olditem) == Some(std::cmp::Ordering::Greater) {
-                    Self::Both(olditem, item)
-                } else {
-                    Self::Single(olditem)
-                }
-            }
-            Self::Both(oldmin, oldmax) => {
-                if item.partial_cmp(&oldmin) == Some(std::cmp::Ordering::Less) {
-                    Self::Both(item, oldmax)
-                } else if item.partial_cmp(&oldmax) == Some(std::cmp::Ordering::Greater) {
-                    Self::Both(oldmin, item)
-                } else {
-                    Self::Both(oldmin, oldmax)
-                }
-            }
-        };
+        self.reduce_check(item);
     }
     /// Replaces a current value with the one behind the ref if it is greater/smaller.
     ///
@@ -318,28 +927,23 @@ impl<Item> MinMax<Item> {
     where
         Item: PartialOrd + Clone,
     {
-        let old = std::mem::take(self);
-        *self = match old {
-            Self::None => Self::Single(item.clone()),
-            Self::Single(olditem) => {
-                if item.partial_cmp(&olditem) == Some(std::cmp::Ordering::Less) {
-                    Self::Both(item.clone(), olditem)
-                } else if item.partial_cmp(&olditem) == Some(std::cmp::Ordering::Greater) {
-                    Self::Both(olditem, item.clone())
-                } else {
-                    Self::Single(olditem)
-                }
-            }
-            Self::Both(oldmin, oldmax) => {
-                if item.partial_cmp(&oldmin) == Some(std::cmp::Ordering::Less) {
-                    Self::Both(item.clone(), oldmax)
-                } else if item.partial_cmp(&oldmax) == Some(std::cmp::Ordering::Greater) {
-                    Self::Both(oldmin, item.clone())
-                } else {
-                    Self::Both(oldmin, oldmax)
-                }
-            }
-        };
+        self.reduce_ref_check(item);
+    }
+    /// Same as [`MinMax::reduce`], but returns which values were replaced.
+    pub fn reduce_check(&mut self, item: Item) -> MinMaxUpdate
+    where
+        Item: PartialOrd,
+    {
+        self.count += 1;
+        self.extremes.reduce(item)
+    }
+    /// Same as [`MinMax::reduce_ref`], but returns which values were replaced.
+    pub fn reduce_ref_check(&mut self, item: &Item) -> MinMaxUpdate
+    where
+        Item: PartialOrd + Clone,
+    {
+        self.count += 1;
+        self.extremes.reduce_ref(item)
     }
     /// Alias for [`MinMax::reduce`]
     pub fn eval(&mut self, item: Item)
@@ -355,6 +959,95 @@ impl<Item> MinMax<Item> {
     {
         self.reduce_ref(item)
     }
+    /// Same as [`MinMax::reduce`], but returns an error with the item instead of ignoring it if
+    /// it can't be compared with the current values.
+    ///
+    /// When self is empty, the item is checked against itself, which rejects `NaN`s.
+    pub fn try_reduce(&mut self, item: Item) -> Result<(), IncomparableError<Item>>
+    where
+        Item: PartialOrd,
+    {
+        let ok = match &self.extremes {
+            Extremes::None => comparable(None, &item),
+            Extremes::Single(current) => comparable(Some(current), &item),
+            Extremes::Both(min, max) => {
+                comparable(Some(min), &item) && comparable(Some(max), &item)
+            }
+        };
+        if ok {
+            self.reduce(item);
+            Ok(())
+        } else {
+            Err(IncomparableError::new(item))
+        }
+    }
+    /// Reduces the items with [`MinMax::try_reduce`], stopping at the first one that can't be
+    /// compared.
+    pub fn extend_strict<It>(&mut self, iter: It) -> Result<(), IncomparableError<Item>>
+    where
+        It: IntoIterator<Item = Item>,
+        Item: PartialOrd,
+    {
+        iter.into_iter().try_for_each(|i| self.try_reduce(i))
+    }
+    /// Reduces all items of the slice, with the same result as `extend` for totally ordered
+    /// items, including floats.
+    ///
+    /// This compares copies of the items with independent accumulators, which is faster than
+    /// `extend` for primitive types.
+    pub fn reduce_slice(&mut self, items: &[Item])
+    where
+        Item: PartialOrd + Copy,
+    {
+        let (min, max, both, rest) = match (&self.extremes, items.split_first()) {
+            (Extremes::Both(min, max), _) => (*min, *max, true, items),
+            (Extremes::Single(item), _) => (*item, *item, false, items),
+            (Extremes::None, Some((first, rest))) => (*first, *first, false, rest),
+            (Extremes::None, None) => return,
+        };
+        let new_min = slice_extreme(min, rest, core::cmp::Ordering::Less, Tie::First);
+        let new_max = slice_extreme(max, rest, core::cmp::Ordering::Greater, Tie::First);
+        let both = both
+            || new_min.partial_cmp(&min) == Some(core::cmp::Ordering::Less)
+            || new_max.partial_cmp(&max) == Some(core::cmp::Ordering::Greater);
+        self.extremes = if both {
+            Extremes::Both(new_min, new_max)
+        } else {
+            Extremes::Single(new_min)
+        };
+        self.count += items.len();
+    }
+    /// Reduce the values of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: PartialOrd,
+    {
+        match other.extremes {
+            Extremes::None => {}
+            Extremes::Single(item) => {
+                self.extremes.reduce(item);
+            }
+            Extremes::Both(min, max) => {
+                self.extremes.reduce(min);
+                self.extremes.reduce(max);
+            }
+        }
+        self.count += other.count;
+    }
+    /// Reduce the values of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: PartialOrd,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for MinMax<Item> {
+    fn default() -> Self {
+        Self::empty()
+    }
 }
 
 impl<Item> From<Item> for MinMax<Item> {
@@ -363,6 +1056,53 @@ impl<Item> From<Item> for MinMax<Item> {
     }
 }
 
+impl<Item> From<(Item, Item)> for MinMax<Item>
+where
+    Item: PartialOrd,
+{
+    fn from((min, max): (Item, Item)) -> Self {
+        Self::both(min, max)
+    }
+}
+
+impl<Item> PartialEq for MinMax<Item>
+where
+    Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<Item> Eq for MinMax<Item> where Item: Eq {}
+
+impl<Item> PartialOrd for MinMax<Item>
+where
+    Item: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_ref().partial_cmp(&other.as_ref())
+    }
+}
+
+impl<Item> Ord for MinMax<Item>
+where
+    Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_ref().cmp(&other.as_ref())
+    }
+}
+
+impl<Item> core::hash::Hash for MinMax<Item>
+where
+    Item: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
 impl<Item> Extend<Item> for MinMax<Item>
 where
     Item: PartialOrd,
@@ -381,7 +1121,7 @@ where
     }
 }
 
-impl<Item> std::iter::FromIterator<Item> for MinMax<Item>
+impl<Item> core::iter::FromIterator<Item> for MinMax<Item>
 where
     Item: PartialOrd,
 {
@@ -392,7 +1132,7 @@ where
     }
 }
 
-impl<'a, Item> std::iter::FromIterator<&'a Item> for MinMax<Item>
+impl<'a, Item> core::iter::FromIterator<&'a Item> for MinMax<Item>
 where
     Item: PartialOrd + Clone,
 {
@@ -402,3 +1142,39 @@ where
         autofolder
     }
 }
+
+impl<Item> FoldItem<Item> for MinMax<Item>
+where
+    Item: PartialOrd,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> core::ops::AddAssign<Item> for MinMax<Item>
+where
+    Item: PartialOrd,
+{
+    fn add_assign(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for MinMax<Item>
+where
+    Item: PartialOrd + Clone,
+{
+    type Item = Item;
+    type Output = Option<(Item, Item)>;
+    type Peek<'a>
+        = Option<(&'a Item, &'a Item)>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<(Item, Item)> {
+        self.to_inner()
+    }
+}
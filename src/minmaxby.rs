@@ -0,0 +1,257 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use crate::Tie;
+
+/// The `MaxBy` type contains only the iterated value with the largest key, as computed by a
+/// key-extraction function.
+///
+/// This works like [`Iterator::max_by_key`], without requiring a [`std::cmp::PartialOrd`]
+/// implementation for the items themselves. The key of the current value is cached, so that
+/// the function is called only once per item. As with [`Max`](crate::Max), the current value
+/// is only replaced by items with strictly larger keys, unless the [`Tie::Last`] policy is given
+/// to [`MaxBy::with_tie`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the longest string.
+/// let mut max = MaxBy::new(|s: &String| s.len());
+///
+/// // We can "reduce-in" individual items:
+/// max.reduce("abc".to_string());
+///
+/// // We can then peek at the running output and key:
+/// println!("Partial max is {:?} with key {:?}", max.as_ref(), max.key());
+///
+/// // And still keep on folding by processing whole iterators:
+/// max.extend(vec!["de".to_string(), "fghi".to_string()]);
+///
+/// // And finally consume the autofolder to get the final output value:
+/// assert_eq!(max.into_inner(), Some("fghi".to_string()));
+/// ```
+#[derive(Copy, Clone)]
+pub struct MaxBy<Item, Key, Func> {
+    entry: Option<(Item, Key)>,
+    function: Func,
+    tie: Tie,
+}
+
+/// The `MinBy` type contains only the iterated value with the smallest key, as computed by a
+/// key-extraction function.
+///
+/// This works like [`Iterator::min_by_key`], without requiring a [`std::cmp::PartialOrd`]
+/// implementation for the items themselves. The key of the current value is cached, so that
+/// the function is called only once per item. As with [`Min`](crate::Min), the current value
+/// is only replaced by items with strictly smaller keys, unless the [`Tie::Last`] policy is given
+/// to [`MinBy::with_tie`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the shortest string.
+/// let mut min = MinBy::new(|s: &String| s.len());
+///
+/// // We can "reduce-in" individual items:
+/// min.reduce("abc".to_string());
+///
+/// // We can then peek at the running output and key:
+/// println!("Partial min is {:?} with key {:?}", min.as_ref(), min.key());
+///
+/// // And still keep on folding by processing whole iterators:
+/// min.extend(vec!["de".to_string(), "fghi".to_string()]);
+///
+/// // And finally consume the autofolder to get the final output value:
+/// assert_eq!(min.into_inner(), Some("de".to_string()));
+/// ```
+#[derive(Copy, Clone)]
+pub struct MinBy<Item, Key, Func> {
+    entry: Option<(Item, Key)>,
+    function: Func,
+    tie: Tie,
+}
+
+macro_rules! impl_minmaxby {
+    ($name: ident, $cmpval: expr) => {
+        impl<Item, Key, Func> $name<Item, Key, Func> {
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the provided key-extraction function.")]
+            pub fn new(func: Func) -> Self
+            where
+                Func: Fn(&Item) -> Key,
+            {
+                Self::with_tie(func, Tie::First)
+            }
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the provided key-extraction function and tie policy.")]
+            pub fn with_tie(func: Func, tie: Tie) -> Self
+            where
+                Func: Fn(&Item) -> Key,
+            {
+                Self {
+                    entry: None,
+                    function: func,
+                    tie,
+                }
+            }
+            /// Returns the tie policy.
+            pub fn tie(&self) -> Tie {
+                self.tie
+            }
+            /// Deconstruct self and return the inner value.
+            pub fn into_inner(self) -> Option<Item> {
+                self.entry.map(|(item, _)| item)
+            }
+            /// Returns a reference to the inner value, if there is one.
+            pub fn as_ref(&self) -> Option<&Item> {
+                self.entry.as_ref().map(|(item, _)| item)
+            }
+            /// Returns a reference to the cached key of the inner value, if there is one.
+            pub fn key(&self) -> Option<&Key> {
+                self.entry.as_ref().map(|(_, key)| key)
+            }
+            fn replaces(&self, key: &Key) -> bool
+            where
+                Key: PartialOrd,
+            {
+                match &self.entry {
+                    None => true,
+                    Some((_, current)) => self.tie.replaces(key.partial_cmp(current), $cmpval),
+                }
+            }
+            /// Replaces the current value with the new one if the key of the new one is
+            /// greater/smaller, or equal with the [`Tie::Last`] policy.
+            pub fn reduce(&mut self, item: Item)
+            where
+                Func: Fn(&Item) -> Key,
+                Key: PartialOrd,
+            {
+                let key = (self.function)(&item);
+                if self.replaces(&key) {
+                    self.entry = Some((item, key));
+                }
+            }
+            /// Replaces the current value with the one behind the ref if its key is
+            /// greater/smaller.
+            ///
+            /// This function requires the `Clone` trait, but uses it only if necessary.
+            pub fn reduce_ref(&mut self, item: &Item)
+            where
+                Func: Fn(&Item) -> Key,
+                Key: PartialOrd,
+                Item: Clone,
+            {
+                let key = (self.function)(item);
+                if self.replaces(&key) {
+                    self.entry = Some((item.clone(), key));
+                }
+            }
+            /// Reduce the value of `other` into self, reusing its cached key.
+            pub fn merge_from(&mut self, other: Self)
+            where
+                Key: PartialOrd,
+            {
+                if let Some((item, key)) = other.entry {
+                    if self.replaces(&key) {
+                        self.entry = Some((item, key));
+                    }
+                }
+            }
+            /// Reduce the value of `other` into self and return the result.
+            pub fn merge(mut self, other: Self) -> Self
+            where
+                Key: PartialOrd,
+            {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl<Item, Key, Func> core::fmt::Debug for $name<Item, Key, Func>
+        where
+            Item: core::fmt::Debug,
+            Key: core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "{}::<{}, {}, _> {{ entry: {:?}, function: {}, tie: {:?} }}",
+                    stringify!($name),
+                    &core::any::type_name::<Item>(),
+                    &core::any::type_name::<Key>(),
+                    self.entry,
+                    &core::any::type_name::<Func>(),
+                    self.tie,
+                )
+            }
+        }
+
+        impl<Item, Key, Func> From<Func> for $name<Item, Key, Func>
+        where
+            Func: Fn(&Item) -> Key,
+        {
+            fn from(func: Func) -> Self {
+                Self::new(func)
+            }
+        }
+
+        impl<Item, Key, Func> Extend<Item> for $name<Item, Key, Func>
+        where
+            Func: Fn(&Item) -> Key,
+            Key: PartialOrd,
+        {
+            fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.reduce(i));
+            }
+        }
+
+        impl<'a, Item, Key, Func> Extend<&'a Item> for $name<Item, Key, Func>
+        where
+            Func: Fn(&Item) -> Key,
+            Key: PartialOrd,
+            Item: Clone,
+        {
+            fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.reduce_ref(i));
+            }
+        }
+
+        impl<Item, Key, Func> FoldItem<Item> for $name<Item, Key, Func>
+        where
+            Func: Fn(&Item) -> Key,
+            Key: PartialOrd,
+        {
+            fn fold_item(&mut self, item: Item) {
+                self.reduce(item)
+            }
+        }
+
+        impl<Item, Key, Func> Folding for $name<Item, Key, Func>
+        where
+            Func: Fn(&Item) -> Key,
+            Key: PartialOrd,
+        {
+            type Item = Item;
+            type Output = Option<Item>;
+            type Peek<'a>
+                = Option<&'a Item>
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Option<Item> {
+                self.into_inner()
+            }
+        }
+    };
+}
+
+impl_minmaxby!(MaxBy, core::cmp::Ordering::Greater);
+impl_minmaxby!(MinBy, core::cmp::Ordering::Less);
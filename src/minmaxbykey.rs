@@ -0,0 +1,225 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use crate::MinMax;
+
+/// A `(key, value)` pair that is compared only by the key.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct KeyEntry<Key, Value>(Key, Value);
+
+impl<Key, Value> PartialEq for KeyEntry<Key, Value>
+where
+    Key: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<Key, Value> PartialOrd for KeyEntry<Key, Value>
+where
+    Key: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+/// The `MinMaxByKey` type reduces `(key, value)` pairs, keeping the pairs with the smallest
+/// and largest keys, as given by [`std::cmp::PartialOrd`].
+///
+/// Only the keys are compared, so the values don't have to implement any trait. This works
+/// like [`MinMax`] otherwise, including keeping the first pair found when keys are equal.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder of (timestamp, record) pairs.
+/// let mut minmax = MinMaxByKey::<u64, &str>::default();
+///
+/// // We can "reduce-in" individual pairs:
+/// minmax.reduce((20, "second"));
+///
+/// // We can then peek at the running output:
+/// println!("Partial minmax is {:?}", minmax.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// minmax.extend(vec![(10, "first"), (40, "last"), (30, "third")]);
+///
+/// // And finally consume the autofolder to get the pairs with the smallest and largest keys:
+/// assert_eq!(minmax.to_inner(), Some(((10, "first"), (40, "last"))));
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinMaxByKey<Key, Value> {
+    minmax: MinMax<KeyEntry<Key, Value>>,
+}
+
+impl<Key, Value> MinMaxByKey<Key, Value> {
+    /// Creates a new `MinMaxByKey` with the provided initial pair.
+    pub const fn new(key: Key, value: Value) -> Self {
+        Self {
+            minmax: MinMax::new(KeyEntry(key, value)),
+        }
+    }
+    /// Deconstruct self and return the pairs with the smallest and largest keys.
+    ///
+    /// This function requires the `Clone` trait, as a single pair is returned as both.
+    pub fn to_inner(self) -> Option<((Key, Value), (Key, Value))>
+    where
+        Key: Clone,
+        Value: Clone,
+    {
+        self.minmax
+            .to_inner()
+            .map(|(min, max)| ((min.0, min.1), (max.0, max.1)))
+    }
+    /// Returns references to the pairs with the smallest and largest keys, if they exist.
+    #[allow(clippy::type_complexity)]
+    pub fn as_ref(&self) -> Option<((&Key, &Value), (&Key, &Value))> {
+        self.minmax
+            .as_ref()
+            .map(|(min, max)| ((&min.0, &min.1), (&max.0, &max.1)))
+    }
+    /// Returns references to the pair with the smallest key, if it exists.
+    pub fn min_as_ref(&self) -> Option<(&Key, &Value)> {
+        self.minmax.min_as_ref().map(|min| (&min.0, &min.1))
+    }
+    /// Returns references to the pair with the largest key, if it exists.
+    pub fn max_as_ref(&self) -> Option<(&Key, &Value)> {
+        self.minmax.max_as_ref().map(|max| (&max.0, &max.1))
+    }
+    /// Replaces a current pair with the new one if the new key is greater/smaller.
+    pub fn reduce(&mut self, item: (Key, Value))
+    where
+        Key: PartialOrd,
+    {
+        self.minmax.reduce(KeyEntry(item.0, item.1))
+    }
+    /// Replaces a current pair with the one behind the ref if its key is greater/smaller.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if necessary.
+    pub fn reduce_ref(&mut self, item: &(Key, Value))
+    where
+        Key: PartialOrd + Clone,
+        Value: Clone,
+    {
+        let replaces = match self.minmax.as_ref() {
+            None => true,
+            Some((min, max)) => {
+                item.0.partial_cmp(&min.0) == Some(core::cmp::Ordering::Less)
+                    || item.0.partial_cmp(&max.0) == Some(core::cmp::Ordering::Greater)
+            }
+        };
+        if replaces {
+            self.reduce(item.clone());
+        }
+    }
+    /// Reduce the pairs of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Key: PartialOrd,
+    {
+        self.minmax.merge_from(other.minmax)
+    }
+    /// Reduce the pairs of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Key: PartialOrd,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Key, Value> Default for MinMaxByKey<Key, Value> {
+    fn default() -> Self {
+        Self {
+            minmax: MinMax::default(),
+        }
+    }
+}
+
+impl<Key, Value> From<(Key, Value)> for MinMaxByKey<Key, Value> {
+    fn from(item: (Key, Value)) -> Self {
+        Self::new(item.0, item.1)
+    }
+}
+
+impl<Key, Value> Extend<(Key, Value)> for MinMaxByKey<Key, Value>
+where
+    Key: PartialOrd,
+{
+    fn extend<It: IntoIterator<Item = (Key, Value)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, Key, Value> Extend<&'a (Key, Value)> for MinMaxByKey<Key, Value>
+where
+    Key: PartialOrd + Clone,
+    Value: Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a (Key, Value)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<Key, Value> core::iter::FromIterator<(Key, Value)> for MinMaxByKey<Key, Value>
+where
+    Key: PartialOrd,
+{
+    fn from_iter<It: IntoIterator<Item = (Key, Value)>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Key, Value> core::iter::FromIterator<&'a (Key, Value)> for MinMaxByKey<Key, Value>
+where
+    Key: PartialOrd + Clone,
+    Value: Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a (Key, Value)>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Key, Value> FoldItem<(Key, Value)> for MinMaxByKey<Key, Value>
+where
+    Key: PartialOrd,
+{
+    fn fold_item(&mut self, item: (Key, Value)) {
+        self.reduce(item)
+    }
+}
+
+impl<Key, Value> Folding for MinMaxByKey<Key, Value>
+where
+    Key: PartialOrd + Clone,
+    Value: Clone,
+{
+    type Item = (Key, Value);
+    type Output = Option<((Key, Value), (Key, Value))>;
+    type Peek<'a>
+        = Option<((&'a Key, &'a Value), (&'a Key, &'a Value))>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<((Key, Value), (Key, Value))> {
+        self.to_inner()
+    }
+}
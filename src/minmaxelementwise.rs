@@ -0,0 +1,252 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// The `ElementwiseMinMax` type reduces `[T; N]` arrays, keeping the min and max of each
+/// component, as given by [`std::cmp::PartialOrd`].
+///
+/// For points, this is their axis-aligned bounding box. Each component works like a separate
+/// [`MinMax`](crate::MinMax): it's only replaced by strictly smaller/greater values, and values
+/// that can't be compared, like `NaN`s, are ignored after the first array.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the bounding box of 3D points.
+/// let mut bbox = ElementwiseMinMax::<f32, 3>::default();
+///
+/// // We can "reduce-in" individual points:
+/// bbox.reduce([1.0, 2.0, 3.0]);
+///
+/// // We can then peek at the running output:
+/// println!("Partial bounding box is {:?}", bbox.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// bbox.extend(vec![[-1.0, 5.0, 0.0], [0.0, 4.0, 8.0]]);
+/// assert!(bbox.contains(&[0.5, 3.0, 7.0]));
+///
+/// // And finally consume the autofolder to get the corners of the bounding box:
+/// assert_eq!(bbox.into_inner(), Some(([-1.0, 2.0, 0.0], [1.0, 5.0, 8.0])));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct ElementwiseMinMax<T, const N: usize> {
+    extremes: Option<([T; N], [T; N])>,
+    count: usize,
+}
+
+impl<T, const N: usize> ElementwiseMinMax<T, N> {
+    /// Creates a new `ElementwiseMinMax` with the provided initial array, which counts as an item.
+    pub fn new(initial: [T; N]) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            extremes: Some((initial.clone(), initial)),
+            count: 1,
+        }
+    }
+    /// Creates a new empty `ElementwiseMinMax`, the same as [`Default::default`], which can be
+    /// used to initialize statics.
+    pub const fn empty() -> Self {
+        Self {
+            extremes: None,
+            count: 0,
+        }
+    }
+    /// Returns the number of arrays reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no array was reduced in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// Deconstruct self and return the arrays with the min and max of each component, if any
+    /// array was reduced in.
+    pub fn into_inner(self) -> Option<([T; N], [T; N])> {
+        self.extremes
+    }
+    /// Returns references to the arrays with the min and max of each component, if any array
+    /// was reduced in.
+    pub fn as_ref(&self) -> Option<(&[T; N], &[T; N])> {
+        self.extremes.as_ref().map(|(min, max)| (min, max))
+    }
+    /// Returns a reference to the array with the min of each component, if it exists.
+    pub fn min_as_ref(&self) -> Option<&[T; N]> {
+        self.extremes.as_ref().map(|(min, _)| min)
+    }
+    /// Returns a reference to the array with the max of each component, if it exists.
+    pub fn max_as_ref(&self) -> Option<&[T; N]> {
+        self.extremes.as_ref().map(|(_, max)| max)
+    }
+    /// Returns `true` if each component of `item` is between the corresponding min and max,
+    /// inclusive.
+    ///
+    /// Always returns `false` if self is empty.
+    pub fn contains(&self, item: &[T; N]) -> bool
+    where
+        T: PartialOrd,
+    {
+        match &self.extremes {
+            None => false,
+            Some((min, max)) => item
+                .iter()
+                .zip(min.iter().zip(max.iter()))
+                .all(|(i, (min, max))| min <= i && i <= max),
+        }
+    }
+    /// Replaces each min and max component with the corresponding component of the new array
+    /// if it is smaller/greater.
+    pub fn reduce(&mut self, item: [T; N])
+    where
+        T: PartialOrd + Clone,
+    {
+        self.count += 1;
+        match &mut self.extremes {
+            None => self.extremes = Some((item.clone(), item)),
+            Some((min, max)) => {
+                let components = min.iter_mut().zip(max.iter_mut());
+                for ((min, max), value) in components.zip(IntoIterator::into_iter(item)) {
+                    if value.partial_cmp(min) == Some(core::cmp::Ordering::Less) {
+                        *min = value;
+                    } else if value.partial_cmp(max) == Some(core::cmp::Ordering::Greater) {
+                        *max = value;
+                    }
+                }
+            }
+        }
+    }
+    /// Replaces each min and max component with the corresponding component of the array
+    /// behind the ref if it is smaller/greater.
+    ///
+    /// This function requires the `Clone` trait, but uses it only for the components that are
+    /// replaced.
+    pub fn reduce_ref(&mut self, item: &[T; N])
+    where
+        T: PartialOrd + Clone,
+    {
+        self.count += 1;
+        match &mut self.extremes {
+            None => self.extremes = Some((item.clone(), item.clone())),
+            Some((min, max)) => {
+                let components = min.iter_mut().zip(max.iter_mut());
+                for ((min, max), value) in components.zip(item.iter()) {
+                    if value.partial_cmp(min) == Some(core::cmp::Ordering::Less) {
+                        *min = value.clone();
+                    } else if value.partial_cmp(max) == Some(core::cmp::Ordering::Greater) {
+                        *max = value.clone();
+                    }
+                }
+            }
+        }
+    }
+    /// Reduce the min and max arrays of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        T: PartialOrd + Clone,
+    {
+        if let Some((min, max)) = other.extremes {
+            let count = self.count + other.count;
+            self.reduce(min);
+            self.reduce(max);
+            self.count = count;
+        }
+    }
+    /// Reduce the min and max arrays of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        T: PartialOrd + Clone,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<T, const N: usize> Default for ElementwiseMinMax<T, N> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ElementwiseMinMax<T, N>
+where
+    T: Clone,
+{
+    fn from(item: [T; N]) -> Self {
+        Self::new(item)
+    }
+}
+
+impl<T, const N: usize> Extend<[T; N]> for ElementwiseMinMax<T, N>
+where
+    T: PartialOrd + Clone,
+{
+    fn extend<It: IntoIterator<Item = [T; N]>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, T, const N: usize> Extend<&'a [T; N]> for ElementwiseMinMax<T, N>
+where
+    T: PartialOrd + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a [T; N]>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<T, const N: usize> core::iter::FromIterator<[T; N]> for ElementwiseMinMax<T, N>
+where
+    T: PartialOrd + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = [T; N]>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, T, const N: usize> core::iter::FromIterator<&'a [T; N]> for ElementwiseMinMax<T, N>
+where
+    T: PartialOrd + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a [T; N]>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<T, const N: usize> FoldItem<[T; N]> for ElementwiseMinMax<T, N>
+where
+    T: PartialOrd + Clone,
+{
+    fn fold_item(&mut self, item: [T; N]) {
+        self.reduce(item)
+    }
+}
+
+impl<T, const N: usize> Folding for ElementwiseMinMax<T, N>
+where
+    T: PartialOrd + Clone,
+{
+    type Item = [T; N];
+    type Output = Option<([T; N], [T; N])>;
+    type Peek<'a>
+        = Option<(&'a [T; N], &'a [T; N])>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<([T; N], [T; N])> {
+        self.into_inner()
+    }
+}
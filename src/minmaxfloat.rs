@@ -0,0 +1,230 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// How [`MinFloat`] and [`MaxFloat`] handle `NaN` items.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NanPolicy {
+    /// `NaN` items are skipped; the result is `None` only if all items are `NaN`.
+    #[default]
+    Ignore,
+    /// A `NaN` item becomes the result, which is then kept.
+    Propagate,
+    /// Items are compared with `total_cmp`, which places positive `NaN`s above infinity and
+    /// negative ones below negative infinity.
+    TotalOrder,
+}
+
+/// The `MaxFloat` type keeps the largest iterated `f32` or `f64` value, handling `NaN`s
+/// according to a [`NanPolicy`].
+///
+/// [`Max`](crate::Max) relies on [`PartialOrd`], which makes the result depend on where the
+/// `NaN`s are: a leading one is kept, while the others are ignored. `MaxFloat` always applies
+/// the same policy instead.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that ignores NaNs, the default policy:
+/// let mut max = MaxFloat::<f64>::default();
+///
+/// // We can "reduce-in" individual items:
+/// max.reduce(f64::NAN);
+/// max.reduce(2.0);
+///
+/// // We can then peek at the running output:
+/// println!("Partial max is {:?}", max.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// max.extend(vec![1.0, f64::NAN, 3.0]);
+/// assert_eq!(max.into_inner(), Some(3.0));
+///
+/// // Other policies are selected when creating the autofolder:
+/// let mut max = MaxFloat::new(NanPolicy::Propagate);
+/// max.extend(vec![1.0, f64::NAN, 3.0]);
+/// assert!(max.into_inner().unwrap().is_nan());
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxFloat<Float> {
+    item: Option<Float>,
+    count: usize,
+    policy: NanPolicy,
+}
+
+/// The `MinFloat` type keeps the smallest iterated `f32` or `f64` value, handling `NaN`s
+/// according to a [`NanPolicy`].
+///
+/// This is the counterpart of [`MaxFloat`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut min = MinFloat::new(NanPolicy::TotalOrder);
+/// min.extend(vec![1.0_f32, -f32::NAN, f32::NEG_INFINITY]);
+/// assert!(min.into_inner().unwrap().is_nan());
+///
+/// let min = vec![f32::NAN, 2.0, 1.5].into_iter().collect::<MinFloat<_>>();
+/// assert_eq!(min.into_inner(), Some(1.5));
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinFloat<Float> {
+    item: Option<Float>,
+    count: usize,
+    policy: NanPolicy,
+}
+
+macro_rules! impl_minmaxfloat {
+    ($name: ident) => {
+        impl<Float> $name<Float> {
+            #[doc = concat!("Creates a new, empty, `", stringify!($name), "` with the provided `NaN` policy.")]
+            pub const fn new(policy: NanPolicy) -> Self {
+                Self {
+                    item: None,
+                    count: 0,
+                    policy,
+                }
+            }
+            /// Returns the `NaN` policy.
+            pub fn policy(&self) -> NanPolicy {
+                self.policy
+            }
+            /// Returns the number of items reduced in, including the ignored `NaN`s.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Returns `true` if no item was reduced in.
+            pub fn is_empty(&self) -> bool {
+                self.count == 0
+            }
+            /// Deconstruct self and return the inner value.
+            pub fn into_inner(self) -> Option<Float> {
+                self.item
+            }
+            /// Returns a reference to the inner value, if there is one.
+            pub fn as_ref(&self) -> Option<&Float> {
+                self.item.as_ref()
+            }
+        }
+
+        impl<Float> Default for $name<Float> {
+            fn default() -> Self {
+                Self::new(NanPolicy::default())
+            }
+        }
+    };
+    ($name: ident, $float: ty, $cmpval: expr) => {
+        impl $name<$float> {
+            /// Replaces the current value with the new one if the new one is greater/smaller,
+            /// according to the `NaN` policy.
+            pub fn reduce(&mut self, item: $float) {
+                self.count += 1;
+                if self.replaces(item) {
+                    self.item = Some(item);
+                }
+            }
+            /// Replaces the current value with the one behind the ref if it is greater/smaller,
+            /// according to the `NaN` policy.
+            pub fn reduce_ref(&mut self, item: &$float) {
+                self.reduce(*item)
+            }
+            fn replaces(&self, item: $float) -> bool {
+                match (self.item, self.policy) {
+                    (_, NanPolicy::Ignore) if item.is_nan() => false,
+                    (None, _) => true,
+                    (Some(current), NanPolicy::Ignore) => {
+                        item.partial_cmp(&current) == Some($cmpval)
+                    }
+                    (Some(current), NanPolicy::Propagate) => {
+                        !current.is_nan()
+                            && (item.is_nan() || item.partial_cmp(&current) == Some($cmpval))
+                    }
+                    (Some(current), NanPolicy::TotalOrder) => item.total_cmp(&current) == $cmpval,
+                }
+            }
+            #[doc = concat!("Alias for [`", stringify!($name), "::reduce`]")]
+            pub fn eval(&mut self, item: $float) {
+                self.reduce(item)
+            }
+            /// Reduce the value of `other` into self, using the `NaN` policy of self.
+            pub fn merge_from(&mut self, other: Self) {
+                if let Some(item) = other.item {
+                    let count = self.count + other.count;
+                    self.reduce(item);
+                    self.count = count;
+                }
+            }
+            /// Reduce the value of `other` into self and return the result.
+            pub fn merge(mut self, other: Self) -> Self {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl Extend<$float> for $name<$float> {
+            fn extend<It: IntoIterator<Item = $float>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.reduce(i));
+            }
+        }
+
+        impl<'a> Extend<&'a $float> for $name<$float> {
+            fn extend<It: IntoIterator<Item = &'a $float>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.reduce_ref(i));
+            }
+        }
+
+        impl core::iter::FromIterator<$float> for $name<$float> {
+            fn from_iter<It: IntoIterator<Item = $float>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a> core::iter::FromIterator<&'a $float> for $name<$float> {
+            fn from_iter<It: IntoIterator<Item = &'a $float>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl FoldItem<$float> for $name<$float> {
+            fn fold_item(&mut self, item: $float) {
+                self.reduce(item)
+            }
+        }
+
+        impl Folding for $name<$float> {
+            type Item = $float;
+            type Output = Option<$float>;
+            type Peek<'a>
+                = Option<&'a $float>
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Option<$float> {
+                self.into_inner()
+            }
+        }
+    };
+}
+
+impl_minmaxfloat!(MaxFloat);
+impl_minmaxfloat!(MinFloat);
+impl_minmaxfloat!(MaxFloat, f32, core::cmp::Ordering::Greater);
+impl_minmaxfloat!(MaxFloat, f64, core::cmp::Ordering::Greater);
+impl_minmaxfloat!(MinFloat, f32, core::cmp::Ordering::Less);
+impl_minmaxfloat!(MinFloat, f64, core::cmp::Ordering::Less);
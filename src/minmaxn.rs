@@ -0,0 +1,192 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::BottomK;
+use crate::FoldItem;
+use crate::Folding;
+use crate::TopK;
+
+/// The `MinMaxN` type keeps both the `n` smallest and the `n` largest items folded in, using
+/// the [`std::cmp::Ord`] trait.
+///
+/// This is a pair of [`BottomK`] and [`TopK`] that share the items, which are cloned only when
+/// kept by both. It can be used to compute trimmed statistics or to display outliers.
+///
+/// As `n` is given at runtime, the autofolder can't be created with
+/// [`collect`](Iterator::collect); use [`MinMaxN::with_items`] instead.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the 2 smallest and the 2 largest items:
+/// let mut minmax = MinMaxN::<u32>::new(2);
+///
+/// // We can "fold-in" individual items:
+/// minmax.fold(5);
+///
+/// // We can then peek at the largest of the smallest and the smallest of the largest items:
+/// println!("Partial thresholds are {:?}", minmax.peek());
+///
+/// // And still keep on folding by processing whole iterators:
+/// minmax.extend(vec![1, 9, 7, 3]);
+///
+/// // And finally consume the autofolder to get both sets of items in ascending order:
+/// assert_eq!(minmax.into_sorted_vecs(), (vec![1, 3], vec![7, 9]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinMaxN<Item> {
+    bottom: BottomK<Item>,
+    top: TopK<Item>,
+}
+
+impl<Item> MinMaxN<Item> {
+    /// Creates a new `MinMaxN` that keeps the `n` smallest and the `n` largest items.
+    pub fn new(n: usize) -> Self
+    where
+        Item: Ord,
+    {
+        Self {
+            bottom: BottomK::new(n),
+            top: TopK::new(n),
+        }
+    }
+    /// Folds all items of the iterator into self and return it.
+    ///
+    /// This is the builder-style replacement for [`collect`](Iterator::collect):
+    /// `MinMaxN::new(n).with_items(iter)`.
+    pub fn with_items<It>(mut self, iter: It) -> Self
+    where
+        It: IntoIterator<Item = Item>,
+        Item: Ord + Clone,
+    {
+        self.extend(iter);
+        self
+    }
+    /// Returns the maximum number of items kept on each side.
+    pub fn n(&self) -> usize {
+        self.top.k()
+    }
+    /// Returns the number of items kept on each side.
+    pub fn len(&self) -> usize {
+        self.top.len()
+    }
+    /// Returns `true` if no item is kept.
+    pub fn is_empty(&self) -> bool {
+        self.top.is_empty()
+    }
+    /// Returns references to the largest of the smallest items and to the smallest of the
+    /// largest items, if there are any.
+    pub fn peek(&self) -> Option<(&Item, &Item)>
+    where
+        Item: Ord,
+    {
+        self.bottom.peek().zip(self.top.peek())
+    }
+    /// Returns the [`BottomK`] with the smallest items.
+    pub fn bottom(&self) -> &BottomK<Item> {
+        &self.bottom
+    }
+    /// Returns the [`TopK`] with the largest items.
+    pub fn top(&self) -> &TopK<Item> {
+        &self.top
+    }
+    /// Deconstruct self and return the smallest and the largest items, both in ascending
+    /// order.
+    pub fn into_sorted_vecs(self) -> (Vec<Item>, Vec<Item>)
+    where
+        Item: Ord,
+    {
+        (self.bottom.into_sorted_vec(), self.top.into_sorted_vec())
+    }
+    /// Deconstruct self and return the [`BottomK`] and the [`TopK`].
+    pub fn into_parts(self) -> (BottomK<Item>, TopK<Item>) {
+        (self.bottom, self.top)
+    }
+    /// Folds an individual value into self, if it is among the `n` smallest or largest.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if the item is kept on both
+    /// sides.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Ord + Clone,
+    {
+        self.top.fold_ref(&item);
+        self.bottom.fold(item);
+    }
+    /// Folds in the value behind the reference, if it is among the `n` smallest or largest.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if necessary.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Ord + Clone,
+    {
+        self.top.fold_ref(item);
+        self.bottom.fold_ref(item);
+    }
+    /// Folds all items of `other` into self, keeping the `n` of self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Ord,
+    {
+        self.bottom.merge_from(other.bottom);
+        self.top.merge_from(other.top);
+    }
+    /// Folds all items of `other` into self and return the result, keeping the `n` of self.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Ord,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Extend<Item> for MinMaxN<Item>
+where
+    Item: Ord + Clone,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for MinMaxN<Item>
+where
+    Item: Ord + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+impl<Item> FoldItem<Item> for MinMaxN<Item>
+where
+    Item: Ord + Clone,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item> Folding for MinMaxN<Item>
+where
+    Item: Ord + Clone,
+{
+    type Item = Item;
+    type Output = (Vec<Item>, Vec<Item>);
+    type Peek<'a>
+        = Option<(&'a Item, &'a Item)>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        MinMaxN::peek(self)
+    }
+    fn finish(self) -> (Vec<Item>, Vec<Item>) {
+        self.into_sorted_vecs()
+    }
+}
@@ -5,7 +5,11 @@
 #![warn(missing_debug_implementations)]
 #![warn(missing_docs)]
 
-use num;
+use crate::minmax::slice_extreme;
+use crate::FoldItem;
+use crate::Folding;
+use crate::MinMaxUpdate;
+use crate::Tie;
 
 /// The `MaxNum` type uses the [`num`] crate functionality to keep
 /// only the largest iterated value.
@@ -35,9 +39,17 @@ use num;
 /// let max = max.into_inner();
 /// println!("Final max is {}", max);
 /// ```
+///
+/// The value starts at `min_value()`, which is also returned by `into_inner` if no item was
+/// reduced in; [`MaxNum::into_inner_checked`] returns `None` in that case instead.
+///
+/// Comparisons and hashing only take the inner value into account, not the count.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaxNum<Item> {
     item: Item,
+    #[cfg_attr(feature = "serde", serde(default))]
+    count: usize,
 }
 
 /// The `MinNum` type uses the [`num`] crate functionality to keep
@@ -68,37 +80,60 @@ pub struct MaxNum<Item> {
 /// let min = min.into_inner();
 /// println!("Final min is {}", min);
 /// ```
+///
+/// The value starts at `max_value()`, which is also returned by `into_inner` if no item was
+/// reduced in; [`MinNum::into_inner_checked`] returns `None` in that case instead.
+///
+/// Comparisons and hashing only take the inner value into account, not the count.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MinNum<Item> {
     item: Item,
+    #[cfg_attr(feature = "serde", serde(default))]
+    count: usize,
 }
 
 macro_rules! impl_minmax {
     ($name: ident, $initial: expr, $cmpval: expr) => {
         impl<Item> $name<Item> {
-            /// Creates a new `$name` with the provided initial value.
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the provided initial value.")]
             pub fn new() -> Self
             where
                 Item: num::Bounded,
             {
-                Self { item: $initial }
+                Self {
+                    item: $initial,
+                    count: 0,
+                }
+            }
+            /// Returns the number of items reduced in.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Returns `true` if no item was reduced in.
+            pub fn is_empty(&self) -> bool {
+                self.count == 0
             }
             /// Deconstruct self and return the inner value.
+            ///
+            /// If no item was reduced in, this is the initial bound.
             pub fn into_inner(self) -> Item {
                 self.item
             }
-            /// Returns a reference to the inner value, if there is one.
-            pub fn as_ref(&self) -> &Item {
-                &self.item
+            /// Deconstruct self and return the inner value, or `None` if no item was reduced in.
+            pub fn into_inner_checked(self) -> Option<Item> {
+                if self.is_empty() {
+                    None
+                } else {
+                    Some(self.item)
+                }
             }
             /// Replaces the current value with the new one if the new one is greater/smaller.
             pub fn reduce(&mut self, item: Item)
             where
                 Item: PartialOrd,
             {
-                if item.partial_cmp(&self.item) == Some($cmpval) {
-                    self.item = item;
-                }
+                self.reduce_check(item);
             }
             /// Replaces the current value with the one behing the ref if it is greater/smaller.
             ///
@@ -107,24 +142,81 @@ macro_rules! impl_minmax {
             where
                 Item: PartialOrd + Clone,
             {
-                if item.partial_cmp(&self.item) == Some($cmpval) {
+                self.reduce_ref_check(item);
+            }
+            #[doc = concat!("Same as [`", stringify!($name), "::reduce`], but returns `true` if the value was replaced.")]
+            pub fn reduce_check(&mut self, item: Item) -> bool
+            where
+                Item: PartialOrd,
+            {
+                self.count += 1;
+                let replaces = item.partial_cmp(&self.item) == Some($cmpval);
+                if replaces {
+                    self.item = item;
+                }
+                replaces
+            }
+            #[doc = concat!("Same as [`", stringify!($name), "::reduce_ref`], but returns `true` if the value was replaced.")]
+            pub fn reduce_ref_check(&mut self, item: &Item) -> bool
+            where
+                Item: PartialOrd + Clone,
+            {
+                self.count += 1;
+                let replaces = item.partial_cmp(&self.item) == Some($cmpval);
+                if replaces {
                     self.item = item.clone();
                 }
+                replaces
             }
-            /// Alias for [`$name::reduce`]
+            /// Reduces all items of the slice, with the same result as `extend` for totally ordered
+            /// items, including floats.
+            ///
+            /// This compares copies of the items with independent accumulators, which is faster
+            /// than `extend` for primitive types.
+            pub fn reduce_slice(&mut self, items: &[Item])
+            where
+                Item: PartialOrd + Copy,
+            {
+                self.item = slice_extreme(self.item, items, $cmpval, Tie::First);
+                self.count += items.len();
+            }
+            #[doc = concat!("Alias for [`", stringify!($name), "::reduce`]")]
             pub fn eval(&mut self, item: Item)
             where
                 Item: PartialOrd,
             {
                 self.reduce(item)
             }
-            /// Alias for [`$name::reduce_ref`]
+            #[doc = concat!("Alias for [`", stringify!($name), "::reduce_ref`]")]
             pub fn eval_ref(&mut self, item: &Item)
             where
                 Item: PartialOrd + Clone,
             {
                 self.reduce_ref(item)
             }
+            /// Reduce the value of `other` into self.
+            pub fn merge_from(&mut self, other: Self)
+            where
+                Item: PartialOrd,
+            {
+                let count = self.count + other.count;
+                self.reduce(other.item);
+                self.count = count;
+            }
+            /// Reduce the value of `other` into self and return the result.
+            pub fn merge(mut self, other: Self) -> Self
+            where
+                Item: PartialOrd,
+            {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl<Item> AsRef<Item> for $name<Item> {
+            fn as_ref(&self) -> &Item {
+                &self.item
+            }
         }
 
         impl<Item> Default for $name<Item>
@@ -147,6 +239,44 @@ macro_rules! impl_minmax {
             }
         }
 
+        impl<Item> PartialEq for $name<Item>
+        where
+            Item: PartialEq,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.item == other.item
+            }
+        }
+
+        impl<Item> Eq for $name<Item> where Item: Eq {}
+
+        impl<Item> PartialOrd for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.item.partial_cmp(&other.item)
+            }
+        }
+
+        impl<Item> Ord for $name<Item>
+        where
+            Item: Ord,
+        {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.item.cmp(&other.item)
+            }
+        }
+
+        impl<Item> core::hash::Hash for $name<Item>
+        where
+            Item: core::hash::Hash,
+        {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.item.hash(state)
+            }
+        }
+
         impl<Item> Extend<Item> for $name<Item>
         where
             Item: PartialOrd,
@@ -165,7 +295,7 @@ macro_rules! impl_minmax {
             }
         }
 
-        impl<Item> std::iter::FromIterator<Item> for $name<Item>
+        impl<Item> core::iter::FromIterator<Item> for $name<Item>
         where
             Item: PartialOrd + num::Bounded,
         {
@@ -176,7 +306,7 @@ macro_rules! impl_minmax {
             }
         }
 
-        impl<'a, Item> std::iter::FromIterator<&'a Item> for $name<Item>
+        impl<'a, Item> core::iter::FromIterator<&'a Item> for $name<Item>
         where
             Item: PartialOrd + Clone + num::Bounded,
         {
@@ -186,11 +316,38 @@ macro_rules! impl_minmax {
                 autofolder
             }
         }
+
+        impl<Item> FoldItem<Item> for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            fn fold_item(&mut self, item: Item) {
+                self.reduce(item)
+            }
+        }
+
+        impl<Item> Folding for $name<Item>
+        where
+            Item: PartialOrd,
+        {
+            type Item = Item;
+            type Output = Item;
+            type Peek<'a>
+                = &'a Item
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Item {
+                self.into_inner()
+            }
+        }
     };
 }
 
-impl_minmax!(MaxNum, Item::min_value(), std::cmp::Ordering::Greater);
-impl_minmax!(MinNum, Item::max_value(), std::cmp::Ordering::Less);
+impl_minmax!(MaxNum, Item::min_value(), core::cmp::Ordering::Greater);
+impl_minmax!(MinNum, Item::max_value(), core::cmp::Ordering::Less);
 
 /// The `MinMaxNum` type uses the [`num`] crate functionality to keep
 /// both the smallest and largest iterated values.
@@ -225,10 +382,18 @@ impl_minmax!(MinNum, Item::max_value(), std::cmp::Ordering::Less);
 /// let (min, max) = minmax.into_inner();
 /// println!("Final min is {}, max is {}", min, max);
 /// ```
+///
+/// The values start at `(max_value(), min_value())`, which is also returned by `into_inner` if
+/// no item was reduced in; [`MinMaxNum::into_inner_checked`] returns `None` in that case instead.
+///
+/// Comparisons and hashing only take the inner values into account, not the count.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MinMaxNum<Item> {
     min: Item,
     max: Item,
+    #[cfg_attr(feature = "serde", serde(default))]
+    count: usize,
 }
 
 impl<Item> MinMaxNum<Item> {
@@ -240,12 +405,31 @@ impl<Item> MinMaxNum<Item> {
         Self {
             min: Item::max_value(),
             max: Item::min_value(),
+            count: 0,
         }
     }
+    /// Returns the number of items reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no item was reduced in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
     /// Deconstruct self and return the inner values.
+    ///
+    /// If no item was reduced in, these are the initial bounds: `(max_value(), min_value())`.
     pub fn into_inner(self) -> (Item, Item) {
         (self.min, self.max)
     }
+    /// Deconstruct self and return the inner values, or `None` if no item was reduced in.
+    pub fn into_inner_checked(self) -> Option<(Item, Item)> {
+        if self.is_empty() {
+            None
+        } else {
+            Some((self.min, self.max))
+        }
+    }
     /// Returns a reference to the inner values.
     pub fn as_ref(&self) -> (&Item, &Item) {
         (&self.min, &self.max)
@@ -263,24 +447,59 @@ impl<Item> MinMaxNum<Item> {
     where
         Item: PartialOrd + Clone,
     {
-        if item.partial_cmp(&self.min) == Some(std::cmp::Ordering::Less) {
+        self.reduce_check(item);
+    }
+    /// Replaces a current value with the one behind the ref if it is greater/smaller.
+    pub fn reduce_ref(&mut self, item: &Item)
+    where
+        Item: PartialOrd + Clone,
+    {
+        self.reduce_ref_check(item);
+    }
+    /// Same as [`MinMaxNum::reduce`], but returns which values were replaced.
+    pub fn reduce_check(&mut self, item: Item) -> MinMaxUpdate
+    where
+        Item: PartialOrd + Clone,
+    {
+        self.count += 1;
+        let min = item.partial_cmp(&self.min) == Some(core::cmp::Ordering::Less);
+        let max = item.partial_cmp(&self.max) == Some(core::cmp::Ordering::Greater);
+        if min {
             self.min = item.clone();
         }
-        if item.partial_cmp(&self.max) == Some(std::cmp::Ordering::Greater) {
+        if max {
             self.max = item;
         }
+        MinMaxUpdate::from_flags(min, max)
     }
-    /// Replaces a current value with the one behind the ref if it is greater/smaller.
-    pub fn reduce_ref(&mut self, item: &Item)
+    /// Same as [`MinMaxNum::reduce_ref`], but returns which values were replaced.
+    pub fn reduce_ref_check(&mut self, item: &Item) -> MinMaxUpdate
     where
         Item: PartialOrd + Clone,
     {
-        if item.partial_cmp(&self.min) == Some(std::cmp::Ordering::Less) {
+        self.count += 1;
+        let min = item.partial_cmp(&self.min) == Some(core::cmp::Ordering::Less);
+        let max = item.partial_cmp(&self.max) == Some(core::cmp::Ordering::Greater);
+        if min {
             self.min = item.clone();
         }
-        if item.partial_cmp(&self.max) == Some(std::cmp::Ordering::Greater) {
+        if max {
             self.max = item.clone();
         }
+        MinMaxUpdate::from_flags(min, max)
+    }
+    /// Reduces all items of the slice, with the same result as `extend` for totally ordered
+    /// items, including floats.
+    ///
+    /// This compares copies of the items with independent accumulators, which is faster than
+    /// `extend` for primitive types.
+    pub fn reduce_slice(&mut self, items: &[Item])
+    where
+        Item: PartialOrd + Copy,
+    {
+        self.min = slice_extreme(self.min, items, core::cmp::Ordering::Less, Tie::First);
+        self.max = slice_extreme(self.max, items, core::cmp::Ordering::Greater, Tie::First);
+        self.count += items.len();
     }
     /// Alias for [`MinMaxNum::reduce`]
     pub fn eval(&mut self, item: Item)
@@ -296,6 +515,27 @@ impl<Item> MinMaxNum<Item> {
     {
         self.reduce_ref(item)
     }
+    /// Reduce the values of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: PartialOrd,
+    {
+        if other.min.partial_cmp(&self.min) == Some(core::cmp::Ordering::Less) {
+            self.min = other.min;
+        }
+        if other.max.partial_cmp(&self.max) == Some(core::cmp::Ordering::Greater) {
+            self.max = other.max;
+        }
+        self.count += other.count;
+    }
+    /// Reduce the values of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: PartialOrd,
+    {
+        self.merge_from(other);
+        self
+    }
 }
 
 impl<Item> Default for MinMaxNum<Item>
@@ -318,6 +558,44 @@ where
     }
 }
 
+impl<Item> PartialEq for MinMaxNum<Item>
+where
+    Item: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<Item> Eq for MinMaxNum<Item> where Item: Eq {}
+
+impl<Item> PartialOrd for MinMaxNum<Item>
+where
+    Item: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_ref().partial_cmp(&other.as_ref())
+    }
+}
+
+impl<Item> Ord for MinMaxNum<Item>
+where
+    Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_ref().cmp(&other.as_ref())
+    }
+}
+
+impl<Item> core::hash::Hash for MinMaxNum<Item>
+where
+    Item: core::hash::Hash,
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
 impl<Item> Extend<Item> for MinMaxNum<Item>
 where
     Item: PartialOrd + Clone,
@@ -336,7 +614,7 @@ where
     }
 }
 
-impl<Item> std::iter::FromIterator<Item> for MinMaxNum<Item>
+impl<Item> core::iter::FromIterator<Item> for MinMaxNum<Item>
 where
     Item: PartialOrd + num::Bounded + Clone,
 {
@@ -347,7 +625,7 @@ where
     }
 }
 
-impl<'a, Item> std::iter::FromIterator<&'a Item> for MinMaxNum<Item>
+impl<'a, Item> core::iter::FromIterator<&'a Item> for MinMaxNum<Item>
 where
     Item: PartialOrd + Clone + num::Bounded,
 {
@@ -357,3 +635,30 @@ where
         autofolder
     }
 }
+
+impl<Item> FoldItem<Item> for MinMaxNum<Item>
+where
+    Item: PartialOrd + Clone,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for MinMaxNum<Item>
+where
+    Item: PartialOrd + Clone,
+{
+    type Item = Item;
+    type Output = (Item, Item);
+    type Peek<'a>
+        = (&'a Item, &'a Item)
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> (Item, Item) {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The policy that [`Mode`] uses to choose between items with the same number of occurrences.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum ModeTie {
+    /// Choose the item whose first occurrence came first.
+    #[default]
+    First,
+    /// Choose the item whose last occurrence came last.
+    Last,
+}
+
+impl ModeTie {
+    fn cmp(self, a: &Occurrences, b: &Occurrences) -> Ordering {
+        a.count.cmp(&b.count).then_with(|| match self {
+            ModeTie::First => b.first.cmp(&a.first),
+            ModeTie::Last => a.last.cmp(&b.last),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Occurrences {
+    count: usize,
+    first: usize,
+    last: usize,
+}
+
+/// The `Mode` type counts the occurrences of the items in a [`HashMap`] and keeps the most
+/// frequent one.
+///
+/// Ties are resolved by the [`ModeTie`] policy given to [`Mode::with_tie`]; the default is
+/// [`ModeTie::First`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that finds the most frequent char.
+/// let mut mode = Mode::<char>::default();
+///
+/// // We can "fold-in" individual items:
+/// mode.fold('b');
+///
+/// // We can then peek at the running output:
+/// println!("Partial mode is {:?}", mode.peek());
+///
+/// // And still keep on folding by processing whole iterators:
+/// mode.extend("abracadabra".chars());
+///
+/// // And finally consume the autofolder to get the mode and its count:
+/// assert_eq!(mode.into_inner(), Some(('a', 5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mode<Item> {
+    counts: HashMap<Item, Occurrences>,
+    seq: usize,
+    tie: ModeTie,
+}
+
+impl<Item> Mode<Item> {
+    /// Creates a new empty `Mode` with the [`ModeTie::First`] tie policy.
+    pub fn new() -> Self {
+        Self::with_tie(ModeTie::First)
+    }
+    /// Creates a new empty `Mode` with the provided tie policy.
+    pub fn with_tie(tie: ModeTie) -> Self {
+        Self {
+            counts: HashMap::new(),
+            seq: 0,
+            tie,
+        }
+    }
+    /// Returns the tie policy.
+    pub fn tie(&self) -> ModeTie {
+        self.tie
+    }
+    /// Returns the number of distinct items folded in.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+    /// Returns `true` if no item was folded in.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+    /// Returns the number of occurrences of the given item.
+    pub fn count(&self, item: &Item) -> usize
+    where
+        Item: Eq + Hash,
+    {
+        self.counts.get(item).map_or(0, |o| o.count)
+    }
+    /// Returns a reference to the most frequent item and its count, if there is one.
+    ///
+    /// This goes through all distinct items.
+    pub fn peek(&self) -> Option<(&Item, usize)> {
+        self.counts
+            .iter()
+            .max_by(|(_, a), (_, b)| self.tie.cmp(a, b))
+            .map(|(item, o)| (item, o.count))
+    }
+    /// Deconstruct self and return the most frequent item and its count, if there is one.
+    pub fn into_inner(self) -> Option<(Item, usize)>
+    where
+        Item: Eq + Hash,
+    {
+        let tie = self.tie;
+        self.counts
+            .into_iter()
+            .max_by(|(_, a), (_, b)| tie.cmp(a, b))
+            .map(|(item, o)| (item, o.count))
+    }
+    fn add(&mut self, item: Item, count: usize, first: usize, last: usize)
+    where
+        Item: Eq + Hash,
+    {
+        self.counts
+            .entry(item)
+            .and_modify(|o| {
+                o.count += count;
+                o.last = last;
+            })
+            .or_insert(Occurrences { count, first, last });
+    }
+    /// Counts an individual item.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Eq + Hash,
+    {
+        let seq = self.seq;
+        self.seq += 1;
+        self.add(item, 1, seq, seq);
+    }
+    /// Counts the item behind the reference.
+    ///
+    /// This function requires the `Clone` trait, but uses it only for items not seen before.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Eq + Hash + Clone,
+    {
+        let seq = self.seq;
+        self.seq += 1;
+        if let Some(o) = self.counts.get_mut(item) {
+            o.count += 1;
+            o.last = seq;
+        } else {
+            self.add(item.clone(), 1, seq, seq);
+        }
+    }
+    /// Adds the counts of `other` into self, as if its items were folded in after the ones of
+    /// self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Eq + Hash,
+    {
+        let offset = self.seq;
+        self.seq += other.seq;
+        for (item, o) in other.counts {
+            self.add(item, o.count, offset + o.first, offset + o.last);
+        }
+    }
+    /// Adds the counts of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Eq + Hash,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for Mode<Item> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> Extend<Item> for Mode<Item>
+where
+    Item: Eq + Hash,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for Mode<Item>
+where
+    Item: Eq + Hash + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Item> for Mode<Item>
+where
+    Item: Eq + Hash,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> std::iter::FromIterator<&'a Item> for Mode<Item>
+where
+    Item: Eq + Hash + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for Mode<Item>
+where
+    Item: Eq + Hash,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item> Folding for Mode<Item>
+where
+    Item: Eq + Hash,
+{
+    type Item = Item;
+    type Output = Option<(Item, usize)>;
+    type Peek<'a>
+        = Option<(&'a Item, usize)>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        Mode::peek(self)
+    }
+    fn finish(self) -> Option<(Item, usize)> {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// The `MultiFold` type drives a tuple of autofolders that share the item type from a single
+/// iterator pass.
+///
+/// Tuples of up to 6 autofolders implement [`FoldItem`] and [`Folding`] themselves, by
+/// folding each item into every member: the item is cloned for all but the last member,
+/// which gets the item itself. `MultiFold` wraps such a tuple to also provide [`Extend`] and
+/// [`FromIterator`](std::iter::FromIterator), which can't be implemented for tuples directly.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that computes the min, max and sum of f64 items:
+/// let mut multi = MultiFold::new((Min::default(), Max::default(), SumKahan::<f64>::default()));
+///
+/// // We can "fold-in" individual items:
+/// multi.fold(2.5);
+///
+/// // And still keep on folding by processing whole iterators:
+/// multi.extend(vec![1.0, 4.0, 0.5]);
+///
+/// // And finally consume the autofolder to get the output of all members:
+/// let (min, max, sum) = multi.finish();
+/// assert_eq!((min, max, sum), (Some(0.5), Some(4.0), 8.0));
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct MultiFold<Folders> {
+    folders: Folders,
+}
+
+impl<Folders> MultiFold<Folders> {
+    /// Creates a new `MultiFold` with the provided tuple of autofolders.
+    pub const fn new(folders: Folders) -> Self {
+        Self { folders }
+    }
+    /// Deconstruct self and return the tuple of autofolders.
+    pub fn into_inner(self) -> Folders {
+        self.folders
+    }
+    /// Folds an individual item into all autofolders.
+    pub fn fold<Item>(&mut self, item: Item)
+    where
+        Folders: FoldItem<Item>,
+    {
+        self.folders.fold_item(item)
+    }
+}
+
+impl<Folders> AsRef<Folders> for MultiFold<Folders> {
+    fn as_ref(&self) -> &Folders {
+        &self.folders
+    }
+}
+
+impl<Folders> AsMut<Folders> for MultiFold<Folders> {
+    fn as_mut(&mut self) -> &mut Folders {
+        &mut self.folders
+    }
+}
+
+impl<Folders> From<Folders> for MultiFold<Folders> {
+    fn from(folders: Folders) -> Self {
+        Self::new(folders)
+    }
+}
+
+impl<Item, Folders> Extend<Item> for MultiFold<Folders>
+where
+    Folders: FoldItem<Item>,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<Item, Folders> core::iter::FromIterator<Item> for MultiFold<Folders>
+where
+    Folders: FoldItem<Item> + Default,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item, Folders> FoldItem<Item> for MultiFold<Folders>
+where
+    Folders: FoldItem<Item>,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Folders> Folding for MultiFold<Folders>
+where
+    Folders: Folding,
+{
+    type Item = Folders::Item;
+    type Output = Folders::Output;
+    type Peek<'a>
+        = Folders::Peek<'a>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.folders.peek()
+    }
+    fn finish(self) -> Folders::Output {
+        self.folders.finish()
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($init: ident $index: tt),*; $last: ident $lastindex: tt) => {
+        impl<Item, $($init,)* $last> FoldItem<Item> for ($($init,)* $last,)
+        where
+            Item: Clone,
+            $($init: FoldItem<Item>,)*
+            $last: FoldItem<Item>,
+        {
+            fn fold_item(&mut self, item: Item) {
+                $(self.$index.fold_item(item.clone());)*
+                self.$lastindex.fold_item(item);
+            }
+        }
+
+        impl<$($init,)* $last> Folding for ($($init,)* $last,)
+        where
+            $last: Folding,
+            $last::Item: Clone,
+            $($init: Folding<Item = $last::Item>,)*
+        {
+            type Item = $last::Item;
+            type Output = ($($init::Output,)* $last::Output,);
+            type Peek<'a>
+                = ($($init::Peek<'a>,)* $last::Peek<'a>,)
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                ($(self.$index.peek(),)* self.$lastindex.peek(),)
+            }
+            fn finish(self) -> Self::Output {
+                ($(self.$index.finish(),)* self.$lastindex.finish(),)
+            }
+        }
+    };
+}
+
+impl_tuple!(A 0; B 1);
+impl_tuple!(A 0, B 1; C 2);
+impl_tuple!(A 0, B 1, C 2; D 3);
+impl_tuple!(A 0, B 1, C 2, D 3; E 4);
+impl_tuple!(A 0, B 1, C 2, D 3, E 4; F 5);
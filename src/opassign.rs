@@ -0,0 +1,502 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use core::ops::AddAssign;
+use core::ops::BitAndAssign;
+use core::ops::BitOrAssign;
+use core::ops::BitXorAssign;
+use core::ops::MulAssign;
+
+/// The `Adder` type uses the [`std::ops::AddAssign`] trait to sum the iterated values into an
+/// output.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that sums u32 items.
+/// let mut sum = Adder::<u32>::default();
+///
+/// // We can "fold-in" individual items:
+/// sum.fold(3);
+///
+/// // We can then peek at the running output, directly or through `Deref`:
+/// println!("Partial sum is {}", *sum);
+///
+/// // And still keep on folding by processing whole iterators:
+/// sum.extend((1..=5));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// assert_eq!(sum.into_inner(), 18);
+/// ```
+///
+/// Overflows are handled by the `AddAssign` implementation of the items, that usually panics
+/// in debug builds and wraps in release builds; see [`CheckedAdder`](crate::CheckedAdder),
+/// [`SaturatingAdder`](crate::SaturatingAdder) and [`WrappingAdder`](crate::WrappingAdder) for
+/// explicit overflow handling.
+///
+/// `Adder` also implements [`Sum`](core::iter::Sum), which lets code that is generic over
+/// `Sum` use it, as in `iter.sum::<Adder<_>>()`.
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adder<Item> {
+    item: Item,
+    count: usize,
+}
+
+/// The `BitOrer` type uses the [`std::ops::BitOrAssign`] trait to accumulate the iterated
+/// values into an output with `|=`.
+///
+/// The default value is the one given by [`Default`], which is `0` for the primitive integers
+/// and `false` for `bool`. This is the autofolder to use for accumulated bitmasks.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// const READ: u8 = 0b001;
+/// const WRITE: u8 = 0b010;
+///
+/// let mut flags = vec![READ, WRITE, READ].into_iter().collect::<BitOrer<u8>>();
+/// assert_eq!(*flags, READ | WRITE);
+///
+/// let any = vec![false, true, false].into_iter().collect::<BitOrer<bool>>();
+/// assert!(any.into_inner());
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitOrer<Item> {
+    item: Item,
+    count: usize,
+}
+
+/// The `BitXorer` type uses the [`std::ops::BitXorAssign`] trait to accumulate the iterated
+/// values into an output with `^=`.
+///
+/// The default value is the one given by [`Default`], which is `0` for the primitive integers
+/// and `false` for `bool`.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let parity = vec![0xf0_u8, 0x0f, 0xff].into_iter().collect::<BitXorer<_>>();
+/// assert_eq!(parity.into_inner(), 0);
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitXorer<Item> {
+    item: Item,
+    count: usize,
+}
+
+/// The `Multiplier` type uses the [`std::ops::MulAssign`] trait to multiply the iterated values.
+///
+/// As there's no identity value in [`Default`] for multiplication, this is a reducer: the first
+/// item is incorporated as-is, and each following one is multiplied into it. It also
+/// implements [`core::iter::Product`], and is aliased as [`Product`](crate::Product).
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let mut product = (1..=4).collect::<Multiplier<u32>>();
+/// product.fold(5);
+/// assert_eq!(*product, Some(120));
+///
+/// let empty = Multiplier::<u32>::default();
+/// assert_eq!(empty.into_inner(), None);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Multiplier<Item> {
+    item: Option<Item>,
+    count: usize,
+}
+
+/// The `BitAnder` type uses the [`std::ops::BitAndAssign`] trait to accumulate the iterated
+/// values into an output with `&=`.
+///
+/// As there's no identity value in [`Default`] for `&`, this is a reducer: the first item is
+/// incorporated as-is, and each following one is and-ed into it.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let common = vec![0b1110_u8, 0b0111, 0b0110].into_iter().collect::<BitAnder<_>>();
+/// assert_eq!(common.into_inner(), Some(0b0110));
+///
+/// let all = vec![true, false, true].into_iter().collect::<BitAnder<bool>>();
+/// assert_eq!(*all, Some(false));
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitAnder<Item> {
+    item: Option<Item>,
+    count: usize,
+}
+
+macro_rules! impl_opassign {
+    (fold $name: ident, $trait: ident, $method: ident, $verb: literal) => {
+        impl<Item> $name<Item> {
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the provided initial value.")]
+            pub const fn new(initial: Item) -> Self {
+                Self {
+                    item: initial,
+                    count: 0,
+                }
+            }
+            /// Deconstruct self and return the inner value.
+            pub fn into_inner(self) -> Item {
+                self.item
+            }
+            /// Returns the number of items folded in.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Returns `true` if no item was folded in.
+            pub fn is_empty(&self) -> bool {
+                self.count == 0
+            }
+            #[doc = concat!($verb, " the given item into the current value.")]
+            pub fn fold(&mut self, item: Item)
+            where
+                Item: $trait,
+            {
+                $trait::$method(&mut self.item, item);
+                self.count += 1;
+            }
+            #[doc = concat!($verb, " the item behind the ref into the current value.")]
+            ///
+            /// This function requires the `Clone` trait.
+            pub fn fold_ref(&mut self, item: &Item)
+            where
+                Item: $trait + Clone,
+            {
+                self.fold(item.clone())
+            }
+            #[doc = concat!($verb, " all items of the slice into the current value, with the same result as `extend`.")]
+            ///
+            /// This works with copies of the items in a simple loop, that the compiler can
+            /// vectorize for integers; the items are still folded in order, so that float results
+            /// are the same as with `extend`.
+            pub fn fold_slice(&mut self, items: &[Item])
+            where
+                Item: $trait + Copy,
+            {
+                for &item in items {
+                    $trait::$method(&mut self.item, item);
+                }
+                self.count += items.len();
+            }
+            #[doc = concat!($verb, " the value of `other` into self.")]
+            pub fn merge_from(&mut self, other: Self)
+            where
+                Item: $trait,
+            {
+                let count = self.count + other.count;
+                self.fold(other.item);
+                self.count = count;
+            }
+            #[doc = concat!($verb, " the value of `other` into self and return the result.")]
+            pub fn merge(mut self, other: Self) -> Self
+            where
+                Item: $trait,
+            {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl<Item> AsRef<Item> for $name<Item> {
+            fn as_ref(&self) -> &Item {
+                &self.item
+            }
+        }
+
+        impl<Item> core::ops::Deref for $name<Item> {
+            type Target = Item;
+            fn deref(&self) -> &Item {
+                &self.item
+            }
+        }
+
+        impl<Item> From<Item> for $name<Item> {
+            fn from(item: Item) -> Self {
+                Self::new(item)
+            }
+        }
+
+        impl<Item> core::iter::FromIterator<Item> for $name<Item>
+        where
+            Item: $trait + Default,
+        {
+            fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a, Item> core::iter::FromIterator<&'a Item> for $name<Item>
+        where
+            Item: $trait + Default + Clone,
+        {
+            fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<Item> Folding for $name<Item>
+        where
+            Item: $trait,
+        {
+            type Item = Item;
+            type Output = Item;
+            type Peek<'a>
+                = &'a Item
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Item {
+                self.into_inner()
+            }
+        }
+
+        impl_opassign!(common $name, $trait);
+    };
+    (reduce $name: ident, $trait: ident, $method: ident, $verb: literal) => {
+        impl<Item> $name<Item> {
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the provided initial value, which counts as an item.")]
+            pub const fn new(initial: Item) -> Self {
+                Self {
+                    item: Some(initial),
+                    count: 1,
+                }
+            }
+            /// Deconstruct self and return the inner value.
+            pub fn into_inner(self) -> Option<Item> {
+                self.item
+            }
+            /// Returns the number of items folded in.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Returns `true` if no item was folded in.
+            pub fn is_empty(&self) -> bool {
+                self.count == 0
+            }
+            /// Returns a reference to the inner value, if there is one.
+            pub fn as_ref(&self) -> Option<&Item> {
+                self.item.as_ref()
+            }
+            #[doc = concat!($verb, " the given item into the current value, or incorporates it as-is if there isn't one.")]
+            pub fn fold(&mut self, item: Item)
+            where
+                Item: $trait,
+            {
+                match &mut self.item {
+                    Some(current) => $trait::$method(current, item),
+                    None => self.item = Some(item),
+                }
+                self.count += 1;
+            }
+            #[doc = concat!($verb, " the item behind the ref into the current value, or incorporates it as-is if there isn't one.")]
+            ///
+            /// This function requires the `Clone` trait.
+            pub fn fold_ref(&mut self, item: &Item)
+            where
+                Item: $trait + Clone,
+            {
+                self.fold(item.clone())
+            }
+            #[doc = concat!("Alias for [`", stringify!($name), "::fold`], as this is a reducer.")]
+            pub fn reduce(&mut self, item: Item)
+            where
+                Item: $trait,
+            {
+                self.fold(item)
+            }
+            #[doc = concat!("Alias for [`", stringify!($name), "::fold_ref`], as this is a reducer.")]
+            pub fn reduce_ref(&mut self, item: &Item)
+            where
+                Item: $trait + Clone,
+            {
+                self.fold_ref(item)
+            }
+            #[doc = concat!($verb, " the value of `other` into self.")]
+            pub fn merge_from(&mut self, other: Self)
+            where
+                Item: $trait,
+            {
+                if let Some(item) = other.item {
+                    let count = self.count + other.count;
+                    self.fold(item);
+                    self.count = count;
+                }
+            }
+            #[doc = concat!($verb, " the value of `other` into self and return the result.")]
+            pub fn merge(mut self, other: Self) -> Self
+            where
+                Item: $trait,
+            {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl<Item> Default for $name<Item> {
+            fn default() -> Self {
+                Self {
+                    item: None,
+                    count: 0,
+                }
+            }
+        }
+
+        impl<Item> core::ops::Deref for $name<Item> {
+            type Target = Option<Item>;
+            fn deref(&self) -> &Option<Item> {
+                &self.item
+            }
+        }
+
+        impl<Item> From<Item> for $name<Item> {
+            fn from(item: Item) -> Self {
+                Self::new(item)
+            }
+        }
+
+        impl<Item> core::iter::FromIterator<Item> for $name<Item>
+        where
+            Item: $trait,
+        {
+            fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a, Item> core::iter::FromIterator<&'a Item> for $name<Item>
+        where
+            Item: $trait + Clone,
+        {
+            fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<Item> Folding for $name<Item>
+        where
+            Item: $trait,
+        {
+            type Item = Item;
+            type Output = Option<Item>;
+            type Peek<'a>
+                = Option<&'a Item>
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Option<Item> {
+                self.into_inner()
+            }
+        }
+
+        impl_opassign!(common $name, $trait);
+    };
+    (common $name: ident, $trait: ident) => {
+        impl<Item> Extend<Item> for $name<Item>
+        where
+            Item: $trait,
+        {
+            fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold(i));
+            }
+        }
+
+        impl<'a, Item> Extend<&'a Item> for $name<Item>
+        where
+            Item: $trait + Clone,
+        {
+            fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold_ref(i));
+            }
+        }
+
+        impl<Item> FoldItem<Item> for $name<Item>
+        where
+            Item: $trait,
+        {
+            fn fold_item(&mut self, item: Item) {
+                self.fold(item)
+            }
+        }
+
+        impl<Item> core::ops::AddAssign<Item> for $name<Item>
+        where
+            Item: $trait,
+        {
+            fn add_assign(&mut self, item: Item) {
+                self.fold(item)
+            }
+        }
+    };
+}
+
+impl_opassign!(fold Adder, AddAssign, add_assign, "Adds");
+impl_opassign!(fold BitOrer, BitOrAssign, bitor_assign, "Ors");
+impl_opassign!(fold BitXorer, BitXorAssign, bitxor_assign, "Xors");
+impl_opassign!(reduce Multiplier, MulAssign, mul_assign, "Multiplies");
+impl_opassign!(reduce BitAnder, BitAndAssign, bitand_assign, "Ands");
+
+impl<Item> core::iter::Sum<Item> for Adder<Item>
+where
+    Item: AddAssign + Default,
+{
+    fn sum<It: Iterator<Item = Item>>(iter: It) -> Self {
+        iter.collect()
+    }
+}
+
+impl<'a, Item> core::iter::Sum<&'a Item> for Adder<Item>
+where
+    Item: AddAssign + Default + Clone,
+{
+    fn sum<It: Iterator<Item = &'a Item>>(iter: It) -> Self {
+        iter.collect()
+    }
+}
+
+impl<Item> core::iter::Product<Item> for Multiplier<Item>
+where
+    Item: MulAssign,
+{
+    fn product<It: Iterator<Item = Item>>(iter: It) -> Self {
+        iter.collect()
+    }
+}
+
+impl<'a, Item> core::iter::Product<&'a Item> for Multiplier<Item>
+where
+    Item: MulAssign + Clone,
+{
+    fn product<It: Iterator<Item = &'a Item>>(iter: It) -> Self {
+        iter.collect()
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use crate::Multiplier;
+
+/// The `Product` type uses the [`std::ops::MulAssign`] trait to multiply the iterated values.
+///
+/// This is an alias for [`Multiplier`], named after [`core::iter::Product`]. It's a reducer:
+/// the first item is incorporated as-is, and each following one is multiplied into it.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that multiplies u32 items.
+/// let mut product = Product::<u32>::default();
+///
+/// // We can "reduce-in" individual items.
+/// // (note: as this is the first value, we incorporate it as-is)
+/// product.reduce(2);
+///
+/// // We can then peek at the running output:
+/// println!("Partial product is {:?}", product.as_ref());
+///
+/// // And still keep on reducing by processing whole iterators:
+/// product.extend((1..=5));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// assert_eq!(product.into_inner(), Some(240));
+/// ```
+///
+/// Overflows are handled by the `MulAssign` implementation of the items, that usually panics
+/// in debug builds and wraps in release builds; see [`CheckedProduct`] for explicit overflow
+/// handling.
+///
+/// Both types implement [`core::iter::Product`], so that `iter.product::<Product<_>>()` works
+/// as well as `collect`.
+pub type Product<Item> = Multiplier<Item>;
+
+/// The `CheckedProduct` type multiplies primitive integers, recording overflows.
+///
+/// The product starts at `1`, and becomes *overflowed* when a multiplication overflows: from
+/// then on, further items are ignored and [`CheckedProduct::into_inner`] returns `None`.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that multiplies u8 items.
+/// let mut product = CheckedProduct::<u8>::default();
+///
+/// // We can "fold-in" individual items:
+/// product.fold(5);
+///
+/// // We can then peek at the running output:
+/// println!("Partial product is {:?}", product.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// product.extend(vec![10, 6]);
+///
+/// // And finally consume the autofolder to get the final output value, if there was no
+/// // overflow:
+/// assert!(product.is_overflowed());
+/// assert_eq!(product.into_inner(), None);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheckedProduct<Item> {
+    item: Option<Item>,
+}
+
+macro_rules! impl_checkedproduct {
+    ($($int: ty),*) => {$(
+        impl CheckedProduct<$int> {
+            /// Creates a new `CheckedProduct` with the provided initial value.
+            pub const fn new(initial: $int) -> Self {
+                Self {
+                    item: Some(initial),
+                }
+            }
+            /// Deconstruct self and return the product, or `None` if it overflowed.
+            pub fn into_inner(self) -> Option<$int> {
+                self.item
+            }
+            /// Returns a reference to the product, or `None` if it overflowed.
+            pub fn as_ref(&self) -> Option<&$int> {
+                self.item.as_ref()
+            }
+            /// Returns `true` if a multiplication overflowed.
+            pub fn is_overflowed(&self) -> bool {
+                self.item.is_none()
+            }
+            /// Multiply the given item into the product.
+            pub fn fold(&mut self, item: $int) {
+                self.item = self.item.and_then(|current| current.checked_mul(item));
+            }
+            /// Multiply the item behind the ref into the product.
+            pub fn fold_ref(&mut self, item: &$int) {
+                self.fold(*item)
+            }
+            /// Multiply the product of `other` into self.
+            pub fn merge_from(&mut self, other: Self) {
+                match other.item {
+                    Some(item) => self.fold(item),
+                    None => self.item = None,
+                }
+            }
+            /// Multiply the product of `other` into self and return the result.
+            pub fn merge(mut self, other: Self) -> Self {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl Default for CheckedProduct<$int> {
+            fn default() -> Self {
+                Self::new(1)
+            }
+        }
+
+        impl From<$int> for CheckedProduct<$int> {
+            fn from(item: $int) -> Self {
+                Self::new(item)
+            }
+        }
+
+        impl Extend<$int> for CheckedProduct<$int> {
+            fn extend<It: IntoIterator<Item = $int>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold(i));
+            }
+        }
+
+        impl<'a> Extend<&'a $int> for CheckedProduct<$int> {
+            fn extend<It: IntoIterator<Item = &'a $int>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold_ref(i));
+            }
+        }
+
+        impl core::iter::FromIterator<$int> for CheckedProduct<$int> {
+            fn from_iter<It: IntoIterator<Item = $int>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a> core::iter::FromIterator<&'a $int> for CheckedProduct<$int> {
+            fn from_iter<It: IntoIterator<Item = &'a $int>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl core::iter::Product<$int> for CheckedProduct<$int> {
+            fn product<It: Iterator<Item = $int>>(iter: It) -> Self {
+                iter.collect()
+            }
+        }
+
+        impl<'a> core::iter::Product<&'a $int> for CheckedProduct<$int> {
+            fn product<It: Iterator<Item = &'a $int>>(iter: It) -> Self {
+                iter.collect()
+            }
+        }
+
+        impl FoldItem<$int> for CheckedProduct<$int> {
+            fn fold_item(&mut self, item: $int) {
+                self.fold(item)
+            }
+        }
+
+        impl Folding for CheckedProduct<$int> {
+            type Item = $int;
+            type Output = Option<$int>;
+            type Peek<'a>
+                = Option<&'a $int>
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Option<$int> {
+                self.into_inner()
+            }
+        }
+    )*};
+}
+
+impl_checkedproduct!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
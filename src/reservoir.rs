@@ -0,0 +1,251 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::hash::Hasher;
+
+/// Source of random numbers used by [`Reservoir`].
+///
+/// It's implemented for [`SplitMix64`] and, with the `rand` feature, for `RandRng`, which wraps
+/// any `rand::RngCore`.
+pub trait ReservoirRng {
+    /// Returns the next random `u64`.
+    fn next_u64(&mut self) -> u64;
+}
+
+/// The default random number generator of [`Reservoir`], a small non-cryptographic
+/// SplitMix64 generator.
+#[derive(Debug, Copy, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a new `SplitMix64` with the provided seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+    /// Creates a new `SplitMix64` with a random seed.
+    pub fn from_entropy() -> Self {
+        Self::new(RandomState::new().build_hasher().finish())
+    }
+}
+
+impl ReservoirRng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Wrapper that allows using a `rand::RngCore` as the random number generator of
+/// [`Reservoir`].
+#[cfg(feature = "rand")]
+#[derive(Debug, Copy, Clone)]
+pub struct RandRng<R>(pub R);
+
+#[cfg(feature = "rand")]
+impl<R> ReservoirRng for RandRng<R>
+where
+    R: rand::RngCore,
+{
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
+
+/// The `Reservoir` type keeps a uniform random sample of `k` of the items folded in, using
+/// reservoir sampling.
+///
+/// Every item folded in has the same probability of being in the sample, without knowing the
+/// length of the stream beforehand. The random numbers come from a [`SplitMix64`] by default;
+/// with the `rand` feature, any `rand::RngCore` can be used by wrapping it in a `RandRng` and
+/// passing it to [`Reservoir::with_rng`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that samples 3 items, with a fixed seed:
+/// let mut reservoir = Reservoir::<u32>::with_seed(3, 42);
+///
+/// // We can "fold-in" individual items:
+/// reservoir.fold(0);
+///
+/// // We can then peek at the running sample:
+/// println!("Partial sample is {:?}", reservoir.as_slice());
+///
+/// // And still keep on folding by processing whole iterators:
+/// reservoir.extend(1..1000);
+///
+/// // And finally consume the autofolder to get the sample:
+/// assert_eq!(reservoir.seen(), 1000);
+/// let sample = reservoir.into_inner();
+/// assert_eq!(sample.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Reservoir<Item, Rng = SplitMix64> {
+    k: usize,
+    seen: u64,
+    sample: Vec<Item>,
+    rng: Rng,
+}
+
+impl<Item> Reservoir<Item> {
+    /// Creates a new empty `Reservoir` that samples `k` items, with a random seed.
+    pub fn new(k: usize) -> Self {
+        Self::with_rng(k, SplitMix64::from_entropy())
+    }
+    /// Creates a new empty `Reservoir` that samples `k` items, with the provided seed.
+    pub fn with_seed(k: usize, seed: u64) -> Self {
+        Self::with_rng(k, SplitMix64::new(seed))
+    }
+}
+
+impl<Item, Rng> Reservoir<Item, Rng> {
+    /// Creates a new empty `Reservoir` that samples `k` items, with the provided random number
+    /// generator.
+    pub fn with_rng(k: usize, rng: Rng) -> Self
+    where
+        Rng: ReservoirRng,
+    {
+        Self {
+            k,
+            seen: 0,
+            sample: Vec::new(),
+            rng,
+        }
+    }
+    /// Returns the size of the sample.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+    /// Returns the number of items currently in the sample.
+    pub fn len(&self) -> usize {
+        self.sample.len()
+    }
+    /// Returns `true` if the sample is empty.
+    pub fn is_empty(&self) -> bool {
+        self.sample.is_empty()
+    }
+    /// Returns the number of items folded in.
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+    /// Returns the sample as a slice.
+    pub fn as_slice(&self) -> &[Item] {
+        &self.sample
+    }
+    /// Deconstruct self and return the sample.
+    pub fn into_inner(self) -> Vec<Item> {
+        self.sample
+    }
+    /// Returns the position of the sample to be replaced by the next item, if any.
+    fn slot(&mut self) -> Option<usize>
+    where
+        Rng: ReservoirRng,
+    {
+        let seen = self.seen;
+        self.seen += 1;
+        if self.sample.len() < self.k {
+            return Some(self.sample.len());
+        }
+        // Uniform in [0, seen], using the multiply-shift reduction:
+        let index = ((u128::from(self.rng.next_u64()) * u128::from(seen + 1)) >> 64) as u64;
+        if index < self.k as u64 {
+            Some(index as usize)
+        } else {
+            None
+        }
+    }
+    fn place(&mut self, slot: usize, item: Item) {
+        if slot == self.sample.len() {
+            self.sample.push(item);
+        } else {
+            self.sample[slot] = item;
+        }
+    }
+    /// Folds an individual item into the sample, with the appropriate probability.
+    pub fn fold(&mut self, item: Item)
+    where
+        Rng: ReservoirRng,
+    {
+        if let Some(slot) = self.slot() {
+            self.place(slot, item);
+        }
+    }
+    /// Folds in the item behind the reference, with the appropriate probability.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if the item is sampled.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Rng: ReservoirRng,
+        Item: Clone,
+    {
+        if let Some(slot) = self.slot() {
+            self.place(slot, item.clone());
+        }
+    }
+}
+
+impl<Item, Rng> AsRef<[Item]> for Reservoir<Item, Rng> {
+    fn as_ref(&self) -> &[Item] {
+        self.as_slice()
+    }
+}
+
+impl<Item, Rng> Extend<Item> for Reservoir<Item, Rng>
+where
+    Rng: ReservoirRng,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold(i));
+    }
+}
+
+impl<'a, Item, Rng> Extend<&'a Item> for Reservoir<Item, Rng>
+where
+    Rng: ReservoirRng,
+    Item: Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.fold_ref(i));
+    }
+}
+
+impl<Item, Rng> FoldItem<Item> for Reservoir<Item, Rng>
+where
+    Rng: ReservoirRng,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item)
+    }
+}
+
+impl<Item, Rng> Folding for Reservoir<Item, Rng>
+where
+    Rng: ReservoirRng,
+{
+    type Item = Item;
+    type Output = Vec<Item>;
+    type Peek<'a>
+        = &'a [Item]
+    where
+        Self: 'a;
+    fn peek(&self) -> &[Item] {
+        self.as_slice()
+    }
+    fn finish(self) -> Vec<Item> {
+        self.into_inner()
+    }
+}
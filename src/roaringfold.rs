@@ -0,0 +1,156 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use roaring::RoaringBitmap;
+
+/// The `RoaringFold` type uses the [`roaring`] crate to fold `u32` IDs, or whole bitmaps,
+/// into a [`RoaringBitmap`] via union.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that collects IDs:
+/// let mut ids = RoaringFold::default();
+///
+/// // We can "fold-in" individual IDs:
+/// ids.fold(3);
+///
+/// // Or whole bitmaps:
+/// ids.fold_bitmap((10..20).collect());
+///
+/// // We can then query the running set:
+/// println!("Partial cardinality is {}", ids.cardinality());
+///
+/// // And still keep on folding by processing whole iterators:
+/// ids.extend(1..=5);
+///
+/// // And finally consume the autofolder to get the final bitmap:
+/// let bitmap = ids.into_inner();
+/// assert!(bitmap.contains(15));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RoaringFold {
+    bitmap: RoaringBitmap,
+}
+
+impl RoaringFold {
+    /// Creates a new `RoaringFold` with the provided initial bitmap.
+    pub const fn new(initial: RoaringBitmap) -> Self {
+        Self { bitmap: initial }
+    }
+    /// Deconstruct self and return the inner bitmap.
+    pub fn into_inner(self) -> RoaringBitmap {
+        self.bitmap
+    }
+    /// Returns the number of distinct IDs folded in.
+    pub fn cardinality(&self) -> u64 {
+        self.bitmap.len()
+    }
+    /// Returns `true` if no ID was folded in.
+    pub fn is_empty(&self) -> bool {
+        self.bitmap.is_empty()
+    }
+    /// Returns `true` if the ID was folded in.
+    pub fn contains(&self, id: u32) -> bool {
+        self.bitmap.contains(id)
+    }
+    /// Folds an individual ID into self.
+    pub fn fold(&mut self, id: u32) {
+        self.bitmap.insert(id);
+    }
+    /// Folds all IDs of the given bitmap into self.
+    pub fn fold_bitmap(&mut self, bitmap: RoaringBitmap) {
+        self.bitmap |= bitmap;
+    }
+    /// Folds all IDs of `other` into self.
+    pub fn merge_from(&mut self, other: Self) {
+        self.fold_bitmap(other.bitmap);
+    }
+    /// Folds all IDs of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl From<RoaringBitmap> for RoaringFold {
+    fn from(bitmap: RoaringBitmap) -> Self {
+        Self::new(bitmap)
+    }
+}
+
+impl AsRef<RoaringBitmap> for RoaringFold {
+    fn as_ref(&self) -> &RoaringBitmap {
+        &self.bitmap
+    }
+}
+
+impl Extend<u32> for RoaringFold {
+    fn extend<It: IntoIterator<Item = u32>>(&mut self, iter: It) {
+        self.bitmap.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a u32> for RoaringFold {
+    fn extend<It: IntoIterator<Item = &'a u32>>(&mut self, iter: It) {
+        self.bitmap.extend(iter.into_iter().copied());
+    }
+}
+
+impl Extend<RoaringBitmap> for RoaringFold {
+    fn extend<It: IntoIterator<Item = RoaringBitmap>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|b| self.fold_bitmap(b));
+    }
+}
+
+impl std::iter::FromIterator<u32> for RoaringFold {
+    fn from_iter<It: IntoIterator<Item = u32>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a> std::iter::FromIterator<&'a u32> for RoaringFold {
+    fn from_iter<It: IntoIterator<Item = &'a u32>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl std::iter::FromIterator<RoaringBitmap> for RoaringFold {
+    fn from_iter<It: IntoIterator<Item = RoaringBitmap>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl FoldItem<u32> for RoaringFold {
+    fn fold_item(&mut self, item: u32) {
+        self.fold(item)
+    }
+}
+
+impl Folding for RoaringFold {
+    type Item = u32;
+    type Output = RoaringBitmap;
+    type Peek<'a>
+        = &'a RoaringBitmap
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> RoaringBitmap {
+        self.into_inner()
+    }
+}
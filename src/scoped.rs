@@ -0,0 +1,72 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+/// Folds the chunks of a slice in parallel, using scoped threads, and merges the partial
+/// autofolders.
+///
+/// The slice is split with [`slice::chunks`], each chunk is folded into a new autofolder
+/// created by `init`, and the partial autofolders are then combined with `merge`, which is
+/// usually the `merge` method of the autofolder. The merges are done in the order of the chunks,
+/// so that the result is the same as folding the whole slice sequentially.
+///
+/// One thread is used for each chunk - the first chunk is folded by the calling thread - so
+/// `chunk_size` should be chosen according to the number of threads available, for instance
+/// with [`std::thread::available_parallelism`].
+///
+/// If the slice is empty, the autofolder created by `init` is returned as is.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is 0, or propagates the panic if folding a chunk panics.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let items = (1..=1000).collect::<Vec<u32>>();
+///
+/// let minmax = fold_chunks_scoped(&items, 300, MinMax::default, MinMax::merge);
+/// assert_eq!(minmax.to_inner(), Some((1, 1000)));
+///
+/// let sum = fold_chunks_scoped(&items, 300, || Adder::new(0), Adder::merge);
+/// assert_eq!(sum.into_inner(), 500500);
+/// ```
+pub fn fold_chunks_scoped<'a, Item, F, Init, Merge>(
+    items: &'a [Item],
+    chunk_size: usize,
+    init: Init,
+    mut merge: Merge,
+) -> F
+where
+    Item: Sync,
+    F: Extend<&'a Item> + Send,
+    Init: Fn() -> F + Sync,
+    Merge: FnMut(F, F) -> F,
+{
+    let mut chunks = items.chunks(chunk_size);
+    let first = match chunks.next() {
+        Some(first) => first,
+        None => return init(),
+    };
+    let fold_chunk = |chunk: &'a [Item]| {
+        let mut folder = init();
+        folder.extend(chunk);
+        folder
+    };
+    std::thread::scope(|scope| {
+        let handles = chunks
+            .map(|chunk| scope.spawn(move || fold_chunk(chunk)))
+            .collect::<Vec<_>>();
+        let folder = fold_chunk(first);
+        handles.into_iter().fold(folder, |folder, handle| {
+            let other = handle
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            merge(folder, other)
+        })
+    })
+}
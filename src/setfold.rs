@@ -0,0 +1,313 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+use std::mem;
+
+/// Trait that provides the set operations used by [`UnionFold`] and [`IntersectionFold`].
+///
+/// It's implemented for [`HashSet`] and [`BTreeSet`].
+pub trait FoldableSet {
+    /// Returns the number of elements in the set.
+    fn set_len(&self) -> usize;
+    /// Adds all elements of `other` to self.
+    fn union_with(&mut self, other: Self);
+    /// Removes all elements of self that are not in `other`.
+    fn intersect_with(&mut self, other: &Self);
+}
+
+impl<T, S> FoldableSet for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher,
+{
+    fn set_len(&self) -> usize {
+        self.len()
+    }
+    fn union_with(&mut self, other: Self) {
+        self.extend(other);
+    }
+    fn intersect_with(&mut self, other: &Self) {
+        self.retain(|i| other.contains(i));
+    }
+}
+
+impl<T> FoldableSet for BTreeSet<T>
+where
+    T: Ord,
+{
+    fn set_len(&self) -> usize {
+        self.len()
+    }
+    fn union_with(&mut self, mut other: Self) {
+        self.append(&mut other);
+    }
+    fn intersect_with(&mut self, other: &Self) {
+        self.retain(|i| other.contains(i));
+    }
+}
+
+/// The `UnionFold` type reduces sets into their union.
+///
+/// As the empty set is the identity of the union, this reducer starts with one and
+/// [`UnionFold::into_inner`] returns a set directly, instead of an [`Option`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+/// use std::collections::HashSet;
+///
+/// // Create an autofolder that unites sets of tags:
+/// let mut tags = UnionFold::<HashSet<&str>>::default();
+///
+/// // We can "reduce-in" individual sets:
+/// tags.reduce(vec!["red", "blue"].into_iter().collect());
+///
+/// // And still keep on reducing by processing whole iterators:
+/// tags.extend(vec![vec!["blue", "green"].into_iter().collect()]);
+///
+/// // And finally consume the autofolder to get the final union:
+/// println!("All tags: {:?}", tags.into_inner());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UnionFold<Set> {
+    set: Set,
+}
+
+impl<Set> UnionFold<Set> {
+    /// Creates a new `UnionFold` with the provided initial set.
+    pub const fn new(initial: Set) -> Self {
+        Self { set: initial }
+    }
+    /// Deconstruct self and return the union.
+    pub fn into_inner(self) -> Set {
+        self.set
+    }
+    /// Reduce the given set into the current union.
+    pub fn reduce(&mut self, mut set: Set)
+    where
+        Set: FoldableSet,
+    {
+        if set.set_len() > self.set.set_len() {
+            mem::swap(&mut self.set, &mut set);
+        }
+        self.set.union_with(set);
+    }
+    /// Reduce the union of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Set: FoldableSet,
+    {
+        self.reduce(other.set);
+    }
+    /// Reduce the union of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Set: FoldableSet,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Set> From<Set> for UnionFold<Set> {
+    fn from(set: Set) -> Self {
+        Self::new(set)
+    }
+}
+
+impl<Set> AsRef<Set> for UnionFold<Set> {
+    fn as_ref(&self) -> &Set {
+        &self.set
+    }
+}
+
+impl<Set> Extend<Set> for UnionFold<Set>
+where
+    Set: FoldableSet,
+{
+    fn extend<It: IntoIterator<Item = Set>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|s| self.reduce(s));
+    }
+}
+
+impl<Set> std::iter::FromIterator<Set> for UnionFold<Set>
+where
+    Set: FoldableSet + Default,
+{
+    fn from_iter<It: IntoIterator<Item = Set>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+/// The `IntersectionFold` type reduces sets into their intersection.
+///
+/// The first set reduced in is incorporated as-is, which means that an `IntersectionFold`
+/// that hasn't seen any set yet is not the same as one that holds the empty set:
+/// [`IntersectionFold::into_inner`] returns `None` in the former case.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+/// use std::collections::BTreeSet;
+///
+/// // Create an autofolder that intersects sets of permissions:
+/// let mut perms = IntersectionFold::<BTreeSet<&str>>::default();
+///
+/// // We can "reduce-in" individual sets.
+/// // (note: as this is the first set, we incorporate it as-is)
+/// perms.reduce(vec!["read", "write", "exec"].into_iter().collect());
+///
+/// // And still keep on reducing by processing whole iterators:
+/// perms.extend(vec![vec!["read", "write"].into_iter().collect()]);
+///
+/// // And finally consume the autofolder to get the final intersection:
+/// println!("Common permissions: {:?}", perms.into_inner().unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntersectionFold<Set> {
+    set: Option<Set>,
+}
+
+impl<Set> IntersectionFold<Set> {
+    /// Creates a new `IntersectionFold` with the provided initial set.
+    pub const fn new(initial: Set) -> Self {
+        Self { set: Some(initial) }
+    }
+    /// Deconstruct self and return the intersection, if any set was reduced in.
+    pub fn into_inner(self) -> Option<Set> {
+        self.set
+    }
+    /// Returns a reference to the intersection, if any set was reduced in.
+    pub fn as_ref(&self) -> Option<&Set> {
+        self.set.as_ref()
+    }
+    /// Reduce the given set into the current intersection.
+    pub fn reduce(&mut self, mut set: Set)
+    where
+        Set: FoldableSet,
+    {
+        if let Some(current) = self.set.as_mut() {
+            if set.set_len() < current.set_len() {
+                mem::swap(current, &mut set);
+            }
+            current.intersect_with(&set);
+        } else {
+            self.set = Some(set);
+        }
+    }
+    /// Reduce the intersection of `other` into self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Set: FoldableSet,
+    {
+        if let Some(set) = other.set {
+            self.reduce(set);
+        }
+    }
+    /// Reduce the intersection of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Set: FoldableSet,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Set> Default for IntersectionFold<Set> {
+    fn default() -> Self {
+        Self { set: None }
+    }
+}
+
+impl<Set> From<Set> for IntersectionFold<Set> {
+    fn from(set: Set) -> Self {
+        Self::new(set)
+    }
+}
+
+impl<Set> Extend<Set> for IntersectionFold<Set>
+where
+    Set: FoldableSet,
+{
+    fn extend<It: IntoIterator<Item = Set>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|s| self.reduce(s));
+    }
+}
+
+impl<Set> std::iter::FromIterator<Set> for IntersectionFold<Set>
+where
+    Set: FoldableSet,
+{
+    fn from_iter<It: IntoIterator<Item = Set>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Set> FoldItem<Set> for UnionFold<Set>
+where
+    Set: FoldableSet,
+{
+    fn fold_item(&mut self, item: Set) {
+        self.reduce(item)
+    }
+}
+
+impl<Set> Folding for UnionFold<Set>
+where
+    Set: FoldableSet,
+{
+    type Item = Set;
+    type Output = Set;
+    type Peek<'a>
+        = &'a Set
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Set {
+        self.into_inner()
+    }
+}
+
+impl<Set> FoldItem<Set> for IntersectionFold<Set>
+where
+    Set: FoldableSet,
+{
+    fn fold_item(&mut self, item: Set) {
+        self.reduce(item)
+    }
+}
+
+impl<Set> Folding for IntersectionFold<Set>
+where
+    Set: FoldableSet,
+{
+    type Item = Set;
+    type Output = Option<Set>;
+    type Peek<'a>
+        = Option<&'a Set>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<Set> {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! Implementations of [`futures_sink::Sink`] for the autofolders, which allows forwarding a
+//! stream directly into one of them.
+//!
+//! Example:
+//! ```
+//! use autofolder::*;
+//! use futures::executor::block_on;
+//! use futures::stream::{self, StreamExt};
+//!
+//! let mut max = Max::default();
+//! block_on(stream::iter(vec![3, 8, 2]).map(Ok).forward(&mut max)).unwrap();
+//! assert_eq!(max.into_inner(), Some(8));
+//! ```
+//!
+//! Folding never blocks nor fails, so the sinks are always ready and their error type is
+//! [`Infallible`].
+
+use crate::*;
+use core::convert::Infallible;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+use futures_sink::Sink;
+
+macro_rules! impl_sink {
+    ([$($generics: tt)*], $name: ty, $item: ty) => {
+        impl<$($generics)*> Sink<$item> for $name
+        where
+            Self: FoldItem<$item> + Unpin,
+        {
+            type Error = Infallible;
+            fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+                Poll::Ready(Ok(()))
+            }
+            fn start_send(self: Pin<&mut Self>, item: $item) -> Result<(), Infallible> {
+                self.get_mut().fold_item(item);
+                Ok(())
+            }
+            fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+                Poll::Ready(Ok(()))
+            }
+            fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+                Poll::Ready(Ok(()))
+            }
+        }
+    };
+}
+
+impl_sink!(
+    [Output, Item, Func, Observer],
+    DynFolder<Output, Item, Func, Observer>,
+    Item
+);
+impl_sink!([Output, Item], ImplFolder<Output, Item>, Item);
+impl_sink!([Item, Func, Observer], DynReduce<Item, Func, Observer>, Item);
+impl_sink!([Item], ImplReduce<Item>, Item);
+impl_sink!([Item], Min<Item>, Item);
+impl_sink!([Item], Max<Item>, Item);
+impl_sink!([Item], MinMax<Item>, Item);
+
+#[cfg(feature = "num")]
+impl_sink!([Item], MinNum<Item>, Item);
+#[cfg(feature = "num")]
+impl_sink!([Item], MaxNum<Item>, Item);
+#[cfg(feature = "num")]
+impl_sink!([Item], MinMaxNum<Item>, Item);
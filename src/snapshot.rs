@@ -0,0 +1,142 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Bytes that start every snapshot.
+const MAGIC: &[u8; 2] = b"AF";
+
+/// Version of the snapshot format written by [`Snapshot::to_bytes`].
+pub const SNAPSHOT_VERSION: u8 = 3;
+
+/// Error returned when writing or reading a [`Snapshot`].
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The bytes don't start with a snapshot header.
+    BadMagic,
+    /// The snapshot was written with an unsupported format version.
+    UnsupportedVersion(u8),
+    /// The snapshot was written by a different autofolder type.
+    TagMismatch {
+        /// Tag of the type being read.
+        expected: &'static str,
+        /// Tag found in the snapshot.
+        found: String,
+    },
+    /// The state could not be encoded or decoded.
+    Encoding(postcard::Error),
+}
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BadMagic => write!(f, "not an autofolder snapshot"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported snapshot version {}", v),
+            Self::TagMismatch { expected, found } => {
+                write!(f, "snapshot of {} found while reading {}", found, expected)
+            }
+            Self::Encoding(e) => write!(f, "snapshot encoding error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Encoding(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<postcard::Error> for SnapshotError {
+    fn from(e: postcard::Error) -> Self {
+        Self::Encoding(e)
+    }
+}
+
+/// Trait that provides a compact binary snapshot format for the state of autofolders.
+///
+/// Snapshots start with a small header that has a magic number, the format version
+/// ([`SNAPSHOT_VERSION`]) and the tag of the autofolder type, followed by the state encoded
+/// with [`postcard`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let max = (1..=5).collect::<Max<u32>>();
+///
+/// // Store the state somewhere:
+/// let bytes = max.to_bytes()?;
+///
+/// // Restore it later and keep on folding:
+/// let mut max = Max::<u32>::from_bytes(&bytes)?;
+/// max.extend(6..=9);
+/// assert_eq!(max.into_inner(), Some(9));
+///
+/// // Snapshots carry the type tag:
+/// assert!(Min::<u32>::from_bytes(&bytes).is_err());
+/// # Ok::<(), SnapshotError>(())
+/// ```
+pub trait Snapshot: Serialize + DeserializeOwned {
+    /// Tag that identifies the autofolder type in the snapshot header.
+    const TAG: &'static str;
+    /// Writes the state of self as a snapshot.
+    fn to_bytes(&self) -> Result<Vec<u8>, SnapshotError> {
+        let tag = Self::TAG.as_bytes();
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 2 + tag.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(SNAPSHOT_VERSION);
+        bytes.push(tag.len() as u8);
+        bytes.extend_from_slice(tag);
+        Ok(postcard::to_extend(self, bytes)?)
+    }
+    /// Reads a snapshot written by [`Snapshot::to_bytes`].
+    fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let bytes = bytes.strip_prefix(MAGIC).ok_or(SnapshotError::BadMagic)?;
+        let (&version, bytes) = bytes.split_first().ok_or(SnapshotError::BadMagic)?;
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        let (&len, bytes) = bytes.split_first().ok_or(SnapshotError::BadMagic)?;
+        let len = usize::from(len);
+        if bytes.len() < len {
+            return Err(SnapshotError::BadMagic);
+        }
+        let (tag, payload) = bytes.split_at(len);
+        if tag != Self::TAG.as_bytes() {
+            return Err(SnapshotError::TagMismatch {
+                expected: Self::TAG,
+                found: String::from_utf8_lossy(tag).into_owned(),
+            });
+        }
+        Ok(postcard::from_bytes(payload)?)
+    }
+}
+
+macro_rules! impl_snapshot {
+    ($name: ident) => {
+        impl<Item> Snapshot for crate::$name<Item>
+        where
+            Item: Serialize + DeserializeOwned,
+        {
+            const TAG: &'static str = stringify!($name);
+        }
+    };
+}
+
+impl_snapshot!(Min);
+impl_snapshot!(Max);
+impl_snapshot!(MinMax);
+#[cfg(feature = "num")]
+impl_snapshot!(MinNum);
+#[cfg(feature = "num")]
+impl_snapshot!(MaxNum);
+#[cfg(feature = "num")]
+impl_snapshot!(MinMaxNum);
@@ -0,0 +1,193 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::vec;
+
+/// The `SortedMerge` type reduces already-sorted runs of items into a single sorted run.
+///
+/// The runs are kept as they are reduced in, and merged lazily with a k-way heap merge when
+/// the autofolder is consumed. Equal items are yielded in the order of the runs that contain
+/// them, which makes the merge stable.
+///
+/// Each run must be sorted in ascending order, as given by [`std::cmp::Ord`]; the order of the
+/// output is unspecified otherwise.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that merges sorted runs of usize:
+/// let mut merge = SortedMerge::<usize>::default();
+///
+/// // We can "reduce-in" individual runs:
+/// merge.reduce(vec![1, 4, 7]);
+///
+/// // And still keep on reducing by processing whole iterators of runs:
+/// merge.extend(vec![vec![2, 5], vec![3, 6, 9]]);
+///
+/// // And finally consume the autofolder to get the merged run:
+/// println!("Merged run is {:?}", merge.into_inner());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SortedMerge<Item> {
+    runs: Vec<Vec<Item>>,
+}
+
+impl<Item> SortedMerge<Item> {
+    /// Creates a new empty `SortedMerge`.
+    pub const fn new() -> Self {
+        Self { runs: Vec::new() }
+    }
+    /// Deconstruct self and return the merged run.
+    pub fn into_inner(self) -> Vec<Item>
+    where
+        Item: Ord,
+    {
+        let mut merged = Vec::with_capacity(self.len());
+        merged.extend(self);
+        merged
+    }
+    /// Returns the runs reduced in, ignoring the empty ones.
+    pub fn runs(&self) -> &[Vec<Item>] {
+        &self.runs
+    }
+    /// Returns the number of runs reduced in, ignoring the empty ones.
+    pub fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+    /// Returns the total number of items in the runs.
+    pub fn len(&self) -> usize {
+        self.runs.iter().map(Vec::len).sum()
+    }
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+    /// Reduce the given sorted run into self.
+    pub fn reduce(&mut self, run: Vec<Item>) {
+        if !run.is_empty() {
+            self.runs.push(run);
+        }
+    }
+    /// Reduce all runs of `other` into self.
+    pub fn merge_from(&mut self, other: Self) {
+        self.runs.extend(other.runs);
+    }
+    /// Reduce all runs of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for SortedMerge<Item> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> From<Vec<Item>> for SortedMerge<Item> {
+    fn from(run: Vec<Item>) -> Self {
+        let mut autofolder = Self::new();
+        autofolder.reduce(run);
+        autofolder
+    }
+}
+
+impl<Item> Extend<Vec<Item>> for SortedMerge<Item> {
+    fn extend<It: IntoIterator<Item = Vec<Item>>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|r| self.reduce(r));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Vec<Item>> for SortedMerge<Item> {
+    fn from_iter<It: IntoIterator<Item = Vec<Item>>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> IntoIterator for SortedMerge<Item>
+where
+    Item: Ord,
+{
+    type Item = Item;
+    type IntoIter = SortedMergeIter<Item>;
+    fn into_iter(self) -> Self::IntoIter {
+        let mut runs = self
+            .runs
+            .into_iter()
+            .map(Vec::into_iter)
+            .collect::<Vec<_>>();
+        let mut heap = BinaryHeap::with_capacity(runs.len());
+        for (index, run) in runs.iter_mut().enumerate() {
+            if let Some(item) = run.next() {
+                heap.push(Reverse((item, index)));
+            }
+        }
+        SortedMergeIter { runs, heap }
+    }
+}
+
+/// Iterator that lazily merges the runs of a [`SortedMerge`].
+#[derive(Debug)]
+pub struct SortedMergeIter<Item> {
+    runs: Vec<vec::IntoIter<Item>>,
+    heap: BinaryHeap<Reverse<(Item, usize)>>,
+}
+
+impl<Item> Iterator for SortedMergeIter<Item>
+where
+    Item: Ord,
+{
+    type Item = Item;
+    fn next(&mut self) -> Option<Item> {
+        let Reverse((item, index)) = self.heap.pop()?;
+        if let Some(next) = self.runs[index].next() {
+            self.heap.push(Reverse((next, index)));
+        }
+        Some(item)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.heap.len() + self.runs.iter().map(|r| r.len()).sum::<usize>();
+        (len, Some(len))
+    }
+}
+
+impl<Item> ExactSizeIterator for SortedMergeIter<Item> where Item: Ord {}
+
+impl<Item> FoldItem<Vec<Item>> for SortedMerge<Item>
+where
+    Item: Ord,
+{
+    fn fold_item(&mut self, item: Vec<Item>) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for SortedMerge<Item>
+where
+    Item: Ord,
+{
+    type Item = Vec<Item>;
+    type Output = Vec<Item>;
+    type Peek<'a>
+        = &'a [Vec<Item>]
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.runs()
+    }
+    fn finish(self) -> Vec<Item> {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use core::marker;
+
+/// The `Stats` type keeps running statistics of the iterated values, converted to `f64`,
+/// using Welford's online algorithm.
+///
+/// The count, mean and variance are updated incrementally, without storing the items, in a
+/// numerically stable way. Partial statistics can be combined with [`Stats::merge`].
+///
+/// The standard deviations require the `std` feature, for the square root.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the statistics of u32 items.
+/// let mut stats = Stats::<u32>::default();
+///
+/// // We can "reduce-in" individual items:
+/// stats.reduce(2);
+///
+/// // We can then peek at the running statistics:
+/// println!("Partial mean is {:?}", stats.mean());
+///
+/// // And still keep on folding by processing whole iterators:
+/// stats.extend(vec![4, 4, 4, 5, 5, 7, 9]);
+///
+/// // And finally query the statistics:
+/// assert_eq!(stats.count(), 8);
+/// assert_eq!(stats.mean(), Some(5.0));
+/// assert_eq!(stats.variance(), Some(4.0));
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stats<Item> {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    item: marker::PhantomData<Item>,
+}
+
+impl<Item> Stats<Item> {
+    /// Creates a new empty `Stats`.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            item: marker::PhantomData,
+        }
+    }
+    /// Returns the number of items reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns the mean, if any item was reduced in.
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.mean)
+        }
+    }
+    /// Returns the population variance, if any item was reduced in.
+    pub fn variance(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.m2 / self.count as f64)
+        }
+    }
+    /// Returns the sample variance, if at least two items were reduced in.
+    pub fn sample_variance(&self) -> Option<f64> {
+        if self.count < 2 {
+            None
+        } else {
+            Some(self.m2 / (self.count - 1) as f64)
+        }
+    }
+    /// Returns the population standard deviation, if any item was reduced in.
+    #[cfg(feature = "std")]
+    pub fn stddev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+    /// Returns the sample standard deviation, if at least two items were reduced in.
+    #[cfg(feature = "std")]
+    pub fn sample_stddev(&self) -> Option<f64> {
+        self.sample_variance().map(f64::sqrt)
+    }
+    /// Updates the statistics with the given item.
+    pub fn reduce(&mut self, item: Item)
+    where
+        Item: Into<f64>,
+    {
+        let value = item.into();
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+    /// Updates the statistics with the item behind the ref.
+    ///
+    /// This function requires the `Clone` trait.
+    pub fn reduce_ref(&mut self, item: &Item)
+    where
+        Item: Into<f64> + Clone,
+    {
+        self.reduce(item.clone())
+    }
+    /// Combine the statistics of `other` into self.
+    pub fn merge_from(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+    }
+    /// Combine the statistics of `other` into self and return the result.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Default for Stats<Item> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item> Extend<Item> for Stats<Item>
+where
+    Item: Into<f64>,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for Stats<Item>
+where
+    Item: Into<f64> + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<Item> core::iter::FromIterator<Item> for Stats<Item>
+where
+    Item: Into<f64>,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> core::iter::FromIterator<&'a Item> for Stats<Item>
+where
+    Item: Into<f64> + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for Stats<Item>
+where
+    Item: Into<f64>,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for Stats<Item>
+where
+    Item: Into<f64>,
+{
+    type Item = Item;
+    type Output = Self;
+    type Peek<'a>
+        = &'a Self
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self
+    }
+    fn finish(self) -> Self {
+        self
+    }
+}
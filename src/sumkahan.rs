@@ -0,0 +1,158 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// The `SumKahan` type sums `f32` or `f64` items using compensated summation.
+///
+/// Naive summation loses precision on long streams, as the low-order bits of each item are
+/// discarded when it's added to a much larger sum. `SumKahan` keeps those bits in a separate
+/// compensation term, using Neumaier's variant of the Kahan summation algorithm, which also
+/// handles items larger than the running sum.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that sums f64 items.
+/// let mut sum = SumKahan::<f64>::default();
+///
+/// // We can "fold-in" individual items:
+/// sum.fold(1.0);
+///
+/// // We can then peek at the running output:
+/// println!("Partial sum is {}", sum.sum());
+///
+/// // And still keep on folding by processing whole iterators:
+/// sum.extend(vec![1e100, 1.0, -1e100]);
+///
+/// // And finally consume the autofolder to get the final output value:
+/// assert_eq!(sum.into_inner(), 2.0);
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SumKahan<Float> {
+    sum: Float,
+    compensation: Float,
+}
+
+macro_rules! impl_sumkahan {
+    ($float: ty) => {
+        impl SumKahan<$float> {
+            /// Creates a new `SumKahan` with the provided initial value.
+            pub const fn new(initial: $float) -> Self {
+                Self {
+                    sum: initial,
+                    compensation: 0.0,
+                }
+            }
+            /// Returns the compensated sum.
+            pub fn sum(&self) -> $float {
+                self.sum + self.compensation
+            }
+            /// Deconstruct self and return the compensated sum.
+            pub fn into_inner(self) -> $float {
+                self.sum()
+            }
+            /// Adds an individual item to the sum.
+            pub fn fold(&mut self, item: $float) {
+                let sum = self.sum + item;
+                if abs(self.sum) >= abs(item) {
+                    self.compensation += (self.sum - sum) + item;
+                } else {
+                    self.compensation += (item - sum) + self.sum;
+                }
+                self.sum = sum;
+            }
+            /// Adds the item behind the ref to the sum.
+            pub fn fold_ref(&mut self, item: &$float) {
+                self.fold(*item)
+            }
+            /// Adds the sum of `other` to self.
+            pub fn merge_from(&mut self, other: Self) {
+                self.fold(other.sum);
+                self.fold(other.compensation);
+            }
+            /// Adds the sum of `other` to self and return the result.
+            pub fn merge(mut self, other: Self) -> Self {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl From<$float> for SumKahan<$float> {
+            fn from(initial: $float) -> Self {
+                Self::new(initial)
+            }
+        }
+
+        impl Extend<$float> for SumKahan<$float> {
+            fn extend<It: IntoIterator<Item = $float>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold(i));
+            }
+        }
+
+        impl<'a> Extend<&'a $float> for SumKahan<$float> {
+            fn extend<It: IntoIterator<Item = &'a $float>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold_ref(i));
+            }
+        }
+
+        impl core::iter::FromIterator<$float> for SumKahan<$float> {
+            fn from_iter<It: IntoIterator<Item = $float>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a> core::iter::FromIterator<&'a $float> for SumKahan<$float> {
+            fn from_iter<It: IntoIterator<Item = &'a $float>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl FoldItem<$float> for SumKahan<$float> {
+            fn fold_item(&mut self, item: $float) {
+                self.fold(item)
+            }
+        }
+
+        impl Folding for SumKahan<$float> {
+            type Item = $float;
+            type Output = $float;
+            type Peek<'a>
+                = $float
+            where
+                Self: 'a;
+            fn peek(&self) -> $float {
+                self.sum()
+            }
+            fn finish(self) -> $float {
+                self.into_inner()
+            }
+        }
+    };
+}
+
+impl_sumkahan!(f32);
+impl_sumkahan!(f64);
+
+// `abs` for floats is not available in `core` in all supported rust versions:
+fn abs<Float>(value: Float) -> Float
+where
+    Float: PartialOrd + Default + core::ops::Neg<Output = Float>,
+{
+    if value < Float::default() {
+        -value
+    } else {
+        value
+    }
+}
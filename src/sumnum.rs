@@ -0,0 +1,210 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+
+/// The `SumNum` type uses the [`num`] crate functionality to sum the iterated values, starting
+/// from [`num::Zero::zero`].
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that sums u64 items.
+/// let mut sum = SumNum::<u64>::default();
+///
+/// // We can "fold-in" individual items:
+/// sum.fold(3);
+///
+/// // We can then peek at the running output:
+/// println!("Partial sum is {:?}", sum.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// sum.extend((1..=5));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// assert_eq!(sum.into_inner(), 18);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SumNum<Item> {
+    item: Item,
+    count: usize,
+}
+
+/// The `ProductNum` type uses the [`num`] crate functionality to multiply the iterated values,
+/// starting from [`num::One::one`].
+///
+/// Unlike [`Product`](crate::Product), the output of an empty `ProductNum` is one instead of
+/// `None`.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// let product = (1..=5).collect::<ProductNum<u32>>();
+/// assert_eq!(product.into_inner(), 120);
+///
+/// let empty = ProductNum::<f64>::default();
+/// assert_eq!(empty.into_inner(), 1.0);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProductNum<Item> {
+    item: Item,
+    count: usize,
+}
+
+macro_rules! impl_sumnum {
+    ($name: ident, $trait: path, $seed: ident, $op: tt, $verb: literal) => {
+        impl<Item> $name<Item> {
+            #[doc = concat!("Creates a new `", stringify!($name), "` with the `", stringify!($seed), "` value.")]
+            pub fn new() -> Self
+            where
+                Item: $trait,
+            {
+                Self {
+                    item: Item::$seed(),
+                    count: 0,
+                }
+            }
+            /// Returns the number of items folded in.
+            pub fn count(&self) -> usize {
+                self.count
+            }
+            /// Returns `true` if no item was folded in.
+            pub fn is_empty(&self) -> bool {
+                self.count == 0
+            }
+            /// Deconstruct self and return the inner value.
+            pub fn into_inner(self) -> Item {
+                self.item
+            }
+            #[doc = concat!($verb, " the given item into the current value.")]
+            pub fn fold(&mut self, item: Item)
+            where
+                Item: $trait,
+            {
+                let current = core::mem::replace(&mut self.item, Item::$seed());
+                self.item = current $op item;
+                self.count += 1;
+            }
+            #[doc = concat!($verb, " the item behind the ref into the current value.")]
+            ///
+            /// This function requires the `Clone` trait.
+            pub fn fold_ref(&mut self, item: &Item)
+            where
+                Item: $trait + Clone,
+            {
+                self.fold(item.clone())
+            }
+            #[doc = concat!($verb, " the value of `other` into self.")]
+            pub fn merge_from(&mut self, other: Self)
+            where
+                Item: $trait,
+            {
+                let count = self.count + other.count;
+                self.fold(other.item);
+                self.count = count;
+            }
+            #[doc = concat!($verb, " the value of `other` into self and return the result.")]
+            pub fn merge(mut self, other: Self) -> Self
+            where
+                Item: $trait,
+            {
+                self.merge_from(other);
+                self
+            }
+        }
+
+        impl<Item> AsRef<Item> for $name<Item> {
+            fn as_ref(&self) -> &Item {
+                &self.item
+            }
+        }
+
+        impl<Item> Default for $name<Item>
+        where
+            Item: $trait,
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<Item> Extend<Item> for $name<Item>
+        where
+            Item: $trait,
+        {
+            fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold(i));
+            }
+        }
+
+        impl<'a, Item> Extend<&'a Item> for $name<Item>
+        where
+            Item: $trait + Clone,
+        {
+            fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold_ref(i));
+            }
+        }
+
+        impl<Item> core::iter::FromIterator<Item> for $name<Item>
+        where
+            Item: $trait,
+        {
+            fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<'a, Item> core::iter::FromIterator<&'a Item> for $name<Item>
+        where
+            Item: $trait + Clone,
+        {
+            fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+                let mut autofolder = Self::default();
+                autofolder.extend(iter);
+                autofolder
+            }
+        }
+
+        impl<Item> FoldItem<Item> for $name<Item>
+        where
+            Item: $trait,
+        {
+            fn fold_item(&mut self, item: Item) {
+                self.fold(item)
+            }
+        }
+
+        impl<Item> Folding for $name<Item>
+        where
+            Item: $trait,
+        {
+            type Item = Item;
+            type Output = Item;
+            type Peek<'a>
+                = &'a Item
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                self.as_ref()
+            }
+            fn finish(self) -> Item {
+                self.into_inner()
+            }
+        }
+    };
+}
+
+impl_sumnum!(SumNum, num::Zero, zero, +, "Adds");
+impl_sumnum!(ProductNum, num::One, one, *, "Multiplies");
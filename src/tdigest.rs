@@ -0,0 +1,405 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::borrow::Cow;
+use std::f64::consts::PI;
+use std::marker;
+
+/// A cluster of values, summarized by their mean and their number.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// The `TDigest` type estimates quantiles of the iterated values, converted to `f64`, using a
+/// merging t-digest.
+///
+/// The values are summarized in clusters, or centroids, that are smaller near the extremes, so
+/// that the estimates of the tail quantiles are accurate. The number of centroids, and with it
+/// the memory usage and the accuracy, is bounded by the `compression` parameter. Unlike other
+/// quantile sketches, digests can be combined with [`TDigest::merge`], which allows building
+/// them from shards of the data.
+///
+/// `NaN`s and infinities are ignored.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that estimates the quantiles of u32 items.
+/// let mut digest = TDigest::<u32>::new(100.0);
+///
+/// // We can "reduce-in" individual items:
+/// digest.reduce(1);
+///
+/// // We can then peek at the running estimates:
+/// println!("Partial median is {:?}", digest.quantile(0.5));
+///
+/// // And still keep on folding by processing whole iterators:
+/// digest.extend(2..=10_000);
+///
+/// // And finally query the estimates:
+/// let p99 = digest.quantile(0.99).unwrap();
+/// assert!((p99 - 9900.0).abs() < 10.0);
+/// let cdf = digest.cdf(2500.0).unwrap();
+/// assert!((cdf - 0.25).abs() < 0.001);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TDigestState"))]
+pub struct TDigest<Item> {
+    compression: f64,
+    // Sorted by mean, and compressed:
+    centroids: Vec<Centroid>,
+    // Not sorted, merged into `centroids` by `compress` when full:
+    buffer: Vec<Centroid>,
+    count: usize,
+    min: f64,
+    max: f64,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    item: marker::PhantomData<Item>,
+}
+
+/// The deserialized state of a [`TDigest`], which is validated before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct TDigestState {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    buffer: Vec<Centroid>,
+    count: usize,
+    min: f64,
+    max: f64,
+}
+
+#[cfg(feature = "serde")]
+impl<Item> std::convert::TryFrom<TDigestState> for TDigest<Item> {
+    type Error = String;
+    fn try_from(state: TDigestState) -> Result<Self, String> {
+        if !Self::supports(state.compression) {
+            return Err(format!("unsupported compression {}", state.compression));
+        }
+        let mut centroids = state.centroids.iter().chain(&state.buffer);
+        if !centroids.all(|c| c.mean.is_finite() && c.weight > 0.0) {
+            return Err("invalid centroid".to_string());
+        }
+        let centroids = state.centroids.iter().chain(&state.buffer);
+        if centroids.map(|c| c.weight).sum::<f64>() != state.count as f64 {
+            return Err("count doesn't match the centroids".to_string());
+        }
+        if state.count > 0
+            && !(state.min.is_finite() && state.max.is_finite() && state.min <= state.max)
+        {
+            return Err("invalid min and max".to_string());
+        }
+        Ok(Self {
+            compression: state.compression,
+            centroids: state.centroids,
+            buffer: state.buffer,
+            count: state.count,
+            min: state.min,
+            max: state.max,
+            item: marker::PhantomData,
+        })
+    }
+}
+
+impl<Item> TDigest<Item> {
+    /// The compression used by [`Default`].
+    pub const DEFAULT_COMPRESSION: f64 = 100.0;
+    /// The largest supported compression.
+    pub const MAX_COMPRESSION: f64 = 1e6;
+
+    /// Creates a new empty `TDigest` with the provided compression, which is roughly the
+    /// maximum number of centroids.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the compression is not between 1 and [`TDigest::MAX_COMPRESSION`].
+    pub fn new(compression: f64) -> Self {
+        assert!(
+            Self::supports(compression),
+            "unsupported compression {}",
+            compression
+        );
+        Self {
+            compression,
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            item: marker::PhantomData,
+        }
+    }
+    fn supports(compression: f64) -> bool {
+        (1.0..=Self::MAX_COMPRESSION).contains(&compression)
+    }
+    /// Returns the compression.
+    pub fn compression(&self) -> f64 {
+        self.compression
+    }
+    /// Returns the number of values reduced in.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Returns `true` if no value was reduced in.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// Returns the smallest value reduced in, if any.
+    pub fn min(&self) -> Option<f64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.min)
+        }
+    }
+    /// Returns the largest value reduced in, if any.
+    pub fn max(&self) -> Option<f64> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.max)
+        }
+    }
+    /// Returns the estimated value at the quantile `q`, between 0 and 1, if any value was reduced
+    /// in.
+    ///
+    /// `q` is clamped to the `[0, 1]` range, 0 and 1 corresponding to the min and max values.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let centroids = self.centroids();
+        let total = self.count as f64;
+        let index = q.clamp(0.0, 1.0) * total;
+        // Each centroid is assumed to be centered at its mean, with the min and max as the
+        // limits of the first and last ones; we interpolate linearly between those points:
+        let first = &centroids[0];
+        if index < first.weight / 2.0 {
+            let t = index / (first.weight / 2.0);
+            return Some(self.min + t * (first.mean - self.min));
+        }
+        let mut center = first.weight / 2.0;
+        for pair in centroids.windows(2) {
+            let next = center + (pair[0].weight + pair[1].weight) / 2.0;
+            if index < next {
+                let t = (index - center) / (next - center);
+                return Some(pair[0].mean + t * (pair[1].mean - pair[0].mean));
+            }
+            center = next;
+        }
+        let last = &centroids[centroids.len() - 1];
+        let t = ((index - center) / (last.weight / 2.0)).min(1.0);
+        Some(last.mean + t * (self.max - last.mean))
+    }
+    /// Returns the estimated fraction of the values that are less than or equal to `x`, if any
+    /// value was reduced in.
+    pub fn cdf(&self, x: f64) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        if x < self.min {
+            return Some(0.0);
+        }
+        if x >= self.max {
+            return Some(1.0);
+        }
+        let centroids = self.centroids();
+        let total = self.count as f64;
+        // The inverse of the interpolation done by `quantile`:
+        let first = &centroids[0];
+        if x < first.mean {
+            let t = (x - self.min) / (first.mean - self.min);
+            return Some(t * first.weight / 2.0 / total);
+        }
+        let mut center = first.weight / 2.0;
+        for pair in centroids.windows(2) {
+            let next = center + (pair[0].weight + pair[1].weight) / 2.0;
+            if x < pair[1].mean {
+                let t = (x - pair[0].mean) / (pair[1].mean - pair[0].mean);
+                return Some((center + t * (next - center)) / total);
+            }
+            center = next;
+        }
+        let last = &centroids[centroids.len() - 1];
+        let t = (x - last.mean) / (self.max - last.mean);
+        Some((center + t * last.weight / 2.0) / total)
+    }
+    /// Updates the digest with the given value.
+    pub fn reduce(&mut self, item: Item)
+    where
+        Item: Into<f64>,
+    {
+        let value = item.into();
+        if !value.is_finite() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.count += 1;
+        self.buffer.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        self.compress_if_full();
+    }
+    /// Updates the digest with the value behind the ref.
+    ///
+    /// This function requires the `Clone` trait.
+    pub fn reduce_ref(&mut self, item: &Item)
+    where
+        Item: Into<f64> + Clone,
+    {
+        self.reduce(item.clone())
+    }
+    /// Combine the digest of `other` into self, keeping the compression of self.
+    pub fn merge_from(&mut self, other: Self) {
+        if other.is_empty() {
+            return;
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.count += other.count;
+        self.buffer.extend(other.centroids);
+        self.buffer.extend(other.buffer);
+        self.compress_if_full();
+    }
+    /// Combine the digest of `other` into self and return the result, keeping the compression
+    /// of self.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+    fn compress_if_full(&mut self) {
+        if self.buffer.len() >= 5 * self.compression.ceil() as usize {
+            let buffer = std::mem::take(&mut self.buffer);
+            self.centroids = self.merged(buffer);
+        }
+    }
+    /// Returns the compressed centroids, merging the buffer into a copy if necessary.
+    fn centroids(&self) -> Cow<'_, [Centroid]> {
+        if self.buffer.is_empty() {
+            Cow::Borrowed(&self.centroids)
+        } else {
+            Cow::Owned(self.merged(self.buffer.clone()))
+        }
+    }
+    /// Merges the centroids of self with the provided ones, compressing the result.
+    fn merged(&self, mut centroids: Vec<Centroid>) -> Vec<Centroid> {
+        centroids.extend_from_slice(&self.centroids);
+        centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total = centroids.iter().map(|c| c.weight).sum::<f64>();
+        // The k1 scale function bounds the size of each centroid by its quantile:
+        let scale = |q: f64| self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin();
+        let limit = |k: f64| {
+            let k = k.min(self.compression / 4.0);
+            ((2.0 * PI * k / self.compression).sin() + 1.0) / 2.0
+        };
+        let mut result = Vec::with_capacity(self.compression.ceil() as usize);
+        let mut centroids = centroids.into_iter();
+        let mut current = match centroids.next() {
+            Some(first) => first,
+            None => return result,
+        };
+        let mut weight_before = 0.0;
+        let mut q_limit = limit(scale(0.0) + 1.0);
+        for centroid in centroids {
+            let q = (weight_before + current.weight + centroid.weight) / total;
+            if q <= q_limit {
+                let weight = current.weight + centroid.weight;
+                current.mean += (centroid.mean - current.mean) * centroid.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_before += current.weight;
+                q_limit = limit(scale(weight_before / total) + 1.0);
+                result.push(current);
+                current = centroid;
+            }
+        }
+        result.push(current);
+        result
+    }
+}
+
+impl<Item> Default for TDigest<Item> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_COMPRESSION)
+    }
+}
+
+impl<Item> Extend<Item> for TDigest<Item>
+where
+    Item: Into<f64>,
+{
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, Item> Extend<&'a Item> for TDigest<Item>
+where
+    Item: Into<f64> + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<Item> std::iter::FromIterator<Item> for TDigest<Item>
+where
+    Item: Into<f64>,
+{
+    fn from_iter<It: IntoIterator<Item = Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<'a, Item> std::iter::FromIterator<&'a Item> for TDigest<Item>
+where
+    Item: Into<f64> + Clone,
+{
+    fn from_iter<It: IntoIterator<Item = &'a Item>>(iter: It) -> Self {
+        let mut autofolder = Self::default();
+        autofolder.extend(iter);
+        autofolder
+    }
+}
+
+impl<Item> FoldItem<Item> for TDigest<Item>
+where
+    Item: Into<f64>,
+{
+    fn fold_item(&mut self, item: Item) {
+        self.reduce(item)
+    }
+}
+
+impl<Item> Folding for TDigest<Item>
+where
+    Item: Into<f64>,
+{
+    type Item = Item;
+    type Output = Self;
+    type Peek<'a>
+        = &'a Self
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self
+    }
+    fn finish(self) -> Self {
+        self
+    }
+}
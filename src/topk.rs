@@ -0,0 +1,330 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `TopK` type keeps the `k` largest items folded in, using the [`std::cmp::Ord`] trait.
+///
+/// The items are kept in a [`BinaryHeap`] with the smallest retained item at the top, so that
+/// each fold takes `O(log k)`. When items are equal, the ones folded in first are kept.
+///
+/// As `k` is given at runtime, the autofolder can't be created with
+/// [`collect`](Iterator::collect); use [`TopK::with_items`] instead.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the 3 largest items:
+/// let mut top = TopK::<u32>::new(3);
+///
+/// // We can "fold-in" individual items:
+/// top.fold(5);
+///
+/// // We can then peek at the smallest of the retained items:
+/// println!("Partial threshold is {:?}", top.peek());
+///
+/// // And still keep on folding by processing whole iterators:
+/// top.extend(vec![1, 9, 7, 3]);
+///
+/// // And finally consume the autofolder to get the items in ascending order:
+/// assert_eq!(top.into_sorted_vec(), vec![5, 7, 9]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TopK<Item> {
+    k: usize,
+    heap: BinaryHeap<Reverse<Item>>,
+}
+
+impl<Item> TopK<Item> {
+    /// Creates a new `TopK` that keeps the `k` largest items.
+    pub fn new(k: usize) -> Self
+    where
+        Item: Ord,
+    {
+        Self {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+    /// Folds all items of the iterator into self and return it.
+    ///
+    /// This is the builder-style replacement for [`collect`](Iterator::collect):
+    /// `TopK::new(k).with_items(iter)`.
+    pub fn with_items<It>(mut self, iter: It) -> Self
+    where
+        It: IntoIterator<Item = Item>,
+        Item: Ord,
+    {
+        self.extend(iter);
+        self
+    }
+    /// Returns the maximum number of items kept.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+    /// Returns the number of items kept.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    /// Returns `true` if no item is kept.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    /// Returns a reference to the smallest of the items kept, if there is one.
+    ///
+    /// Once `k` items are kept, only items larger than this one are incorporated.
+    pub fn peek(&self) -> Option<&Item>
+    where
+        Item: Ord,
+    {
+        self.heap.peek().map(|item| &item.0)
+    }
+    /// Deconstruct self and return the items kept in ascending order.
+    pub fn into_sorted_vec(self) -> Vec<Item>
+    where
+        Item: Ord,
+    {
+        let mut vec = self
+            .heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|item| item.0)
+            .collect::<Vec<_>>();
+        vec.reverse();
+        vec
+    }
+    fn keeps(&self, item: &Item) -> bool
+    where
+        Item: Ord,
+    {
+        self.heap.len() < self.k || self.heap.peek().is_some_and(|top| *item > top.0)
+    }
+    /// Folds an individual value into self, if it is among the `k` largest.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Ord,
+    {
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(item));
+        } else if let Some(mut top) = self.heap.peek_mut() {
+            if item > top.0 {
+                *top = Reverse(item);
+            }
+        }
+    }
+    /// Folds in the value behind the reference, if it is among the `k` largest.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if necessary.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Ord + Clone,
+    {
+        if self.keeps(item) {
+            self.fold(item.clone());
+        }
+    }
+    /// Folds all items of `other` into self, keeping the `k` of self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Ord,
+    {
+        self.extend(other.heap.into_iter().map(|item| item.0));
+    }
+    /// Folds all items of `other` into self and return the result, keeping the `k` of self.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Ord,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+/// The `BottomK` type keeps the `k` smallest items folded in, using the [`std::cmp::Ord`]
+/// trait.
+///
+/// The items are kept in a [`BinaryHeap`] with the largest retained item at the top, so that
+/// each fold takes `O(log k)`. When items are equal, the ones folded in first are kept.
+///
+/// As `k` is given at runtime, the autofolder can't be created with
+/// [`collect`](Iterator::collect); use [`BottomK::with_items`] instead.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the 3 smallest items of an iterator:
+/// let mut bottom = BottomK::new(3).with_items(vec![5, 1, 9]);
+///
+/// // We can then peek at the largest of the retained items:
+/// println!("Partial threshold is {:?}", bottom.peek());
+///
+/// // And still keep on folding by processing whole iterators:
+/// bottom.extend(vec![7, 3]);
+///
+/// // And finally consume the autofolder to get the items in ascending order:
+/// assert_eq!(bottom.into_sorted_vec(), vec![1, 3, 5]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BottomK<Item> {
+    k: usize,
+    heap: BinaryHeap<Item>,
+}
+
+impl<Item> BottomK<Item> {
+    /// Creates a new `BottomK` that keeps the `k` smallest items.
+    pub fn new(k: usize) -> Self
+    where
+        Item: Ord,
+    {
+        Self {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+    /// Folds all items of the iterator into self and return it.
+    ///
+    /// This is the builder-style replacement for [`collect`](Iterator::collect):
+    /// `BottomK::new(k).with_items(iter)`.
+    pub fn with_items<It>(mut self, iter: It) -> Self
+    where
+        It: IntoIterator<Item = Item>,
+        Item: Ord,
+    {
+        self.extend(iter);
+        self
+    }
+    /// Returns the maximum number of items kept.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+    /// Returns the number of items kept.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    /// Returns `true` if no item is kept.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    /// Returns a reference to the largest of the items kept, if there is one.
+    ///
+    /// Once `k` items are kept, only items smaller than this one are incorporated.
+    pub fn peek(&self) -> Option<&Item>
+    where
+        Item: Ord,
+    {
+        self.heap.peek()
+    }
+    /// Deconstruct self and return the items kept in ascending order.
+    pub fn into_sorted_vec(self) -> Vec<Item>
+    where
+        Item: Ord,
+    {
+        self.heap.into_sorted_vec()
+    }
+    fn keeps(&self, item: &Item) -> bool
+    where
+        Item: Ord,
+    {
+        self.heap.len() < self.k || self.heap.peek().is_some_and(|top| item < top)
+    }
+    /// Folds an individual value into self, if it is among the `k` smallest.
+    pub fn fold(&mut self, item: Item)
+    where
+        Item: Ord,
+    {
+        if self.heap.len() < self.k {
+            self.heap.push(item);
+        } else if let Some(mut top) = self.heap.peek_mut() {
+            if item < *top {
+                *top = item;
+            }
+        }
+    }
+    /// Folds in the value behind the reference, if it is among the `k` smallest.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if necessary.
+    pub fn fold_ref(&mut self, item: &Item)
+    where
+        Item: Ord + Clone,
+    {
+        if self.keeps(item) {
+            self.fold(item.clone());
+        }
+    }
+    /// Folds all items of `other` into self, keeping the `k` of self.
+    pub fn merge_from(&mut self, other: Self)
+    where
+        Item: Ord,
+    {
+        self.extend(other.heap);
+    }
+    /// Folds all items of `other` into self and return the result, keeping the `k` of self.
+    pub fn merge(mut self, other: Self) -> Self
+    where
+        Item: Ord,
+    {
+        self.merge_from(other);
+        self
+    }
+}
+
+macro_rules! impl_topk_traits {
+    ($name: ident) => {
+        impl<Item> Extend<Item> for $name<Item>
+        where
+            Item: Ord,
+        {
+            fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold(i));
+            }
+        }
+
+        impl<'a, Item> Extend<&'a Item> for $name<Item>
+        where
+            Item: Ord + Clone,
+        {
+            fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+                iter.into_iter().for_each(|i| self.fold_ref(i));
+            }
+        }
+
+        impl<Item> FoldItem<Item> for $name<Item>
+        where
+            Item: Ord,
+        {
+            fn fold_item(&mut self, item: Item) {
+                self.fold(item)
+            }
+        }
+
+        impl<Item> Folding for $name<Item>
+        where
+            Item: Ord,
+        {
+            type Item = Item;
+            type Output = Vec<Item>;
+            type Peek<'a>
+                = Option<&'a Item>
+            where
+                Self: 'a;
+            fn peek(&self) -> Self::Peek<'_> {
+                $name::peek(self)
+            }
+            fn finish(self) -> Vec<Item> {
+                self.into_sorted_vec()
+            }
+        }
+    };
+}
+
+impl_topk_traits!(TopK);
+impl_topk_traits!(BottomK);
@@ -0,0 +1,233 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// The `TopKScored` type keeps the `k` highest-scoring items, along with their `f64` scores.
+///
+/// Scores are compared with [`f64::total_cmp`]. On ties, the items folded in first are
+/// preferred, both for retention and for the order of the output. Items with `NaN` scores are
+/// ignored, as `total_cmp` would otherwise rank a positive `NaN` above infinity.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the 2 best-scoring documents:
+/// let mut top = TopKScored::<&str>::new(2);
+///
+/// // We can "fold-in" individual scored items:
+/// top.fold("intro", 0.3);
+///
+/// // And still keep on folding by processing whole iterators of pairs:
+/// top.extend(vec![("usage", 0.9), ("license", 0.1), ("faq", 0.5)]);
+///
+/// // Or of items, scored by a closure:
+/// top.extend_with(vec!["changelog"], |doc| doc.len() as f64 / 10.0);
+///
+/// // And finally consume the autofolder to get the items, best first:
+/// assert_eq!(top.into_inner(), vec![("usage", 0.9), ("changelog", 0.9)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TopKScored<Item> {
+    k: usize,
+    seq: u64,
+    heap: BinaryHeap<Reverse<Scored<Item>>>,
+}
+
+#[derive(Debug, Clone)]
+struct Scored<Item> {
+    score: f64,
+    seq: u64,
+    item: Item,
+}
+
+impl<Item> Scored<Item> {
+    fn key(&self) -> (TotalF64, Reverse<u64>) {
+        (TotalF64(self.score), Reverse(self.seq))
+    }
+}
+
+impl<Item> PartialEq for Scored<Item> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Item> Eq for Scored<Item> {}
+
+impl<Item> PartialOrd for Scored<Item> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Item> Ord for Scored<Item> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TotalF64(f64);
+
+impl PartialEq for TotalF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalF64 {}
+
+impl PartialOrd for TotalF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl<Item> TopKScored<Item> {
+    /// Creates a new `TopKScored` that keeps the `k` highest-scoring items.
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            seq: 0,
+            heap: BinaryHeap::new(),
+        }
+    }
+    /// Returns the maximum number of items kept.
+    pub fn k(&self) -> usize {
+        self.k
+    }
+    /// Returns the number of items currently kept.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    /// Returns `true` if no item is kept.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    /// Returns the lowest score among the kept items, if there is one.
+    ///
+    /// Once `k` items are kept, items must score higher than this to get in.
+    pub fn threshold(&self) -> Option<f64> {
+        self.heap.peek().map(|Reverse(s)| s.score)
+    }
+    /// Deconstruct self and return the kept items with their scores, best first.
+    pub fn into_inner(self) -> Vec<(Item, f64)> {
+        let mut scored = self.heap.into_vec();
+        scored.sort();
+        scored
+            .into_iter()
+            .map(|Reverse(s)| (s.item, s.score))
+            .collect()
+    }
+    /// Returns `true` if an item with the given score would be kept.
+    fn accepts(&self, score: f64) -> bool {
+        if score.is_nan() {
+            return false;
+        }
+        self.heap.len() < self.k
+            || self
+                .heap
+                .peek()
+                .is_some_and(|Reverse(s)| score.total_cmp(&s.score) == Ordering::Greater)
+    }
+    /// Folds an individual item with its score into self.
+    pub fn fold(&mut self, item: Item, score: f64) {
+        if !self.accepts(score) {
+            return;
+        }
+        self.heap.push(Reverse(Scored {
+            score,
+            seq: self.seq,
+            item,
+        }));
+        self.seq += 1;
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+    /// Folds in the item behind the reference with its score.
+    ///
+    /// This function requires the `Clone` trait, but uses it only if necessary.
+    pub fn fold_ref(&mut self, item: &Item, score: f64)
+    where
+        Item: Clone,
+    {
+        if self.accepts(score) {
+            self.fold(item.clone(), score);
+        }
+    }
+    /// Folds in all items of the iterator, scored by the provided function.
+    pub fn extend_with<It, Func>(&mut self, iter: It, func: Func)
+    where
+        It: IntoIterator<Item = Item>,
+        Func: Fn(&Item) -> f64,
+    {
+        iter.into_iter().for_each(|i| {
+            let score = func(&i);
+            self.fold(i, score)
+        });
+    }
+    /// Folds all items of `other` into self, keeping the `k` of self.
+    pub fn merge_from(&mut self, other: Self) {
+        self.extend(other.into_inner());
+    }
+    /// Folds all items of `other` into self and return the result, keeping the `k` of self.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_from(other);
+        self
+    }
+}
+
+impl<Item> Extend<(Item, f64)> for TopKScored<Item> {
+    fn extend<It: IntoIterator<Item = (Item, f64)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|(i, s)| self.fold(i, s));
+    }
+}
+
+impl<'a, Item> Extend<&'a (Item, f64)> for TopKScored<Item>
+where
+    Item: Clone + 'a,
+{
+    fn extend<It: IntoIterator<Item = &'a (Item, f64)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|(i, s)| self.fold_ref(i, *s));
+    }
+}
+
+impl<Item> FoldItem<(Item, f64)> for TopKScored<Item> {
+    fn fold_item(&mut self, (item, score): (Item, f64)) {
+        self.fold(item, score)
+    }
+}
+
+/// Items are folded in as `(item, score)` pairs, and [`Folding::peek`] returns the
+/// [`threshold`](TopKScored::threshold).
+impl<Item> Folding for TopKScored<Item> {
+    type Item = (Item, f64);
+    type Output = Vec<(Item, f64)>;
+    type Peek<'a>
+        = Option<f64>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.threshold()
+    }
+    fn finish(self) -> Vec<(Item, f64)> {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use core::marker;
+
+/// The `TryDynFolder` type uses a struct field for a fallible folding function.
+///
+/// This is essentially a [`DynFolder`](crate::DynFolder) whose function has the
+/// `Fn(&Output, Item) -> Result<Output, E>` signature. The function gets the current output by
+/// reference, which means that it is preserved when the function returns an error: the
+/// autofolder always holds the last good state.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that sums `u8` items, failing on overflow:
+/// let mut sum = TryDynFolder::new(0_u8, |a: &u8, i: u8| a.checked_add(i).ok_or("overflow"));
+///
+/// // We can "fold-in" individual items:
+/// sum.try_fold(200)?;
+///
+/// // We can then peek at the running output:
+/// println!("Partial sum is {}", sum.as_ref());
+///
+/// // And still keep on folding by processing whole iterators, which stops at the first error:
+/// let mut items = vec![50, 10, 1].into_iter();
+/// assert_eq!(sum.try_extend(&mut items), Err("overflow"));
+/// assert_eq!(items.next(), Some(1));
+///
+/// // And finally consume the autofolder to get the last good output value:
+/// assert_eq!(sum.into_inner(), 250);
+/// # Ok::<(), &str>(())
+/// ```
+#[derive(Copy, Clone)]
+pub struct TryDynFolder<Output, Item, Func> {
+    output: Output,
+    function: Func,
+    item: marker::PhantomData<Item>,
+}
+
+impl<Output, Item, Func> TryDynFolder<Output, Item, Func> {
+    /// Creates a new `TryDynFolder` with the provided initial value and folding function.
+    pub const fn new<E>(initial: Output, func: Func) -> Self
+    where
+        Func: Fn(&Output, Item) -> Result<Output, E>,
+    {
+        Self {
+            output: initial,
+            function: func,
+            item: marker::PhantomData,
+        }
+    }
+    /// Returns the contained value, consuming the self value.
+    pub fn into_inner(self) -> Output {
+        self.output
+    }
+    /// Folds an individual value into self, keeping the current output if the folding function
+    /// returns an error.
+    pub fn try_fold<E>(&mut self, item: Item) -> Result<(), E>
+    where
+        Func: Fn(&Output, Item) -> Result<Output, E>,
+    {
+        self.output = (self.function)(&self.output, item)?;
+        Ok(())
+    }
+    /// Folds all values of the iterator into self, stopping at the first error.
+    ///
+    /// The items after the one that caused the error are not consumed, and the output is kept
+    /// as it was before that item.
+    pub fn try_extend<E, It>(&mut self, iter: It) -> Result<(), E>
+    where
+        Func: Fn(&Output, Item) -> Result<Output, E>,
+        It: IntoIterator<Item = Item>,
+    {
+        iter.into_iter().try_for_each(|i| self.try_fold(i))
+    }
+}
+
+impl<Output, Item, Func> core::fmt::Debug for TryDynFolder<Output, Item, Func>
+where
+    Output: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "TryDynFolder::<{}, {}, _> {{ output: {:?}, function: {} }}",
+            &core::any::type_name::<Output>(),
+            &core::any::type_name::<Item>(),
+            self.output,
+            &core::any::type_name::<Func>(),
+        )
+    }
+}
+
+impl<Output, Item, Func> AsRef<Output> for TryDynFolder<Output, Item, Func> {
+    fn as_ref(&self) -> &Output {
+        &self.output
+    }
+}
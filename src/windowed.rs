@@ -0,0 +1,171 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use std::collections::VecDeque;
+
+/// The `Windowed` type keeps the last `size` items folded in, and reduces them with a struct
+/// field function on demand.
+///
+/// The items are kept in a ring buffer; once it's full, each item folded in evicts the oldest
+/// one. The reduction of the current window, as done by [`DynReduce`](crate::DynReduce), is
+/// recomputed by [`Windowed::result`] from a copy of the items.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that sums the last 3 items:
+/// let mut rolling = Windowed::new(3, |a: u32, b| a + b);
+///
+/// // We can "fold-in" individual items:
+/// rolling.fold(1);
+///
+/// // We can then peek at the reduction of the current window:
+/// assert_eq!(rolling.result(), Some(1));
+///
+/// // And still keep on folding by processing whole iterators:
+/// rolling.extend(vec![2, 3, 4]);
+/// assert_eq!(rolling.result(), Some(9));
+///
+/// // And finally consume the autofolder to get the final reduction:
+/// assert_eq!(rolling.into_inner(), Some(9));
+/// ```
+#[derive(Clone)]
+pub struct Windowed<Item, Func> {
+    size: usize,
+    window: VecDeque<Item>,
+    function: Func,
+}
+
+impl<Item, Func> Windowed<Item, Func> {
+    /// Creates a new empty `Windowed` that keeps the last `size` items, with the provided
+    /// reduce function.
+    pub fn new(size: usize, func: Func) -> Self
+    where
+        Func: Fn(Item, Item) -> Item,
+    {
+        Self {
+            size,
+            window: VecDeque::with_capacity(size),
+            function: func,
+        }
+    }
+    /// Returns the maximum number of items in the window.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    /// Returns the number of items in the window.
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+    /// Returns `true` if the window is empty.
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+    /// Returns `true` if the window has `size` items.
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.size
+    }
+    /// Returns an iterator over the items in the window, oldest first.
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, Item> {
+        self.window.iter()
+    }
+    /// Returns the reduction of the items in the window, oldest first, if there is one.
+    ///
+    /// This function requires the `Clone` trait, as it reduces copies of the items.
+    pub fn result(&self) -> Option<Item>
+    where
+        Func: Fn(Item, Item) -> Item,
+        Item: Clone,
+    {
+        self.window.iter().cloned().reduce(&self.function)
+    }
+    /// Deconstruct self and return the reduction of the items in the window, if there is one.
+    pub fn into_inner(self) -> Option<Item>
+    where
+        Func: Fn(Item, Item) -> Item,
+    {
+        self.window.into_iter().reduce(self.function)
+    }
+    /// Deconstruct self and return the items in the window, oldest first.
+    pub fn into_window(self) -> VecDeque<Item> {
+        self.window
+    }
+    /// Folds an individual item into the window, returning the evicted one, if any.
+    pub fn fold(&mut self, item: Item) -> Option<Item> {
+        if self.size == 0 {
+            return Some(item);
+        }
+        let evicted = if self.is_full() {
+            self.window.pop_front()
+        } else {
+            None
+        };
+        self.window.push_back(item);
+        evicted
+    }
+}
+
+impl<Item, Func> core::fmt::Debug for Windowed<Item, Func>
+where
+    Item: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Windowed::<{}, _> {{ size: {}, window: {:?}, function: {} }}",
+            &core::any::type_name::<Item>(),
+            self.size,
+            self.window,
+            &core::any::type_name::<Func>(),
+        )
+    }
+}
+
+impl<Item, Func> Extend<Item> for Windowed<Item, Func> {
+    fn extend<It: IntoIterator<Item = Item>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| {
+            self.fold(i);
+        });
+    }
+}
+
+impl<'a, Item, Func> Extend<&'a Item> for Windowed<Item, Func>
+where
+    Item: Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a Item>>(&mut self, iter: It) {
+        self.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<Item, Func> FoldItem<Item> for Windowed<Item, Func> {
+    fn fold_item(&mut self, item: Item) {
+        self.fold(item);
+    }
+}
+
+impl<Item, Func> Folding for Windowed<Item, Func>
+where
+    Func: Fn(Item, Item) -> Item,
+    Item: Clone,
+{
+    type Item = Item;
+    type Output = Option<Item>;
+    type Peek<'a>
+        = Option<Item>
+    where
+        Self: 'a;
+    fn peek(&self) -> Option<Item> {
+        self.result()
+    }
+    fn finish(self) -> Option<Item> {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+use crate::FoldItem;
+use crate::Folding;
+use core::ops::Sub;
+use std::collections::VecDeque;
+use std::time::Duration;
+use std::time::Instant;
+
+/// The `WindowedMinMax` type reduces `(key, item)` pairs, keeping the min and max of the items
+/// whose keys are within a window of the latest key, as given by [`std::cmp::PartialOrd`].
+///
+/// The keys are usually timestamps, such as [`Instant`]s, and the window a span, such as a
+/// [`Duration`], which gives the "min/max over the last 5 minutes": an item expires when the
+/// latest key is at least `window` away from its key. Any monotonic key with a [`Sub`]
+/// implementation can be used, though, like `u64` seconds since the epoch with an `u64` window.
+///
+/// The keys are expected not to decrease. The latest key is advanced by each pair reduced in, and
+/// can also be advanced explicitly with [`WindowedMinMax::advance_to`], which evicts the items
+/// that expired.
+///
+/// The candidates for min and max are kept in monotonic deques, so each item is pushed and
+/// evicted only once, and the deques hold only the items that can still become the min or max.
+/// Items that can't be compared with themselves, like `NaN`s, are ignored.
+///
+/// Example:
+/// ```
+/// use autofolder::*;
+///
+/// // Create an autofolder that keeps the min and max over the last 300 seconds:
+/// let mut minmax = WindowedMinMax::<u32, u64, u64>::new(300);
+///
+/// // We can "reduce-in" individual (timestamp, item) pairs:
+/// minmax.reduce((1000, 7));
+///
+/// // We can then peek at the running output:
+/// println!("Partial minmax is {:?}", minmax.as_ref());
+///
+/// // And still keep on folding by processing whole iterators:
+/// minmax.extend(vec![(1100, 3), (1200, 5), (1310, 4)]);
+/// assert_eq!(minmax.as_ref(), Some((&3, &5)));
+///
+/// // Advancing the time evicts the expired items:
+/// minmax.advance_to(1400);
+/// assert_eq!(minmax.as_ref(), Some((&4, &5)));
+///
+/// // And finally consume the autofolder to get the final output value:
+/// minmax.advance_to(1700);
+/// assert_eq!(minmax.into_inner(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WindowedMinMax<Item, Key = Instant, Span = Duration> {
+    window: Span,
+    latest: Option<Key>,
+    // Candidates for the min, with increasing items:
+    mins: VecDeque<(Key, Item)>,
+    // Candidates for the max, with decreasing items:
+    maxs: VecDeque<(Key, Item)>,
+}
+
+impl<Item, Key, Span> WindowedMinMax<Item, Key, Span> {
+    /// Creates a new empty `WindowedMinMax` that keeps the items within `window` of the latest
+    /// key.
+    pub fn new(window: Span) -> Self {
+        Self {
+            window,
+            latest: None,
+            mins: VecDeque::new(),
+            maxs: VecDeque::new(),
+        }
+    }
+    /// Returns the window.
+    pub fn window(&self) -> &Span {
+        &self.window
+    }
+    /// Returns the latest key, if there is one.
+    pub fn latest(&self) -> Option<&Key> {
+        self.latest.as_ref()
+    }
+    /// Returns `true` if there are no items in the window.
+    pub fn is_empty(&self) -> bool {
+        self.mins.is_empty()
+    }
+    /// Returns references to the min and max items in the window, if there are any.
+    pub fn as_ref(&self) -> Option<(&Item, &Item)> {
+        Some((self.min_as_ref()?, self.max_as_ref()?))
+    }
+    /// Returns a reference to the min item in the window, if there is one.
+    pub fn min_as_ref(&self) -> Option<&Item> {
+        self.mins.front().map(|(_, item)| item)
+    }
+    /// Returns a reference to the max item in the window, if there is one.
+    pub fn max_as_ref(&self) -> Option<&Item> {
+        self.maxs.front().map(|(_, item)| item)
+    }
+    /// Deconstruct self and return the min and max items in the window, if there are any.
+    pub fn into_inner(mut self) -> Option<(Item, Item)> {
+        let (_, min) = self.mins.pop_front()?;
+        let (_, max) = self.maxs.pop_front()?;
+        Some((min, max))
+    }
+    /// Advances the latest key to `now`, evicting the items that expired.
+    ///
+    /// Keys older than the latest one are ignored.
+    pub fn advance_to(&mut self, now: Key)
+    where
+        Key: PartialOrd + Sub<Output = Span> + Copy,
+        Span: PartialOrd,
+    {
+        let now = match self.latest {
+            Some(latest) if now < latest => latest,
+            _ => now,
+        };
+        self.latest = Some(now);
+        let window = &self.window;
+        for deque in [&mut self.mins, &mut self.maxs] {
+            while deque.front().is_some_and(|(key, _)| now - *key >= *window) {
+                deque.pop_front();
+            }
+        }
+    }
+    /// Reduces the `(key, item)` pair, advancing the latest key to `key`.
+    ///
+    /// If `key` is older than the latest key, the item is kept as if it had the latest key.
+    pub fn reduce(&mut self, item: (Key, Item))
+    where
+        Key: PartialOrd + Sub<Output = Span> + Copy,
+        Span: PartialOrd,
+        Item: PartialOrd + Clone,
+    {
+        let (key, item) = item;
+        self.advance_to(key);
+        if item.partial_cmp(&item).is_none() {
+            return;
+        }
+        let key = self.latest.unwrap_or(key);
+        // Candidates that are not better than the new item will expire before it, so they can
+        // never be the min/max again:
+        while self.mins.back().is_some_and(|(_, min)| *min >= item) {
+            self.mins.pop_back();
+        }
+        while self.maxs.back().is_some_and(|(_, max)| *max <= item) {
+            self.maxs.pop_back();
+        }
+        self.mins.push_back((key, item.clone()));
+        self.maxs.push_back((key, item));
+    }
+    /// Reduces the `(key, item)` pair behind the ref, advancing the latest key to `key`.
+    ///
+    /// This function requires the `Clone` trait, as the items may be kept.
+    pub fn reduce_ref(&mut self, item: &(Key, Item))
+    where
+        Key: PartialOrd + Sub<Output = Span> + Copy,
+        Span: PartialOrd,
+        Item: PartialOrd + Clone,
+    {
+        self.reduce((item.0, item.1.clone()))
+    }
+}
+
+impl<Item, Key, Span> Extend<(Key, Item)> for WindowedMinMax<Item, Key, Span>
+where
+    Key: PartialOrd + Sub<Output = Span> + Copy,
+    Span: PartialOrd,
+    Item: PartialOrd + Clone,
+{
+    fn extend<It: IntoIterator<Item = (Key, Item)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce(i));
+    }
+}
+
+impl<'a, Item, Key, Span> Extend<&'a (Key, Item)> for WindowedMinMax<Item, Key, Span>
+where
+    Key: PartialOrd + Sub<Output = Span> + Copy,
+    Span: PartialOrd,
+    Item: PartialOrd + Clone,
+{
+    fn extend<It: IntoIterator<Item = &'a (Key, Item)>>(&mut self, iter: It) {
+        iter.into_iter().for_each(|i| self.reduce_ref(i));
+    }
+}
+
+impl<Item, Key, Span> FoldItem<(Key, Item)> for WindowedMinMax<Item, Key, Span>
+where
+    Key: PartialOrd + Sub<Output = Span> + Copy,
+    Span: PartialOrd,
+    Item: PartialOrd + Clone,
+{
+    fn fold_item(&mut self, item: (Key, Item)) {
+        self.reduce(item)
+    }
+}
+
+impl<Item, Key, Span> Folding for WindowedMinMax<Item, Key, Span>
+where
+    Key: PartialOrd + Sub<Output = Span> + Copy,
+    Span: PartialOrd,
+    Item: PartialOrd + Clone,
+{
+    type Item = (Key, Item);
+    type Output = Option<(Item, Item)>;
+    type Peek<'a>
+        = Option<(&'a Item, &'a Item)>
+    where
+        Self: 'a;
+    fn peek(&self) -> Self::Peek<'_> {
+        self.as_ref()
+    }
+    fn finish(self) -> Option<(Item, Item)> {
+        self.into_inner()
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test the checked adder, with and without overflow
+#[test]
+fn test_checked() -> Result<()> {
+    let empty = CheckedAdder::<i32>::default();
+    assert_eq!(empty.into_inner(), Some(0));
+    let mut sum = (1..=10_i64).collect::<CheckedAdder<_>>();
+    sum.fold(-5);
+    assert!(!sum.is_overflowed());
+    assert_eq!(sum.as_ref(), Some(&50));
+    let mut sum = CheckedAdder::<u8>::from(200);
+    sum.merge_from([50, 6].iter().collect());
+    assert!(sum.is_overflowed());
+    sum.fold(0);
+    assert_eq!(sum.count(), 3);
+    assert_eq!(sum.into_inner(), None);
+    Ok(())
+}
+
+/// Test the saturating and wrapping adders
+#[test]
+fn test_saturating_wrapping() -> Result<()> {
+    let sum = [i8::MIN, -1, 10].iter().collect::<SaturatingAdder<i8>>();
+    assert_eq!(sum.into_inner(), -118);
+    let sum = SaturatingAdder::<u16>::from(u16::MAX).merge([1, 2].iter().collect());
+    assert_eq!(sum.into_inner(), u16::MAX);
+    let mut sum = WrappingAdder::<u8>::new(250);
+    sum.extend([3, 4]);
+    assert_eq!(*sum.as_ref(), 1);
+    let sum = sum.merge(WrappingAdder::from(u8::MAX));
+    assert_eq!(sum.into_inner(), 0);
+    Ok(())
+}
+
+/// Test the Sum implementations
+#[test]
+fn test_sum() -> Result<()> {
+    let sum = [100_u8, 100, 100].iter().sum::<CheckedAdder<u8>>();
+    assert!(sum.is_overflowed());
+    let sum = (1..=4_u16).sum::<SaturatingAdder<_>>();
+    assert_eq!(sum.into_inner(), 10);
+    let sum = [u32::MAX, 2].iter().sum::<WrappingAdder<u32>>();
+    assert_eq!(sum.into_inner(), 1);
+    Ok(())
+}
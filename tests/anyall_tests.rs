@@ -0,0 +1,61 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test that any stops consuming once an item matches
+#[test]
+fn test_any() -> Result<()> {
+    let mut any = Any::new(|i: &i32| *i < 0);
+    assert!(!any.is_decided());
+    any.extend(&[1, 2]);
+    assert!(!*any.as_ref());
+    let mut iter = [3, -1, 4, -5].iter().copied();
+    any.extend(&mut iter);
+    assert!(any.is_decided());
+    assert_eq!(any.count(), 4);
+    assert_eq!(iter.next(), Some(4));
+    any.fold(-6);
+    assert_eq!(any.count(), 4);
+    assert!(any.into_inner());
+    Ok(())
+}
+
+/// Test that all stops consuming once an item doesn't match
+#[test]
+fn test_all() -> Result<()> {
+    let mut evaluated = 0;
+    let mut all = All::new(|i: &u32| {
+        evaluated += 1;
+        *i < 5
+    });
+    all.extend(0..);
+    all.fold(7);
+    assert!(!all.into_inner());
+    assert_eq!(evaluated, 6);
+    let positive = |i: &u32| *i > 0;
+    let all = All::new(positive).merge(All::from(positive));
+    assert!(all.into_inner());
+    Ok(())
+}
+
+/// Test merging partial results
+#[test]
+fn test_merge() -> Result<()> {
+    let even = |i: &u32| i.is_multiple_of(2);
+    let mut any = Any::new(even);
+    any.extend([1, 3]);
+    let mut other = Any::new(even);
+    other.extend([5, 6, 7]);
+    let any = any.merge(other);
+    assert!(any.is_decided());
+    assert_eq!(any.count(), 4);
+    let mut all = All::new(even);
+    all.extend([2, 4]);
+    all.merge_from(All::new(even));
+    assert!(all.into_inner());
+    Ok(())
+}
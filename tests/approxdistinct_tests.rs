@@ -0,0 +1,77 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "hyperloglog")]
+mod approxdistinct_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    fn assert_close(estimate: u64, exact: u64, error: f64) {
+        let relative = (estimate as f64 - exact as f64).abs() / exact as f64;
+        assert!(relative < error, "{} is too far from {}", estimate, exact);
+    }
+
+    /// Test small and large cardinalities
+    #[test]
+    fn test_estimate() -> Result<()> {
+        let mut distinct = ApproxDistinct::<u32>::default();
+        assert_eq!(distinct.precision(), 12);
+        assert_eq!(distinct.estimate(), 0.0);
+        distinct.extend((0..20).chain(0..20));
+        assert_eq!(distinct.clone().into_inner(), 20);
+        distinct.extend(0..200_000);
+        assert_close(distinct.into_inner(), 200_000, 4.0 * 1.04 / 64.0);
+        let words = ["a", "b", "a", "c"];
+        let distinct = words.iter().collect::<ApproxDistinct<&str>>();
+        assert_eq!(distinct.into_inner(), 3);
+        Ok(())
+    }
+
+    /// Test precision and error bound
+    #[test]
+    fn test_precision() -> Result<()> {
+        let mut coarse = ApproxDistinct::<u64>::new(ApproxDistinct::<u64>::MIN_PRECISION);
+        let mut fine = ApproxDistinct::<u64>::new(16);
+        assert!(coarse.error_bound() > fine.error_bound());
+        assert!((fine.error_bound() - 1.04 / 256.0).abs() < 1e-12);
+        coarse.extend(0..50_000);
+        fine.extend(0..50_000);
+        assert_close(fine.into_inner(), 50_000, 4.0 * 1.04 / 256.0);
+        assert_close(coarse.into_inner(), 50_000, 4.0 * 1.04 / 4.0);
+        let result = std::panic::catch_unwind(|| ApproxDistinct::<u64>::new(3));
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    /// Test merging
+    #[test]
+    fn test_merge() -> Result<()> {
+        let a = (0..30_000_u64).collect::<ApproxDistinct<_>>();
+        let b = (20_000..60_000_u64).collect::<ApproxDistinct<_>>();
+        let whole = (0..60_000_u64).collect::<ApproxDistinct<_>>();
+        let merged = a.merge(b);
+        assert_eq!(merged.estimate(), whole.estimate());
+        let result = std::panic::catch_unwind(|| {
+            ApproxDistinct::<u64>::new(10).merge(ApproxDistinct::new(11))
+        });
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    /// Test that deserialization validates the state
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() -> Result<()> {
+        let distinct = (0..1000_u64).collect::<ApproxDistinct<_>>();
+        let json = serde_json::to_string(&distinct)?;
+        let restored = serde_json::from_str::<ApproxDistinct<u64>>(&json)?;
+        assert_eq!(restored.estimate(), distinct.estimate());
+        let zero = r#"{"precision":0,"registers":[0]}"#;
+        assert!(serde_json::from_str::<ApproxDistinct<u64>>(zero).is_err());
+        let short = r#"{"precision":4,"registers":[0,0]}"#;
+        assert!(serde_json::from_str::<ApproxDistinct<u64>>(short).is_err());
+        Ok(())
+    }
+}
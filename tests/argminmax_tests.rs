@@ -0,0 +1,49 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test extend, collect and ties for argmin
+#[test]
+fn test_argmin() -> Result<()> {
+    let mut argmin = ArgMin::<i32>::default();
+    assert_eq!(argmin.as_ref(), None);
+    argmin.reduce(5);
+    argmin.extend(vec![3, 8, 3]);
+    assert_eq!(argmin.as_ref(), Some((1, &3)));
+    argmin.extend(&[-1, 4]);
+    assert_eq!(argmin.index(), Some(4));
+    assert_eq!(argmin.next_index(), 6);
+    assert_eq!(argmin.into_inner(), Some((4, -1)));
+    let collect = [2.5, 0.5, 1.5].iter().collect::<ArgMin<f64>>();
+    assert_eq!(collect.into_inner(), Some((1, 0.5)));
+    Ok(())
+}
+
+/// Test extend, collect and ties for argmax
+#[test]
+fn test_argmax() -> Result<()> {
+    let argmax = vec![1, 9, 2, 9, 3].into_iter().collect::<ArgMax<_>>();
+    assert_eq!(argmax.into_inner(), Some((1, 9)));
+    let mut argmax = ArgMax::<f64>::new();
+    argmax.extend(vec![f64::NAN, 1.0, f64::NAN, 2.0]);
+    assert_eq!(argmax.into_inner().map(|(i, _)| i), Some(0));
+    Ok(())
+}
+
+/// Test resuming with an offset and merging
+#[test]
+fn test_offset_merge() -> Result<()> {
+    let items = [4, 7, 1, 9, 9, 0, 9, 2];
+    let first = items[..4].iter().collect::<ArgMax<_>>();
+    let mut second = ArgMax::with_offset(first.next_index());
+    second.extend(&items[4..]);
+    assert_eq!(second.as_ref(), Some((4, &9)));
+    let merged = second.merge(first);
+    assert_eq!(merged.into_inner(), Some((3, 9)));
+    assert_eq!(merged.next_index(), 8);
+    Ok(())
+}
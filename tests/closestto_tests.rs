@@ -0,0 +1,53 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test builtin type, ties
+#[test]
+fn test_closestto() -> Result<()> {
+    let mut closest = ClosestTo::new(10_i32, |a: &i32, b: &i32| (a - b).abs());
+    assert_eq!(closest.as_ref(), None);
+    closest.fold(3);
+    assert_eq!(closest.as_ref(), Some(&3));
+    assert_eq!(closest.distance(), Some(&7));
+    closest.extend(vec![15, 12, 8]);
+    assert_eq!(closest.as_ref(), Some(&12));
+    closest.extend(&[20, 11, 9]);
+    assert_eq!(closest.target(), &10);
+    eprintln!("{:?}", closest);
+    assert_eq!(closest.into_inner_with_distance(), Some((11, 1)));
+    Ok(())
+}
+
+/// Test Strings, which don't impl Copy
+#[test]
+fn test_strings() -> Result<()> {
+    let len_dist = |a: &String, b: &String| (a.len() as isize - b.len() as isize).abs();
+    let mut closest = ClosestTo::new("abcd".to_string(), len_dist);
+    let candidates = ["a".to_string(), "abcdef".to_string(), "abc".to_string()];
+    closest.extend(candidates.iter());
+    assert_eq!(closest.into_inner(), Some("abc".to_string()));
+    Ok(())
+}
+
+/// Test levenshtein distance
+#[cfg(feature = "levenshtein")]
+#[test]
+fn test_levenshtein() -> Result<()> {
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("abc", ""), 3);
+    assert_eq!(levenshtein("ação", "acao"), 2);
+    let mut closest = ClosestTo::new("extnd".to_string(), levenshtein);
+    closest.extend(
+        vec!["fold", "extend", "reduce"]
+            .into_iter()
+            .map(String::from),
+    );
+    assert_eq!(closest.into_inner().as_deref(), Some("extend"));
+    Ok(())
+}
@@ -0,0 +1,104 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+#[derive(Debug, Clone, Copy)]
+struct LogRecord {
+    level: u8,
+    latency: f64,
+}
+
+const RECORDS: [LogRecord; 4] = [
+    LogRecord {
+        level: 1,
+        latency: 9.0,
+    },
+    LogRecord {
+        level: 2,
+        latency: 1.5,
+    },
+    LogRecord {
+        level: 3,
+        latency: 4.0,
+    },
+    LogRecord {
+        level: 2,
+        latency: 0.5,
+    },
+];
+
+/// Test premap with owned and referenced raw items
+#[test]
+fn test_premap() -> Result<()> {
+    let mut max = Max::default().premap(|record: LogRecord| record.latency);
+    max.fold(RECORDS[0]);
+    assert_eq!(max.as_ref().as_ref(), Some(&9.0));
+    max.extend(RECORDS[1..].iter().copied());
+    assert_eq!(max.finish(), Some(9.0));
+    let mut levels = MinMax::default().premap(|record: &LogRecord| record.level);
+    levels.extend(RECORDS.iter());
+    assert_eq!(levels.peek(), Some((&1, &3)));
+    assert_eq!(levels.into_inner().to_inner(), Some((1, 3)));
+    Ok(())
+}
+
+/// Test filter alone and combined with premap
+#[test]
+fn test_filter() -> Result<()> {
+    let mut even = Mean::<u8>::default().filter(|i| i % 2 == 0);
+    even.extend(1..=6);
+    assert_eq!(even.into_inner().into_inner(), Some(4.0));
+    let mut slowest = Max::default()
+        .premap(|record: LogRecord| record.latency)
+        .filter(|record: &LogRecord| record.level >= 2);
+    slowest.extend(RECORDS.iter().copied());
+    assert_eq!(slowest.finish(), Some(4.0));
+    let mut quick = Min::default()
+        .filter(|latency: &f64| *latency > 1.0)
+        .premap(|record: LogRecord| record.latency);
+    quick.extend(RECORDS.iter().copied());
+    assert_eq!(quick.finish(), Some(1.5));
+    // Iterators are not autofolders, so their filter is not ambiguous:
+    assert_eq!((1..=6).filter(|i| i % 3 == 0).count(), 2);
+    Ok(())
+}
+
+/// Test adapted autofolders in generic code
+#[test]
+fn test_generic() -> Result<()> {
+    let sum = DynFolder::new(0_u32, |acc, i: u32| acc + i);
+    let mut adapted = sum
+        .premap(|s: &str| s.len() as u32)
+        .filter(|s: &&str| !s.is_empty());
+    {
+        let folder: &mut dyn FoldItem<&str> = &mut adapted;
+        ["ab", "", "cde"].iter().copied().fold_into(folder);
+    }
+    let folded = ["f", ""]
+        .iter()
+        .copied()
+        .running(adapted)
+        .collect::<Vec<_>>();
+    assert_eq!(folded, vec![6, 6]);
+    Ok(())
+}
+
+/// Test folding in optional items
+#[test]
+fn test_fold_opt() -> Result<()> {
+    let mut minmax = MinMax::<i32>::default();
+    minmax.fold_opt(None);
+    assert!(minmax.is_empty());
+    minmax.fold_opt(Some(3));
+    minmax.extend_flatten(vec![None, Some(-1), None, Some(8)]);
+    assert_eq!(minmax.count(), 3);
+    assert_eq!(minmax.to_inner(), Some((-1, 8)));
+    let mut max = Max::<f64>::default().premap(|record: LogRecord| record.latency);
+    max.extend_flatten(RECORDS.iter().map(|r| Some(*r).filter(|r| r.level == 2)));
+    assert_eq!(max.into_inner().into_inner(), Some(1.5));
+    Ok(())
+}
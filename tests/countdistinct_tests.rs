@@ -0,0 +1,81 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod countdistinct_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    use std::cell::Cell;
+
+    /// Test extend, collect, count
+    #[test]
+    fn test_countdistinct() -> Result<()> {
+        let mut distinct = CountDistinct::<u32>::default();
+        assert_eq!(distinct.count(), 0);
+        distinct.fold(7);
+        distinct.extend(vec![1, 7, 2]);
+        distinct.extend(&[2, 3]);
+        assert_eq!(distinct.count(), 4);
+        assert!(distinct.as_ref().contains(&3));
+        assert_eq!(distinct.into_inner(), 4);
+        let collect = "mississippi".chars().collect::<CountDistinct<_>>();
+        assert_eq!(collect.into_inner(), 4);
+        Ok(())
+    }
+
+    // Type that counts its clones
+    #[derive(Debug)]
+    struct Counted<'a>(u32, &'a Cell<usize>);
+
+    impl PartialEq for Counted<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Counted<'_> {}
+
+    impl std::hash::Hash for Counted<'_> {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            self.0.hash(state)
+        }
+    }
+
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.1.set(self.1.get() + 1);
+            Counted(self.0, self.1)
+        }
+    }
+
+    /// Test that extending with refs clones only new items
+    #[test]
+    fn test_clone_new_only() -> Result<()> {
+        let clones = Cell::new(0);
+        let items = [1, 2, 1, 1, 3, 2].map(|i| Counted(i, &clones));
+        let distinct = items.iter().collect::<CountDistinct<Counted>>();
+        assert_eq!(distinct.count(), 3);
+        assert_eq!(clones.get(), 3);
+        Ok(())
+    }
+
+    /// Test merging
+    #[test]
+    fn test_merge() -> Result<()> {
+        let a = (0..10).collect::<CountDistinct<_>>();
+        let b = (5..100).collect::<CountDistinct<_>>();
+        let merged = a.merge(b);
+        assert_eq!(merged.count(), 100);
+        let mut small = CountDistinct::from(
+            vec![1000]
+                .into_iter()
+                .collect::<std::collections::HashSet<_>>(),
+        );
+        small.merge_from(merged);
+        assert_eq!(small.into_set().len(), 101);
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod counter_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test extend, collect, count
+    #[test]
+    fn test_counter() -> Result<()> {
+        let mut counter = Counter::<&str>::default();
+        assert!(counter.is_empty());
+        assert_eq!(counter.most_common(3), vec![]);
+        counter.fold("x");
+        counter.extend(vec!["y", "z", "y"]);
+        counter.extend(&["y"]);
+        assert_eq!(counter.len(), 3);
+        assert_eq!(counter.total(), 5);
+        assert_eq!(counter.count(&"y"), 3);
+        assert_eq!(counter.count(&"w"), 0);
+        assert_eq!(counter.most_common(1), vec![(&"y", 3)]);
+        let counts = counter.into_inner();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts["x"], 1);
+        Ok(())
+    }
+
+    /// Test merging counters
+    #[test]
+    fn test_merge() -> Result<()> {
+        let counter = "hello".chars().collect::<Counter<_>>();
+        let counter = counter.merge("world".chars().collect());
+        assert_eq!(counter.total(), 10);
+        assert_eq!(counter.most_common(2), vec![(&'l', 3), (&'o', 2)]);
+        assert_eq!(counter.as_ref().get(&'w'), Some(&1));
+        Ok(())
+    }
+}
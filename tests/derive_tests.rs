@@ -0,0 +1,116 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "derive")]
+mod derive_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Joined(String);
+
+    #[autofolder::fold]
+    fn join(mut joined: Joined, item: &str) -> Joined {
+        if !joined.0.is_empty() {
+            joined.0.push(',');
+        }
+        joined.0.push_str(item);
+        joined
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Bag<T>(Vec<T>);
+
+    #[fold]
+    fn push<T, const N: usize>(mut bag: Bag<[T; N]>, item: [T; N]) -> Bag<[T; N]> {
+        bag.0.push(item);
+        bag
+    }
+
+    /// Test the fold attribute with plain and generic functions
+    #[test]
+    fn test_fold() -> Result<()> {
+        let joined = vec!["a", "b", "c"]
+            .into_iter()
+            .collect::<ImplFolder<Joined, &str>>();
+        assert_eq!(joined.into_inner(), Joined("a,b,c".to_string()));
+        let mut bag = ImplFolder::new(Bag(vec![[0_u8; 2]]));
+        bag.extend(vec![[1, 2], [3, 4]]);
+        assert_eq!(bag.into_inner(), Bag(vec![[0, 0], [1, 2], [3, 4]]));
+        let mut bag = ImplFolder::<Bag<[char; 1]>, _>::default();
+        bag.fold(['x']);
+        assert_eq!(bag.finish(), Bag(vec![['x']]));
+        Ok(())
+    }
+
+    #[derive(Reduce, Debug, Clone, Copy, PartialEq)]
+    #[reduce(inner = u32::min)]
+    pub struct Min(u32);
+
+    #[derive(Reduce, Debug, PartialEq)]
+    #[reduce(inner = |a: Vec<T>, b: Vec<T>| a.into_iter().chain(b).collect())]
+    pub struct Concat<T> {
+        items: Vec<T>,
+    }
+
+    #[derive(Reduce, Debug, PartialEq)]
+    #[reduce(with = Point::furthest)]
+    pub struct Point<'a> {
+        name: &'a str,
+        x: i32,
+        y: i32,
+    }
+
+    impl Point<'_> {
+        fn furthest(self, other: Self) -> Self {
+            if other.x.pow(2) + other.y.pow(2) > self.x.pow(2) + self.y.pow(2) {
+                other
+            } else {
+                self
+            }
+        }
+    }
+
+    /// Test the Reduce derive with inner and whole-value functions
+    #[test]
+    fn test_reduce() -> Result<()> {
+        let min = [7, 3, 9]
+            .iter()
+            .copied()
+            .map(Min)
+            .collect::<ImplReduce<_>>();
+        assert_eq!(min.into_inner(), Some(Min(3)));
+        let mut concat = ImplReduce::default();
+        concat.extend(vec![
+            Concat { items: vec![1, 2] },
+            Concat { items: vec![] },
+            Concat { items: vec![3] },
+        ]);
+        assert_eq!(
+            concat.peek(),
+            Some(&Concat {
+                items: vec![1, 2, 3]
+            })
+        );
+        let name = String::from("far");
+        let mut furthest = ImplReduce::new(Point {
+            name: "origin",
+            x: 0,
+            y: 0,
+        });
+        furthest.reduce(Point {
+            name: &name,
+            x: -3,
+            y: 4,
+        });
+        furthest.reduce(Point {
+            name: "near",
+            x: 1,
+            y: 1,
+        });
+        assert_eq!(furthest.into_inner().map(|p| p.name), Some("far"));
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod distinctsorted_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test extend, collect, queries
+    #[test]
+    fn test_distinctsorted() -> Result<()> {
+        let mut distinct = DistinctSorted::<usize>::default();
+        assert!(distinct.is_empty());
+        distinct.fold(5);
+        distinct.extend([3, 9, 5, 1, 3]);
+        assert_eq!(distinct.len(), 4);
+        assert!(distinct.contains(&9));
+        assert!(!distinct.contains(&2));
+        assert_eq!(distinct.first(), Some(&1));
+        assert_eq!(distinct.last(), Some(&9));
+        assert_eq!(distinct.range(2..=5).collect::<Vec<_>>(), vec![&3, &5]);
+        let clone = distinct.clone();
+        distinct.extend(&[2, 9]);
+        assert_eq!(distinct.into_vec(), vec![1, 2, 3, 5, 9]);
+        assert_eq!(clone.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5, 9]);
+        Ok(())
+    }
+
+    /// Test type without clone, merge
+    #[test]
+    fn test_merge() -> Result<()> {
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+        struct Usize(usize);
+        let distinct1 = (1..=3).map(Usize).collect::<DistinctSorted<_>>();
+        let distinct2 = (2..=4).rev().map(Usize).collect::<DistinctSorted<_>>();
+        let merged = distinct1.merge(distinct2);
+        assert_eq!(
+            merged.into_vec(),
+            vec![Usize(1), Usize(2), Usize(3), Usize(4)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test builtin type
+#[test]
+fn test_builtin_sum_usize() -> Result<()> {
+    let mut sum = DynFolderMut::<usize, u16, _>::new(0_usize, |a, i| *a += i as usize);
+    sum.fold(10);
+    assert_eq!(*sum.as_ref(), 10);
+    let sum2 = sum;
+    sum.extend((1..=5).rev());
+    assert_eq!(sum.into_inner(), 25);
+    eprintln!("{:?}", sum2);
+    assert_eq!(sum2.into_inner(), 10);
+    Ok(())
+}
+
+/// Test FnMut closure with auxiliary state
+#[test]
+fn test_fnmut_state() -> Result<()> {
+    let mut seen = std::collections::HashSet::new();
+    let mut dedup = DynFolderMut::<Vec<String>, String, _>::new(vec![], |v, i| {
+        if seen.insert(i.clone()) {
+            v.push(i);
+        }
+    });
+    dedup.extend(["a", "b", "a", "c", "b"].iter().map(|s| s.to_string()));
+    assert_eq!(dedup.as_ref(), &vec!["a", "b", "c"]);
+    assert_eq!(dedup.into_inner().len(), 3);
+    assert_eq!(seen.len(), 3);
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use std::ops::ControlFlow;
+
+use anyhow::Result;
+
+fn find_sentinel(found: Option<usize>, item: usize) -> ControlFlow<Option<usize>, Option<usize>> {
+    if item == 0 {
+        ControlFlow::Break(found)
+    } else {
+        ControlFlow::Continue(Some(found.unwrap_or(0) + item))
+    }
+}
+
+/// Test folding without reaching a terminal state
+#[test]
+fn test_no_break() -> Result<()> {
+    let mut sum = DynFolderUntil::new(None, find_sentinel);
+    sum.fold(3);
+    sum.extend(vec![4, 5]);
+    assert!(!sum.is_done());
+    assert_eq!(sum.as_ref(), &Some(12));
+    eprintln!("{:?}", sum);
+    assert_eq!(sum.into_inner(), Some(12));
+    Ok(())
+}
+
+/// Test that extend stops consuming the iterator at the terminal state
+#[test]
+fn test_break() -> Result<()> {
+    let mut sum = DynFolderUntil::new(None, find_sentinel);
+    let mut items = vec![1, 2, 0, 4].into_iter();
+    sum.extend(&mut items);
+    assert!(sum.is_done());
+    assert_eq!(items.collect::<Vec<_>>(), vec![4]);
+    sum.fold(10);
+    sum.extend(vec![20]);
+    assert_eq!(sum.into_inner(), Some(3));
+    Ok(())
+}
+
+/// Test a terminal state reached by the first item
+#[test]
+fn test_break_first() -> Result<()> {
+    let mut sum = DynFolderUntil::new(None, find_sentinel);
+    sum.fold(0);
+    assert!(sum.is_done());
+    assert_eq!(sum.into_inner(), None);
+    Ok(())
+}
@@ -12,7 +12,7 @@ fn test_builtin_sum_usize() -> Result<()> {
     let mut sum = DynFolder::<usize, u16, _>::new(0_usize, usize_add_u16);
     sum.fold(10);
     assert_eq!(*sum.as_ref(), 10);
-    let sum2 = sum.clone();
+    let sum2 = sum;
     sum.extend((1..=5).rev());
     assert_eq!(sum.into_inner(), 25);
     eprintln!("{:?}", sum2);
@@ -69,3 +69,185 @@ fn folder(mut inner: Vec<String>, item: String) -> Vec<String> {
     inner.push(item);
     inner
 }
+
+/// Test that a panicking folding function poisons the folder
+#[test]
+fn test_panicking_fold() -> Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    let f = |mut a: String, i: &str| {
+        if i.is_empty() {
+            panic!("empty item");
+        }
+        a.push_str(i);
+        a
+    };
+    let mut concat = DynFolder::<String, &str, _>::new(String::new(), f);
+    concat.extend(vec!["a", "b"]);
+    assert!(!concat.is_poisoned());
+    assert!(catch_unwind(AssertUnwindSafe(|| concat.fold(""))).is_err());
+    assert!(concat.is_poisoned());
+    assert!(format!("{:?}", concat).contains("<poisoned>"));
+    assert!(catch_unwind(AssertUnwindSafe(|| concat.fold("c"))).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| concat.as_ref().len())).is_err());
+    assert!(catch_unwind(AssertUnwindSafe(|| concat.into_inner())).is_err());
+    Ok(())
+}
+
+/// Test that a folding function panicking in the middle of extend poisons the folder, and
+/// that reset_with recovers it
+#[test]
+fn test_panicking_extend() -> Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    let f = |mut a: String, i: &str| {
+        assert!(!i.is_empty(), "empty item");
+        a.push_str(i);
+        a
+    };
+    let mut concat = DynFolder::<String, &str, _>::new(String::new(), f);
+    let mut iter = vec!["a", "", "b"].into_iter();
+    assert!(catch_unwind(AssertUnwindSafe(|| concat.extend(&mut iter))).is_err());
+    assert!(concat.is_poisoned());
+    assert_eq!(concat.count(), 1);
+    assert_eq!(iter.next(), Some("b"));
+    assert_eq!(concat.reset_with(String::from("x")), None);
+    assert!(!concat.is_poisoned());
+    assert_eq!(concat.peek(), "x");
+    concat.extend(vec!["y", "z"]);
+    assert_eq!(
+        (concat.count(), concat.into_inner()),
+        (2, String::from("xyz"))
+    );
+    Ok(())
+}
+
+/// Test map_inner and swapping the folding function with into_parts/from_parts
+#[test]
+fn test_parts() -> Result<()> {
+    let mut sum = DynFolder::<u32, u32, _>::new(0, |a: u32, i: u32| a + i);
+    sum.extend(1..=4);
+    sum.map_inner(|a| a * 10);
+    assert_eq!(*sum.as_ref(), 100);
+    let (output, func) = sum.into_parts();
+    assert_eq!(output, 100);
+    assert_eq!(func(output, 5), 105);
+    let mut product = DynFolder::from_parts(output, |a: u32, i: u32| a * i);
+    product.extend(vec![2, 3]);
+    assert_eq!(product.into_inner(), 600);
+    let f = usize_add_u16 as fn(usize, u16) -> usize;
+    let mut sum = DynFolder::from_parts(1_usize, f);
+    sum.fold(2);
+    let (output, func) = sum.into_parts();
+    let sum = DynFolder::from_parts(output + 1, func);
+    assert_eq!(sum.into_inner(), 4);
+    Ok(())
+}
+
+/// Test folding items behind refs
+#[test]
+fn test_fold_ref() -> Result<()> {
+    let words = ["a".to_string(), "bc".to_string(), "def".to_string()];
+    let mut concat = DynFolder::new(String::new(), |mut a: String, i: String| {
+        a.push_str(&i);
+        a
+    });
+    concat.fold_ref(&words[0]);
+    concat.extend(&words[1..]);
+    assert_eq!(concat.into_inner(), "abcdef");
+    assert_eq!(words.len(), 3);
+    Ok(())
+}
+
+/// Test counting the items folded in
+#[test]
+fn test_count() -> Result<()> {
+    let mut sum = DynFolder::<usize, u16, _>::new(7, usize_add_u16);
+    assert!(sum.is_empty());
+    sum.fold(0);
+    sum.extend(&[1, 2]);
+    sum.extend(3..5);
+    assert_eq!(sum.count(), 5);
+    assert!(!sum.is_empty());
+    let sum = DynFolder::from_state(sum.into_state(), usize_add_u16);
+    assert_eq!((sum.count(), sum.into_inner()), (0, 17));
+    Ok(())
+}
+
+/// Test the observer, which gets the output after every fold
+#[test]
+fn test_observer() -> Result<()> {
+    let mut partials = vec![];
+    let mut sum = DynFolder::<usize, u16, _>::new(7, usize_add_u16)
+        .with_observer(|sum: &usize| partials.push(*sum));
+    sum.fold(1);
+    sum.extend(&[2, 3]);
+    sum.map_inner(|sum| sum * 2);
+    sum.fold_item(4);
+    assert_eq!(sum.into_inner(), 30);
+    assert_eq!(partials, vec![8, 10, 13, 30]);
+    Ok(())
+}
+
+/// Test reusing a DynFolder across batches with reset_with
+#[test]
+fn test_reset_with() -> Result<()> {
+    let mut sum = DynFolder::<usize, u16, _>::new(0, usize_add_u16);
+    sum.extend(1..=3);
+    assert_eq!(sum.reset_with(10), Some(6));
+    assert!(sum.is_empty());
+    sum.extend(&[4, 5]);
+    assert_eq!((sum.count(), sum.into_inner()), (2, 19));
+    Ok(())
+}
+
+/// Test storing boxed folders with different closures together
+#[cfg(feature = "std")]
+#[test]
+fn test_boxed() -> Result<()> {
+    let offset = 100;
+    let mut folders: Vec<BoxedDynFolder<usize, u16>> = vec![
+        DynFolder::boxed(0, usize_add_u16),
+        DynFolder::boxed(0, |a, b| a.max(b as usize)),
+        DynFolder::boxed(0, move |a, b| a + b as usize + offset),
+    ];
+    for folder in &mut folders {
+        folder.fold(3);
+        folder.extend(&[1, 2]);
+    }
+    let handle = std::thread::spawn(move || {
+        folders
+            .into_iter()
+            .map(|f| f.into_inner())
+            .collect::<Vec<_>>()
+    });
+    assert_eq!(handle.join().unwrap(), vec![6, 3, 306]);
+    let sum = BoxedSyncDynFolder::<usize, u16>::boxed_sync(1, usize_add_u16);
+    let sum = std::sync::Arc::new(sum);
+    let shared = std::sync::Arc::clone(&sum);
+    assert_eq!(
+        std::thread::spawn(move || *(*shared).as_ref())
+            .join()
+            .unwrap(),
+        1
+    );
+    Ok(())
+}
+
+/// Test folding items in with +=
+#[test]
+fn test_add_assign() -> Result<()> {
+    let mut sum = DynFolder::<usize, u16, _>::new(0, usize_add_u16);
+    sum += 3;
+    sum += 4;
+    assert_eq!((sum.count(), sum.into_inner()), (2, 7));
+    Ok(())
+}
+
+/// Test the const constructor
+#[test]
+fn test_const() -> Result<()> {
+    const SUM: DynFolder<usize, u16, fn(usize, u16) -> usize> = DynFolder::new(1, usize_add_u16);
+    let mut sum = SUM;
+    sum.extend(&[2, 3]);
+    assert_eq!(sum.into_inner(), 6);
+    Ok(())
+}
@@ -12,7 +12,7 @@ fn test_builtin_sum_usize() -> Result<()> {
     let mut sum = DynReduce::<usize, _>::new(usize_add_usize);
     sum.reduce(10);
     assert_eq!(sum.as_ref(), Some(&10));
-    let sum2 = sum.clone();
+    let sum2 = sum;
     sum.extend((1..=5).rev());
     assert_eq!(sum.into_inner(), Some(25));
     eprintln!("{:?}", sum2);
@@ -56,7 +56,7 @@ fn test_string() -> Result<()> {
     let mut autofolder = DynReduce::<String, _>::new(concat);
     let f = |v| format!("{}", v);
     autofolder.extend((1..=5).map(f));
-    assert_eq!(autofolder.as_ref().clone(), Some(&format!("1 2 3 4 5")));
+    assert_eq!(autofolder.as_ref(), Some(&"1 2 3 4 5".to_string()));
     autofolder.extend((6..10).map(f).rev());
     assert_eq!(autofolder.into_inner().unwrap(), "1 2 3 4 5 9 8 7 6");
     Ok(())
@@ -72,7 +72,102 @@ fn concat(lhs: String, rhs: String) -> String {
 #[test]
 fn test_empty_vec() -> Result<()> {
     let mut autofolder = DynReduce::<String, _>::new(concat);
-    autofolder.extend(vec![].into_iter());
+    autofolder.extend(Vec::<String>::new());
     assert_eq!(autofolder.into_inner(), None);
     Ok(())
 }
+
+/// Test merging partial results, keeping the order of the items
+#[test]
+fn test_merge() -> Result<()> {
+    let mut first = DynReduce::<String, _>::new(concat);
+    first.extend(vec!["a".to_string(), "b".to_string()]);
+    let mut second = DynReduce::<String, _>::new(concat);
+    second.reduce("c".to_string());
+    first.merge_from(second);
+    assert_eq!(first.as_ref().map(String::as_str), Some("a b c"));
+    let empty = DynReduce::<String, _>::new(concat);
+    let merged = empty.merge(first);
+    assert_eq!(merged.into_inner(), Some("a b c".to_string()));
+    Ok(())
+}
+
+/// Test reducing items behind refs
+#[test]
+fn test_reduce_ref() -> Result<()> {
+    let words = ["a".to_string(), "bc".to_string(), "def".to_string()];
+    let mut autofolder = DynReduce::new(concat);
+    autofolder.reduce_ref(&words[2]);
+    autofolder.extend(&words[..2]);
+    assert_eq!(autofolder.into_inner().as_deref(), Some("def a bc"));
+    Ok(())
+}
+
+/// Test counting the items reduced in, across merges and state restores
+#[test]
+fn test_count() -> Result<()> {
+    let mut sum = DynReduce::new(usize_add_usize);
+    assert!(sum.is_empty());
+    sum.extend(1..=3);
+    let mut other = DynReduce::new(usize_add_usize);
+    other.extend(vec![4, 5]);
+    sum.merge_from(other);
+    sum.merge_from(DynReduce::new(usize_add_usize));
+    assert_eq!(sum.count(), 5);
+    let sum = DynReduce::from_state(sum.into_state(), usize_add_usize);
+    assert_eq!((sum.count(), sum.into_inner()), (1, Some(15)));
+    Ok(())
+}
+
+/// Test that a panicking reduce function leaves the reducer empty
+#[test]
+fn test_panicking_reduce() -> Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    let mut sum = DynReduce::new(|a: usize, b: usize| a.checked_add(b).expect("overflow"));
+    sum.extend(1..=3);
+    assert!(catch_unwind(AssertUnwindSafe(|| sum.reduce(usize::MAX))).is_err());
+    assert_eq!((sum.count(), sum.as_ref()), (0, None));
+    sum.extend(4..=5);
+    assert_eq!((sum.count(), sum.into_inner()), (2, Some(9)));
+    Ok(())
+}
+
+/// Test the observer, which gets the reduced value after every reduce
+#[test]
+fn test_observer() -> Result<()> {
+    let mut calls = 0;
+    let mut sum = DynReduce::new(usize_add_usize).with_observer(|_: &usize| calls += 1);
+    sum.extend(1..=3);
+    sum.merge_from(DynReduce::new(usize_add_usize).with_observer(|_: &usize| {}));
+    assert_eq!(sum.into_inner(), Some(6));
+    assert_eq!(calls, 3);
+    Ok(())
+}
+
+/// Test reusing a DynReduce across batches with reset, take and replace
+#[test]
+fn test_reset() -> Result<()> {
+    let mut sum = DynReduce::new(usize_add_usize);
+    sum.extend(1..=3);
+    assert_eq!(sum.take(), Some(6));
+    assert!(sum.is_empty());
+    sum.extend(4..=5);
+    assert_eq!(sum.replace(1), Some(9));
+    sum.reduce(2);
+    assert_eq!((sum.count(), sum.as_ref()), (2, Some(&3)));
+    sum.reset();
+    assert_eq!((sum.count(), sum.into_inner()), (0, None));
+    Ok(())
+}
+
+/// Test the fallbacks of into_inner for empty autofolders
+#[test]
+fn test_into_inner_or() -> Result<()> {
+    let sum = DynReduce::new(usize_add_usize);
+    assert_eq!(sum.into_inner_or(7), 7);
+    let mut sum = DynReduce::new(usize_add_usize);
+    sum.extend(1..=3);
+    assert_eq!(sum.into_inner_or_else(|| 7), 6);
+    assert_eq!(DynReduce::new(usize_add_usize).into_inner_or_default(), 0);
+    Ok(())
+}
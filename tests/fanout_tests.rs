@@ -0,0 +1,67 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod fanout_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test broadcasting to autofolders registered by reference
+    #[test]
+    fn test_by_reference() -> Result<()> {
+        let mut minmax = MinMax::default();
+        let mut count = DynFolder::new(0_usize, |a, _: u32| a + 1);
+        let mut fanout = FanOut::new().with(&mut minmax).with(&mut count);
+        assert_eq!(fanout.len(), 2);
+        fanout.fold(7);
+        fanout.extend(vec![3, 9]);
+        fanout.extend(&[5]);
+        drop(fanout);
+        assert_eq!(minmax.to_inner(), Some((3, 9)));
+        assert_eq!(count.into_inner(), 4);
+        Ok(())
+    }
+
+    /// Test a set of autofolders decided at runtime
+    #[test]
+    fn test_runtime_set() -> Result<()> {
+        let wanted = ["min", "max"];
+        let mut min = Min::default();
+        let mut max = Max::default();
+        let mut distinct = DistinctSorted::default();
+        let mut fanout = FanOut::default();
+        if wanted.contains(&"min") {
+            fanout.push(&mut min);
+        }
+        if wanted.contains(&"max") {
+            fanout.push(&mut max);
+        }
+        if wanted.contains(&"distinct") {
+            fanout.push(&mut distinct);
+        }
+        fanout.extend(vec![String::from("b"), String::from("a")]);
+        drop(fanout);
+        assert_eq!(min.into_inner().as_deref(), Some("a"));
+        assert_eq!(max.into_inner().as_deref(), Some("b"));
+        assert!(distinct.is_empty());
+        Ok(())
+    }
+
+    /// Test nested and empty fanouts
+    #[test]
+    fn test_nested() -> Result<()> {
+        let mut sum = DynFolder::new(0, |a, i| a + i);
+        let mut empty = FanOut::<u32>::new();
+        empty.extend(1..=3);
+        assert!(empty.is_empty());
+        let inner = FanOut::new().with(&mut sum);
+        let mut outer = FanOut::new().with(inner).with(empty);
+        outer.extend(1..=3);
+        eprintln!("{:?}", outer);
+        drop(outer);
+        assert_eq!(sum.into_inner(), 6);
+        Ok(())
+    }
+}
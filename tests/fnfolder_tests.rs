@@ -0,0 +1,51 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+fn usize_add_u16(a: usize, b: u16) -> usize {
+    a + b as usize
+}
+
+autofolder_fn!(struct Sum: fn(usize, u16) = usize_add_u16);
+autofolder_fn!(
+    /// Keeps the largest item.
+    pub struct Largest: fn(u32, u32) = std::cmp::max
+);
+
+/// Test folding and collecting with builtin types
+#[test]
+fn test_fnfolder() -> Result<()> {
+    let mut sum = FnFolder::<usize, u16, Sum>::new(7);
+    assert!(sum.is_empty());
+    sum.fold(3);
+    sum += 2;
+    sum.extend(&[1, 2]);
+    assert_eq!((sum.count(), *sum.as_ref()), (4, 15));
+    assert_eq!(sum.reset_with(0), Some(15));
+    let sum = (1..=4).collect::<FnFolder<usize, u16, Sum>>();
+    assert_eq!(sum.into_inner(), 10);
+    let max = [4, 9, 2].iter().collect::<FnFolder<_, _, Largest>>();
+    assert_eq!(max.into_inner(), 9);
+    Ok(())
+}
+
+/// Test that a panicking folding function poisons the folder
+#[test]
+fn test_panicking_fold() -> Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    autofolder_fn!(struct Checked: fn(u8, u8) = |a, b| a.checked_add(b).expect("overflow"));
+    let mut sum = FnFolder::<u8, u8, Checked>::default();
+    sum.extend(vec![100, 100]);
+    assert!(!sum.is_poisoned());
+    assert!(catch_unwind(AssertUnwindSafe(|| sum.fold(100))).is_err());
+    assert!(sum.is_poisoned());
+    assert!(format!("{:?}", sum).contains("<poisoned>"));
+    assert_eq!(sum.reset_with(1), None);
+    sum.fold(2);
+    assert_eq!((sum.count(), sum.into_inner()), (1, 3));
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod folding_tests {
+    use autofolder::*;
+
+    use std::collections::BTreeSet;
+
+    use anyhow::Result;
+
+    fn fold_all<F, It>(mut folder: F, iter: It) -> F::Output
+    where
+        F: Folding,
+        It: IntoIterator<Item = F::Item>,
+    {
+        for item in iter {
+            folder.fold_item(item);
+        }
+        folder.finish()
+    }
+
+    /// Test the same generic function with different autofolders
+    #[test]
+    fn test_generic_fold() -> Result<()> {
+        assert_eq!(fold_all(Min::default(), vec![3, 1, 2]), Some(1));
+        assert_eq!(fold_all(MinMax::default(), vec![3, 1, 2]), Some((1, 3)));
+        assert_eq!(fold_all(DynFolder::new(0, |a, i| a + i), vec![3, 1, 2]), 6);
+        assert_eq!(
+            fold_all(DynReduce::new(|a, i| a * i), vec![3, 1, 2]),
+            Some(6)
+        );
+        assert_eq!(
+            fold_all(DistinctSorted::default(), vec![3, 1, 3]),
+            BTreeSet::from([1, 3])
+        );
+        assert_eq!(fold_all(Max::<u32>::default(), vec![]), None);
+        Ok(())
+    }
+
+    /// Test peeking at the running output
+    #[test]
+    fn test_peek() -> Result<()> {
+        let mut max = Max::default();
+        assert_eq!(Folding::peek(&max), None);
+        max.fold_item(5);
+        max.fold_item(7);
+        assert_eq!(Folding::peek(&max), Some(&7));
+        let mut heap = HeapFold::with_bound(2);
+        heap.fold_item(5);
+        heap.fold_item(1);
+        heap.fold_item(3);
+        assert_eq!(Folding::peek(&heap), Some(&3));
+        Ok(())
+    }
+
+    /// Test autofolders whose items are tuples
+    #[test]
+    fn test_tuple_items() -> Result<()> {
+        let top = fold_all(TopKScored::new(2), vec![("a", 1.0), ("b", 3.0), ("c", 2.0)]);
+        assert_eq!(top, vec![("b", 3.0), ("c", 2.0)]);
+        Ok(())
+    }
+}
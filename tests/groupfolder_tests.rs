@@ -0,0 +1,50 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod groupfolder_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test grouping with default autofolders
+    #[test]
+    fn test_groupfolder() -> Result<()> {
+        let mut groups = GroupFolder::<char, MinMax<u32>>::default();
+        assert!(groups.is_empty());
+        groups.fold(('a', 5));
+        groups.extend(vec![('b', 3), ('a', 9), ('a', 1)]);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups.get(&'a').and_then(|m| m.as_ref()), Some((&1, &9)));
+        assert!(groups.get(&'c').is_none());
+        let mut keys = groups.iter().map(|(k, _)| *k).collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, vec!['a', 'b']);
+        let output = groups.into_inner();
+        assert_eq!(output[&'b'], Some((3, 3)));
+        let collect = vec![(1, 2.0), (1, 4.0), (2, 1.0)]
+            .into_iter()
+            .collect::<GroupFolder<_, Mean<f64>>>();
+        assert_eq!(collect.into_inner()[&1], Some(3.0));
+        Ok(())
+    }
+
+    /// Test grouping with a factory closure
+    #[test]
+    fn test_factory() -> Result<()> {
+        let mut words = GroupFolder::with_factory(|| TopK::new(2));
+        words.extend(
+            "the quick brown fox jumps over the lazy dog"
+                .split(' ')
+                .map(|w| (w.len(), w)),
+        );
+        for (len, top) in &words {
+            assert!(top.len() <= 2, "{}", len);
+        }
+        let words = words.into_inner();
+        assert_eq!(words[&5], vec!["jumps", "quick"]);
+        assert_eq!(words[&3], vec!["the", "the"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod heapfold_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test extend, collect, peek
+    #[test]
+    fn test_heapfold() -> Result<()> {
+        let mut heap = HeapFold::<usize>::default();
+        assert!(heap.is_empty());
+        assert_eq!(heap.peek(), None);
+        heap.fold(3);
+        assert_eq!(heap.peek(), Some(&3));
+        heap.extend((1..=5).rev());
+        assert_eq!(heap.peek(), Some(&5));
+        assert_eq!(heap.len(), 6);
+        let clone = heap.clone();
+        heap.extend([7, 6].iter());
+        assert_eq!(heap.into_sorted_vec(), vec![1, 2, 3, 3, 4, 5, 6, 7]);
+        assert_eq!(clone.into_inner().into_sorted_vec(), vec![1, 2, 3, 3, 4, 5]);
+        let collect = (7..=9).rev().collect::<HeapFold<_>>();
+        assert_eq!(collect.into_sorted_vec(), vec![7, 8, 9]);
+        Ok(())
+    }
+
+    /// Test bounded heap
+    #[test]
+    fn test_bounded() -> Result<()> {
+        let mut heap = HeapFold::<String>::with_bound(3);
+        heap.extend(["d", "b", "e", "a"].iter().map(|s| s.to_string()));
+        assert_eq!(heap.peek().map(String::as_str), Some("d"));
+        let vec = ["c".to_string(), "z".to_string()];
+        heap.extend(vec.iter());
+        assert_eq!(heap.bound(), Some(3));
+        assert_eq!(heap.into_sorted_vec(), vec!["a", "b", "c"]);
+        let mut empty = HeapFold::<usize>::with_bound(0);
+        empty.extend(1..=5);
+        empty.extend(&[1, 2]);
+        assert!(empty.is_empty());
+        let mut huge = HeapFold::<usize>::with_bound(usize::MAX);
+        huge.extend(1..=5);
+        assert_eq!(huge.into_sorted_vec(), vec![1, 2, 3, 4, 5]);
+        Ok(())
+    }
+
+    /// Test merge
+    #[test]
+    fn test_merge() -> Result<()> {
+        let heap1 = (1..=3).collect::<HeapFold<usize>>();
+        let heap2 = (4..=6).collect::<HeapFold<usize>>();
+        let merged = heap1.merge(heap2);
+        assert_eq!(merged.peek(), Some(&6));
+        let mut bounded = HeapFold::with_bound(2);
+        bounded.merge_from(merged);
+        assert_eq!(bounded.into_sorted_vec(), vec![1, 2]);
+        Ok(())
+    }
+}
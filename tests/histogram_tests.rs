@@ -0,0 +1,91 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod histogram_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test bins, edges, underflow and overflow
+    #[test]
+    fn test_histogram() -> Result<()> {
+        let mut histogram = Histogram::new(vec!['a', 'h', 'p', 'x']);
+        histogram.extend("the quick brown fox!".chars());
+        histogram.extend(&['a', 'x']);
+        assert_eq!(histogram.edges(), &['a', 'h', 'p', 'x']);
+        assert_eq!(histogram.counts(), &[5, 6, 5]);
+        assert_eq!(histogram.underflow(), 4);
+        assert_eq!(histogram.overflow(), 2);
+        assert_eq!(histogram.total(), 22);
+        assert_eq!(histogram.into_inner(), vec![5, 6, 5]);
+        Ok(())
+    }
+
+    /// Test uniform bins and values that can't be compared
+    #[test]
+    fn test_uniform() -> Result<()> {
+        let histogram = Histogram::uniform(0.0, 1.0, 4).with_items(vec![
+            0.0,
+            0.1,
+            0.25,
+            0.6,
+            0.99,
+            1.0,
+            -0.5,
+            f64::NAN,
+        ]);
+        assert_eq!(histogram.edges(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(histogram.counts(), &[2, 1, 1, 1]);
+        assert_eq!(histogram.underflow(), 1);
+        assert_eq!(histogram.overflow(), 1);
+        assert_eq!(histogram.unordered(), 1);
+        let result = std::panic::catch_unwind(|| Histogram::new(vec![1, 1]));
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    /// Test merging
+    #[test]
+    fn test_merge() -> Result<()> {
+        let edges = vec![0, 10, 20];
+        let a = Histogram::new(edges.clone()).with_items(vec![1, 11, 21]);
+        let b = Histogram::new(edges.clone()).with_items(vec![2, 3, -1]);
+        let merged = a.merge(b);
+        assert_eq!(merged.counts(), &[3, 1]);
+        assert_eq!(merged.underflow(), 1);
+        assert_eq!(merged.overflow(), 1);
+        let result = std::panic::catch_unwind(|| {
+            Histogram::new(edges).merge(Histogram::new(vec![0, 10, 30]))
+        });
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    /// Test that deserialization validates the state
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() -> Result<()> {
+        let histogram = Histogram::new(vec![0, 1, 2]).with_items(vec![0, 1, 5]);
+        let json = serde_json::to_string(&histogram)?;
+        let restored = serde_json::from_str::<Histogram<i32>>(&json)?;
+        assert_eq!(restored.counts(), histogram.counts());
+        let state = |edges, counts| {
+            format!(
+                r#"{{"edges":{},"counts":{},"underflow":0,"overflow":0,"unordered":0}}"#,
+                edges, counts
+            )
+        };
+        for (edges, counts) in [
+            ("[]", "[]"),
+            ("[0]", "[]"),
+            ("[1,0]", "[0]"),
+            ("[0,1,2]", "[]"),
+        ] {
+            let json = state(edges, counts);
+            assert!(serde_json::from_str::<Histogram<i32>>(&json).is_err());
+        }
+        Ok(())
+    }
+}
@@ -60,3 +60,138 @@ fn test_newtype_vec() -> Result<()> {
     assert_eq!(autofolder.into_inner().0, vec!["9", "8", "7", "6"]);
     Ok(())
 }
+
+/// Test that a panicking folding function poisons the folder
+#[test]
+fn test_panicking_fold() -> Result<()> {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    #[derive(Debug, Clone)]
+    pub struct VecString(Vec<String>);
+    pub type StringJoiner = ImplFolder<VecString, String>;
+    autofolder_impl_foldertrait!(|accum: VecString, item: String| {
+        assert!(!item.is_empty(), "empty item");
+        accum.0.push(item);
+        accum
+    });
+    let mut autofolder = StringJoiner::from(VecString(vec![]));
+    autofolder.fold("a".to_string());
+    assert!(!autofolder.is_poisoned());
+    assert!(catch_unwind(AssertUnwindSafe(|| autofolder.fold(String::new()))).is_err());
+    assert!(autofolder.is_poisoned());
+    assert!(format!("{:?}", autofolder).contains("<poisoned>"));
+    assert!(catch_unwind(AssertUnwindSafe(|| autofolder.as_ref().0.len())).is_err());
+    let mut recovered = autofolder.clone();
+    assert!(catch_unwind(AssertUnwindSafe(|| autofolder.into_inner())).is_err());
+    assert!(recovered.reset_with(VecString(vec![])).is_none());
+    recovered.fold("b".to_string());
+    assert_eq!(recovered.into_inner().0, vec!["b"]);
+    Ok(())
+}
+
+/// Test collecting from an iterator of results
+#[test]
+fn test_collect_result() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Usize(usize);
+    pub type Adder = ImplFolder<Usize, u16>;
+    impl FolderTrait<Usize, u16> for Adder {
+        fn fold(accum: Usize, item: u16) -> Usize {
+            Usize(accum.0 + item as usize)
+        }
+    }
+    let sum = vec!["1", "2", "3"]
+        .into_iter()
+        .map(str::parse::<u16>)
+        .collect::<Result<Adder, _>>()?;
+    assert_eq!(sum.into_inner(), Usize(6));
+    let sum = vec!["1", "-1"]
+        .into_iter()
+        .map(str::parse::<u16>)
+        .collect::<Result<Adder, _>>();
+    assert!(sum.is_err());
+    Ok(())
+}
+
+/// Test the macro with generics, references and where-clauses
+#[test]
+fn test_macro_generics() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Sum<T>(T);
+    autofolder_impl_foldertrait!(<T: std::ops::Add<Output = T>> |accum: Sum<T>, item: T,| {
+        Sum(accum.0 + item)
+    });
+    let sum = (1..=4).collect::<ImplFolder<Sum<u64>, u64>>();
+    assert_eq!(sum.into_inner(), Sum(10));
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Lengths(Vec<usize>);
+    autofolder_impl_foldertrait!(<'a, T: AsRef<Vec<Vec<u8>>>> |accum: Lengths, item: &'a T|
+        where T: ?Sized,
+    {
+        accum.0.push(item.as_ref().len());
+        accum
+    });
+    let items = [vec![vec![1_u8]], vec![], vec![vec![2], vec![3]]];
+    let lengths = items.iter().collect::<ImplFolder<Lengths, _>>();
+    assert_eq!(lengths.into_inner(), Lengths(vec![1, 0, 2]));
+    Ok(())
+}
+
+/// Test folding items behind refs
+#[test]
+fn test_fold_ref() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Lengths(Vec<usize>);
+    autofolder_impl_foldertrait!(|accum: Lengths, item: String| {
+        accum.0.push(item.len());
+        accum
+    });
+    let words = ["a".to_string(), "bc".to_string(), "def".to_string()];
+    let mut lengths = ImplFolder::<Lengths, String>::default();
+    lengths.fold_ref(&words[2]);
+    lengths.extend(words.iter().take(2));
+    assert_eq!(lengths.into_inner(), Lengths(vec![3, 1, 2]));
+    Ok(())
+}
+
+/// Test counting the items folded in
+#[test]
+fn test_count() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Usize(usize);
+    autofolder_impl_foldertrait!(|accum: Usize, item: u8| { Usize(accum.0 + item as usize) });
+    let mut sum = ImplFolder::<Usize, u8>::new(Usize(10));
+    assert!(sum.is_empty());
+    sum.fold(1);
+    sum.extend(2..=4);
+    assert_eq!(sum.count(), 4);
+    let sum = (1..=3).collect::<ImplFolder<Usize, u8>>();
+    assert_eq!(sum.count(), 3);
+    Ok(())
+}
+
+/// Test reusing an ImplFolder across batches with reset_with
+#[test]
+fn test_reset_with() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Total(u64);
+    autofolder_impl_foldertrait!(|accum: Total, item: u8| { Total(accum.0 + item as u64) });
+    let mut total = (1..=3).collect::<ImplFolder<Total, u8>>();
+    assert_eq!(total.reset_with(Total(10)), Some(Total(6)));
+    assert!(total.is_empty());
+    total.fold(4);
+    assert_eq!((total.count(), total.into_inner()), (1, Total(14)));
+    Ok(())
+}
+
+/// Test folding items in with +=
+#[test]
+fn test_add_assign() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Total(u64);
+    autofolder_impl_foldertrait!(|accum: Total, item: u8| { Total(accum.0 + item as u64) });
+    let mut total = ImplFolder::<Total, u8>::default();
+    total += 4;
+    total += 5;
+    assert_eq!(total.into_inner(), Total(9));
+    Ok(())
+}
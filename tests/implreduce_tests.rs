@@ -59,7 +59,7 @@ fn test_newtype_vec() -> Result<()> {
     });
     let mut autofolder = StringMax::default();
     autofolder.extend((6..=10).map(|i| MyString(format!("{}", i))).rev());
-    assert_eq!(autofolder.as_ref().clone().unwrap().0, "10");
+    assert_eq!(autofolder.as_ref().unwrap().0, "10");
     assert_eq!(autofolder.into_inner().unwrap().0, "10");
     Ok(())
 }
@@ -79,3 +79,149 @@ fn test_empty() -> Result<()> {
     assert_eq!(sum.into_inner(), None);
     Ok(())
 }
+
+/// Test merging partial results
+#[test]
+fn test_merge() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Usize(usize);
+    pub type Adder = ImplReduce<Usize>;
+    impl ReduceTrait<Usize> for Adder {
+        fn reduce(lhs: Usize, rhs: Usize) -> Usize {
+            Usize(lhs.0 + rhs.0)
+        }
+    }
+    let shards = [vec![1, 2, 3], vec![], vec![4, 5]];
+    let sum = shards
+        .iter()
+        .map(|s| s.iter().copied().map(Usize).collect::<Adder>())
+        .fold(Adder::default(), Adder::merge);
+    assert_eq!(sum.into_inner(), Some(Usize(15)));
+    let mut sum = Adder::default();
+    sum.merge_from(Adder::default());
+    assert_eq!(sum.into_inner(), None);
+    Ok(())
+}
+
+/// Test collecting from an iterator of results
+#[test]
+fn test_collect_result() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct Usize(usize);
+    pub type Adder = ImplReduce<Usize>;
+    impl ReduceTrait<Usize> for Adder {
+        fn reduce(lhs: Usize, rhs: Usize) -> Usize {
+            Usize(lhs.0 + rhs.0)
+        }
+    }
+    let sum = vec!["1", "2", "3"]
+        .into_iter()
+        .map(|s| s.parse().map(Usize))
+        .collect::<Result<Adder, std::num::ParseIntError>>()?;
+    assert_eq!(sum.into_inner(), Some(Usize(6)));
+    let sum = vec!["1", "x"]
+        .into_iter()
+        .map(|s| s.parse().map(Usize))
+        .collect::<Result<Adder, std::num::ParseIntError>>();
+    assert!(sum.is_err());
+    Ok(())
+}
+
+/// Test the macro with generics, lifetimes and where-clauses
+#[test]
+fn test_macro_generics() -> Result<()> {
+    #[derive(PartialEq, Eq, Debug)]
+    pub struct Longest<'a, T>(&'a [T]);
+    autofolder_impl_reducetrait!(<'a, T> |lhs, rhs,| -> Longest<'a, T> where T: Ord, {
+        if rhs.0.len() > lhs.0.len() || (rhs.0.len() == lhs.0.len() && rhs.0 > lhs.0) {
+            rhs
+        } else {
+            lhs
+        }
+    });
+    let data = [3, 1, 4, 1, 5, 9, 2, 6];
+    let longest = data
+        .chunks(3)
+        .chain(data.windows(3))
+        .map(Longest)
+        .collect::<ImplReduce<_>>();
+    assert_eq!(longest.into_inner(), Some(Longest(&[9, 2, 6][..])));
+    #[derive(PartialEq, Eq, Debug)]
+    pub struct Max<T>(Option<T>);
+    autofolder_impl_reducetrait!(<T: Ord> |lhs, rhs| -> Max<T> { Max(lhs.0.max(rhs.0)) });
+    let max = vec![Max(None), Max(Some(2)), Max(Some(1))]
+        .into_iter()
+        .collect::<ImplReduce<_>>();
+    assert_eq!(max.into_inner(), Some(Max(Some(2))));
+    Ok(())
+}
+
+/// Test reducing and collecting items behind refs
+#[test]
+fn test_reduce_ref() -> Result<()> {
+    #[derive(PartialEq, Eq, Debug, Clone)]
+    pub struct MyString(String);
+    autofolder_impl_reducetrait!(|lhs, rhs| -> MyString { MyString(lhs.0 + &rhs.0) });
+    let words = ["a", "bc", "def"].map(|s| MyString(s.to_string()));
+    let mut concat = words.iter().collect::<ImplReduce<_>>();
+    assert_eq!(concat.as_ref(), Some(&MyString("abcdef".to_string())));
+    concat.reduce_ref(&words[1]);
+    concat.extend(&words[..1]);
+    assert_eq!(concat.into_inner(), Some(MyString("abcdefbca".to_string())));
+    Ok(())
+}
+
+/// Test counting the items reduced in
+#[test]
+fn test_count() -> Result<()> {
+    #[derive(PartialEq, Eq, Debug, Clone)]
+    pub struct MyString(String);
+    autofolder_impl_reducetrait!(|lhs, rhs| -> MyString { MyString(lhs.0 + &rhs.0) });
+    let mut concat = ImplReduce::<MyString>::default();
+    assert!(concat.is_empty());
+    concat.extend(["a", "b"].map(|s| MyString(s.to_string())));
+    let other = ImplReduce::new(MyString("c".to_string()));
+    assert_eq!(other.count(), 1);
+    let concat = concat.merge(other);
+    assert_eq!(concat.count(), 3);
+    assert_eq!(concat.into_inner(), Some(MyString("abc".to_string())));
+    Ok(())
+}
+
+/// Test reusing an ImplReduce across batches with reset, take and replace
+#[test]
+fn test_reset() -> Result<()> {
+    #[derive(PartialEq, Eq, Debug, Clone)]
+    pub struct MyString(String);
+    autofolder_impl_reducetrait!(|lhs, rhs| -> MyString { MyString(lhs.0 + &rhs.0) });
+    let mut concat = ["a", "b"]
+        .iter()
+        .map(|s| MyString(s.to_string()))
+        .collect::<ImplReduce<_>>();
+    assert_eq!(concat.take(), Some(MyString("ab".to_string())));
+    assert!(concat.is_empty());
+    assert_eq!(concat.replace(MyString("c".to_string())), None);
+    concat.reduce(MyString("d".to_string()));
+    assert_eq!(concat.count(), 2);
+    concat.reset();
+    assert_eq!((concat.count(), concat.into_inner()), (0, None));
+    Ok(())
+}
+
+/// Test the fallbacks of into_inner for empty autofolders
+#[test]
+fn test_into_inner_or() -> Result<()> {
+    #[derive(Default, PartialEq, Eq, Debug)]
+    pub struct MyString(String);
+    autofolder_impl_reducetrait!(|lhs, rhs| -> MyString { MyString(lhs.0 + &rhs.0) });
+    let concat = ImplReduce::<MyString>::default();
+    assert_eq!(concat.into_inner_or(MyString("x".to_string())).0, "x");
+    let concat = ImplReduce::new(MyString("a".to_string()));
+    assert_eq!(
+        concat.into_inner_or_else(|| MyString("y".to_string())).0,
+        "a"
+    );
+    let concat = ImplReduce::<MyString>::default();
+    assert_eq!(concat.into_inner_or_default(), MyString::default());
+    Ok(())
+}
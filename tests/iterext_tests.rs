@@ -0,0 +1,81 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test fold_into with owned items and trait objects
+#[test]
+fn test_fold_into() -> Result<()> {
+    let mut minmax = MinMax::default();
+    assert_eq!(vec![4, 1, 7].into_iter().fold_into(&mut minmax), 3);
+    assert_eq!((0..0).fold_into(&mut minmax), 0);
+    assert_eq!(minmax.to_inner(), Some((1, 7)));
+    let mut mean = Mean::<u8>::default();
+    {
+        let folder: &mut dyn FoldItem<u8> = &mut mean;
+        assert_eq!(vec![2, 4].into_iter().fold_into(folder), 2);
+    }
+    assert_eq!(mean.into_inner(), Some(3.0));
+    Ok(())
+}
+
+/// Test tee_fold in the middle of a pipeline
+#[test]
+fn test_tee_fold() -> Result<()> {
+    let words = ["tee", "fold", "autofolder", "x"];
+    let mut longest = MaxBy::new(|s: &&str| s.len());
+    let mut count = DynFolder::new(0, |c, _: String| c + 1);
+    let upper = words
+        .iter()
+        .copied()
+        .tee_fold(&mut longest)
+        .map(str::to_uppercase)
+        .tee_fold(&mut count)
+        .take(3)
+        .collect::<Vec<_>>();
+    assert_eq!(upper, vec!["TEE", "FOLD", "AUTOFOLDER"]);
+    assert_eq!(longest.into_inner(), Some("autofolder"));
+    assert_eq!(count.into_inner(), 3);
+    let mut max = Max::default();
+    let tee = words.iter().tee_fold(&mut max);
+    assert_eq!(tee.size_hint(), (4, Some(4)));
+    assert_eq!(tee.count(), 4);
+    assert_eq!(max.into_inner(), Some(&"x"));
+    Ok(())
+}
+
+/// Test the running outputs
+#[test]
+fn test_running() -> Result<()> {
+    let items = [3, 1, 4, 1, 5];
+    let maxes = items.iter().running(Max::default()).collect::<Vec<_>>();
+    assert_eq!(
+        maxes,
+        vec![Some(&3), Some(&3), Some(&4), Some(&4), Some(&5)]
+    );
+    let mut running = items.iter().copied().running(MinMax::default());
+    assert_eq!(running.next(), Some(Some((3, 3))));
+    assert_eq!(running.next(), Some(Some((1, 3))));
+    assert_eq!(running.into_inner().to_inner(), Some((1, 3)));
+    Ok(())
+}
+
+/// Test the partial outputs given by a callback
+#[test]
+fn test_scan_with() -> Result<()> {
+    let sums = (1..=4)
+        .scan_with(DynFolder::new(0, |a, i| a + i), |s| *s.as_ref())
+        .collect::<Vec<_>>();
+    assert_eq!(sums, vec![1, 3, 6, 10]);
+    let mut scan = [2_u8, 4, 9]
+        .iter()
+        .copied()
+        .scan_with(Mean::default(), Mean::mean);
+    assert_eq!(scan.next(), Some(Some(2.0)));
+    assert_eq!(scan.by_ref().last(), Some(Some(5.0)));
+    assert_eq!(scan.into_inner().count(), 3);
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "json")]
+mod jsonmerge_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+    use serde_json::json;
+
+    /// Test object deep merge with arrays replaced
+    #[test]
+    fn test_jsonmerge_replace() -> Result<()> {
+        let mut merge = JsonMerge::default();
+        assert_eq!(merge.as_ref(), None);
+        merge.reduce(json!({"a": {"b": 1, "c": [1, 2]}, "d": "x"}));
+        merge.extend(vec![
+            json!({"a": {"c": [3], "e": null}}),
+            json!({"d": {"f": true}}),
+        ]);
+        assert_eq!(
+            merge.into_inner(),
+            Some(json!({"a": {"b": 1, "c": [3], "e": null}, "d": {"f": true}}))
+        );
+        Ok(())
+    }
+
+    /// Test array concatenation, merge
+    #[test]
+    fn test_jsonmerge_concat() -> Result<()> {
+        let merge = JsonMerge::new(json!({"tags": ["a"], "n": 1})).with_arrays(ArrayMerge::Concat);
+        let mut other = JsonMerge::default().with_arrays(ArrayMerge::Concat);
+        other.extend(vec![json!({"tags": ["b"]}), json!({"tags": ["c"], "n": 2})]);
+        let merged = merge.merge(other);
+        assert_eq!(
+            merged.into_inner(),
+            Some(json!({"tags": ["a", "b", "c"], "n": 2}))
+        );
+        let empty = Vec::new().into_iter().collect::<JsonMerge>();
+        assert_eq!(empty.into_inner(), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+fn assert_close(value: Option<f64>, expected: f64) {
+    let value = value.unwrap();
+    assert!((value - expected).abs() < 1e-9, "{} != {}", value, expected);
+}
+
+/// Test the regression of a small set
+#[test]
+fn test_linreg() -> Result<()> {
+    let mut linreg = LinReg::<i32, f32>::default();
+    assert_eq!(linreg.mean_x(), None);
+    assert_eq!(linreg.covariance(), None);
+    assert_eq!(linreg.slope(), None);
+    linreg.reduce((1, 2.0));
+    assert_eq!(linreg.covariance(), Some(0.0));
+    assert_eq!(linreg.sample_covariance(), None);
+    assert_eq!(linreg.intercept(), None);
+    linreg.extend(&[(2, 3.0), (3, 5.0), (4, 6.0)]);
+    assert_eq!(linreg.count(), 4);
+    assert_close(linreg.mean_x(), 2.5);
+    assert_close(linreg.mean_y(), 4.0);
+    assert_close(linreg.variance_x(), 1.25);
+    assert_close(linreg.variance_y(), 2.5);
+    assert_close(linreg.covariance(), 1.75);
+    assert_close(linreg.sample_covariance(), 7.0 / 3.0);
+    assert_close(linreg.slope(), 1.4);
+    assert_close(linreg.intercept(), 0.5);
+    assert_close(linreg.predict(5.0), 7.5);
+    #[cfg(feature = "std")]
+    assert_close(linreg.correlation(), 1.75 / (1.25_f64 * 2.5).sqrt());
+    Ok(())
+}
+
+/// Test constant and anti-correlated values
+#[test]
+fn test_degenerate() -> Result<()> {
+    let vertical = vec![(1.0, 1.0), (1.0, 5.0)]
+        .into_iter()
+        .collect::<LinReg<f64, f64>>();
+    assert_eq!(vertical.slope(), None);
+    assert_eq!(vertical.predict(1.0), None);
+    #[cfg(feature = "std")]
+    assert_eq!(vertical.correlation(), None);
+    let falling = (0..10_u8)
+        .map(|x| (x, 100 - 3 * x))
+        .collect::<LinReg<_, _>>();
+    assert_close(falling.slope(), -3.0);
+    assert_close(falling.intercept(), 100.0);
+    #[cfg(feature = "std")]
+    assert_close(falling.correlation(), -1.0);
+    Ok(())
+}
+
+/// Test merging partial regressions
+#[test]
+fn test_merge() -> Result<()> {
+    let pairs = (1..=20)
+        .map(|i| (i as f64, ((i * i) % 7) as f64 + 1e6))
+        .collect::<Vec<_>>();
+    let whole = pairs.iter().collect::<LinReg<_, _>>();
+    let merged = pairs[..7]
+        .iter()
+        .collect::<LinReg<_, _>>()
+        .merge(LinReg::default())
+        .merge(pairs[7..].iter().collect());
+    assert_eq!(merged.count(), whole.count());
+    assert_close(merged.mean_y(), whole.mean_y().unwrap());
+    assert_close(merged.variance_x(), whole.variance_x().unwrap());
+    assert_close(merged.variance_y(), whole.variance_y().unwrap());
+    assert_close(merged.covariance(), whole.covariance().unwrap());
+    assert_close(merged.intercept(), whole.intercept().unwrap());
+    let mut empty = LinReg::<f64, f64>::new();
+    empty.merge_from(whole);
+    assert_close(empty.slope(), whole.slope().unwrap());
+    Ok(())
+}
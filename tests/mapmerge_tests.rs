@@ -0,0 +1,50 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod mapmerge_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    use std::collections::HashMap;
+
+    fn map(entries: &[(&'static str, usize)]) -> HashMap<&'static str, usize> {
+        entries.iter().copied().collect()
+    }
+
+    /// Test merging maps with a builtin function
+    #[test]
+    fn test_mapmerge_max() -> Result<()> {
+        let mut merge = MapMerge::new(std::cmp::max);
+        merge.reduce(map(&[("a", 1), ("b", 5)]));
+        merge.extend(vec![map(&[("a", 3), ("c", 2)]), map(&[("b", 4)])]);
+        assert_eq!(merge.as_ref(), &map(&[("a", 3), ("b", 5), ("c", 2)]));
+        eprintln!("{:?}", merge);
+        merge.extend(vec![("c", 7), ("d", 0)]);
+        assert_eq!(
+            merge.into_inner(),
+            map(&[("a", 3), ("b", 5), ("c", 7), ("d", 0)])
+        );
+        Ok(())
+    }
+
+    /// Test merging maps of Strings, which don't impl Copy
+    #[test]
+    fn test_mapmerge_concat() -> Result<()> {
+        let concat = |a: String, b: String| format!("{} {}", a, b);
+        let mut merge = MapMerge::<usize, String, _>::from(concat);
+        merge.reduce_entry(1, "a".to_string());
+        merge.reduce_entry(1, "b".to_string());
+        let clone = merge.clone();
+        let mut other = MapMerge::with_initial(HashMap::new(), concat);
+        other.reduce_entry(1, "c".to_string());
+        other.reduce_entry(2, "d".to_string());
+        let merged = merge.merge(other).into_inner();
+        assert_eq!(merged[&1], "a b c");
+        assert_eq!(merged[&2], "d");
+        assert_eq!(clone.into_inner()[&1], "a b");
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test the incremental mean
+#[test]
+fn test_mean() -> Result<()> {
+    let mut mean = Mean::<u8>::default();
+    assert_eq!(mean.mean(), None);
+    mean.reduce(4);
+    assert_eq!(mean.mean(), Some(4.0));
+    mean.extend(vec![1, 7]);
+    mean.extend(&[8]);
+    assert_eq!(mean.count(), 4);
+    assert_eq!(mean.into_inner(), Some(5.0));
+    let mean = vec![0.5_f32, 1.5].into_iter().collect::<Mean<_>>();
+    assert_eq!(mean.into_inner(), Some(1.0));
+    Ok(())
+}
+
+/// Test precision on a long stream of large values
+#[test]
+fn test_large_values() -> Result<()> {
+    let mean = (0..1_000_000_u32)
+        .map(|i| f64::from(i) + 1e12)
+        .collect::<Mean<_>>();
+    assert!((mean.into_inner().unwrap() - (1e12 + 499_999.5)).abs() < 1e-3);
+    Ok(())
+}
+
+/// Test merging partial means
+#[test]
+fn test_merge() -> Result<()> {
+    let a = (1..=3_u32).collect::<Mean<_>>();
+    let b = (4..=10_u32).collect::<Mean<_>>();
+    let merged = a.merge(b).merge(Mean::default());
+    assert_eq!(merged.count(), 10);
+    assert!((merged.into_inner().unwrap() - 5.5).abs() < 1e-12);
+    let mut empty = Mean::<u32>::new();
+    empty.merge_from((1..=2).collect());
+    assert_eq!(empty.into_inner(), Some(1.5));
+    Ok(())
+}
@@ -0,0 +1,81 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod median_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test reduce, extend, collect, low, high, average
+    #[test]
+    fn test_median() -> Result<()> {
+        let mut median = Median::<u32>::default();
+        assert!(median.is_empty());
+        assert_eq!(median.as_ref(), None);
+        assert_eq!(median.high(), None);
+        assert_eq!(median.average(), None);
+        median.reduce(3);
+        assert_eq!(median.as_ref(), Some(&3));
+        assert_eq!(median.high(), Some(&3));
+        median.extend([1, 8].iter());
+        assert_eq!(median.as_ref(), Some(&3));
+        median.reduce(6);
+        assert_eq!(median.len(), 4);
+        assert_eq!(median.low(), Some(&3));
+        assert_eq!(median.high(), Some(&6));
+        assert_eq!(median.average(), Some(4.5));
+        assert_eq!(median.clone().into_inner(), Some(3));
+        let collect = (1..=10).rev().collect::<Median<_>>();
+        assert_eq!(collect.average(), Some(5.5));
+        assert_eq!(collect.into_inner(), Some(5));
+        Ok(())
+    }
+
+    /// Test the running median against sorting
+    #[test]
+    fn test_sorted() -> Result<()> {
+        let mut median = Median::<u64>::new();
+        let mut items = vec![];
+        let mut seed = 12345_u64;
+        for _ in 0..500 {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let item = seed >> 56;
+            median.reduce(item);
+            items.push(item);
+            items.sort_unstable();
+            let len = items.len();
+            assert_eq!(median.low(), Some(&items[(len - 1) / 2]));
+            assert_eq!(median.high(), Some(&items[len / 2]));
+        }
+        Ok(())
+    }
+
+    /// Test merge
+    #[test]
+    fn test_merge() -> Result<()> {
+        let mut median = vec![9, 1, 5].into_iter().collect::<Median<i32>>();
+        median.merge_from(vec![2, 3, 4].into_iter().collect());
+        assert_eq!(median.low(), Some(&3));
+        assert_eq!(median.high(), Some(&4));
+        let median = median
+            .merge(Median::default())
+            .merge(vec![0].into_iter().collect());
+        assert_eq!(median.len(), 7);
+        assert_eq!(median.into_inner(), Some(3));
+        Ok(())
+    }
+
+    /// Test the Folding trait
+    #[test]
+    fn test_folding() -> Result<()> {
+        let mut median = Median::<&str>::new();
+        median.fold_item("b");
+        median.fold_item("c");
+        median.fold_item("a");
+        assert_eq!(median.peek(), Some(&"b"));
+        assert_eq!(median.finish(), Some("b"));
+        Ok(())
+    }
+}
@@ -17,11 +17,11 @@ fn test_min() -> Result<()> {
     assert_eq!(min.as_ref(), Some(&300));
     min.extend((250..=255).by_ref());
     assert_eq!(min.as_ref(), Some(&250));
-    let clone = min.clone();
+    let clone = min;
     min.extend((6..=10).rev());
     assert_eq!(min.into_inner(), Some(6));
     assert_eq!(clone.into_inner(), Some(250));
-    let collect = (2..=4).rev().into_iter().collect::<Min<_>>();
+    let collect = (2..=4).rev().collect::<Min<_>>();
     assert_eq!(collect.into_inner(), Some(2));
     let vec = (2..=4).rev().collect::<Vec<_>>();
     let collect_ref = vec.iter().collect::<Min<usize>>();
@@ -37,11 +37,11 @@ fn test_max() -> Result<()> {
     assert_eq!(max.as_ref(), Some(&3));
     max.extend((1..=5).by_ref());
     assert_eq!(max.as_ref(), Some(&5));
-    let clone = max.clone();
+    let clone = max;
     max.extend((6..=10).rev());
     assert_eq!(max.into_inner(), Some(10));
     assert_eq!(clone.into_inner(), Some(5));
-    let collect = (7..=9).rev().into_iter().collect::<Max<_>>();
+    let collect = (7..=9).rev().collect::<Max<_>>();
     assert_eq!(collect.into_inner(), Some(9));
     let vec = (2..=4).rev().collect::<Vec<_>>();
     let collect_ref = vec.iter().collect::<Max<usize>>();
@@ -73,3 +73,246 @@ fn test_type_with_clone() -> Result<()> {
     assert_eq!(sum2.into_inner(), Some(StrnumClone::from(5)));
     Ok(())
 }
+
+/// Test merging partial results
+#[test]
+fn test_merge() -> Result<()> {
+    let shards = [vec![4, 9, 2], vec![], vec![7, 1]];
+    let max = shards
+        .iter()
+        .map(|s| s.iter().collect::<Max<usize>>())
+        .fold(Max::default(), Max::merge);
+    assert_eq!(max.into_inner(), Some(9));
+    let mut min = Min::new(3);
+    min.merge_from(Min::default());
+    assert_eq!(min.as_ref(), Some(&3));
+    min.merge_from(Min::new(1));
+    assert_eq!(min.into_inner(), Some(1));
+    Ok(())
+}
+
+/// Test collecting from an iterator of results
+#[test]
+fn test_collect_result() -> Result<()> {
+    let parse = |s: &str| s.parse::<u32>();
+    let min = vec!["4", "2", "8"]
+        .into_iter()
+        .map(parse)
+        .collect::<Result<Min<_>, _>>()?;
+    assert_eq!(min.into_inner(), Some(2));
+    let mut consumed = 0;
+    let max = vec!["4", "x", "8"]
+        .into_iter()
+        .inspect(|_| consumed += 1)
+        .map(parse)
+        .collect::<Result<Max<_>, _>>();
+    assert!(max.is_err());
+    assert_eq!(consumed, 2);
+    Ok(())
+}
+
+/// Test counting the items reduced in, including the ones that were not kept
+#[test]
+fn test_count() -> Result<()> {
+    let mut max = Max::<i32>::default();
+    assert!(max.is_empty());
+    max.extend(vec![3, 1, 3]);
+    max.reduce_ref(&2);
+    assert_eq!(max.count(), 4);
+    let max = max.merge(Max::new(7)).merge(Max::default());
+    assert_eq!((max.count(), max.into_inner()), (5, Some(7)));
+    let min = [2.0, f64::NAN, 1.0].iter().collect::<Min<f64>>();
+    assert_eq!((min.count(), min.into_inner()), (3, Some(1.0)));
+    Ok(())
+}
+
+/// Test reporting whether the value was replaced
+#[test]
+fn test_reduce_check() -> Result<()> {
+    let mut max = Max::default();
+    let records = vec![3, 1, 3, 5]
+        .into_iter()
+        .filter(|&i| max.reduce_check(i))
+        .collect::<Vec<_>>();
+    assert_eq!(records, vec![3, 5]);
+    assert!(!max.reduce_ref_check(&4));
+    assert_eq!(max.count(), 5);
+    let mut min = Min::new(2.0);
+    assert!(!min.reduce_check(f64::NAN));
+    assert!(min.reduce_ref_check(&1.5));
+    assert_eq!(min.into_inner(), Some(1.5));
+    Ok(())
+}
+
+/// Test reusing Min and Max across batches with reset, take and replace
+#[test]
+fn test_reset() -> Result<()> {
+    let mut max = [3, 9, 4].iter().collect::<Max<u32>>();
+    assert_eq!(max.take(), Some(9));
+    assert!(max.is_empty());
+    max.extend(&[1, 2]);
+    assert_eq!(max.replace(7), Some(2));
+    max.reduce(5);
+    assert_eq!((max.count(), max.as_ref()), (2, Some(&7)));
+    let mut min = Min::new(2);
+    min.reset();
+    assert_eq!((min.count(), min.take()), (0, None));
+    min.extend(vec![8, 6]);
+    assert_eq!(min.into_inner(), Some(6));
+    Ok(())
+}
+
+/// Test the fallbacks of into_inner for empty autofolders
+#[test]
+fn test_into_inner_or() -> Result<()> {
+    assert_eq!(Max::<u8>::default().into_inner_or(3), 3);
+    assert_eq!(Max::new(5).into_inner_or(3), 5);
+    assert_eq!(Min::<u8>::default().into_inner_or_else(|| 4), 4);
+    assert_eq!(Min::<String>::default().into_inner_or_default(), "");
+    Ok(())
+}
+
+/// Test comparing and hashing, which ignore the count
+#[test]
+fn test_cmp() -> Result<()> {
+    let max = [3, 1].iter().collect::<Max<u8>>();
+    assert_eq!(max, Max::new(3));
+    assert!(Max::default() < max);
+    assert!(Min::new(2.5) > Min::new(1.0));
+    let mut seen = std::collections::HashSet::new();
+    assert!(seen.insert(Min::new("a")));
+    assert!(!seen.insert([Some("b"), Some("a")].iter().flatten().collect()));
+    let mut last = Max::with_tie(Tie::Last);
+    last.reduce(3);
+    assert_eq!(last, Max::new(3));
+    Ok(())
+}
+
+/// Test reporting incomparable items instead of ignoring them
+#[test]
+fn test_try_reduce() -> Result<()> {
+    let mut max = Max::default();
+    assert_eq!(
+        max.try_reduce(f64::NAN).map_err(|e| e.item().is_nan()),
+        Err(true)
+    );
+    assert!(max.is_empty());
+    assert_eq!(max.extend_strict(vec![1.0, 3.0]), Ok(()));
+    let error = max.extend_strict(vec![2.0, f64::NAN, 5.0]).unwrap_err();
+    assert!(error.into_item().is_nan());
+    assert_eq!((max.count(), max.into_inner()), (3, Some(3.0)));
+    let mut min = Min::default();
+    assert_eq!(min.extend_strict(vec!["b", "a"]), Ok(()));
+    assert_eq!(min.into_inner(), Some("a"));
+    Ok(())
+}
+
+/// Record that is compared only by its score
+#[derive(Debug)]
+struct Record {
+    score: u32,
+    name: char,
+}
+
+impl PartialEq for Record {
+    fn eq(&self, other: &Self) -> bool {
+        self.score == other.score
+    }
+}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.score.partial_cmp(&other.score)
+    }
+}
+
+/// Test choosing between the first and the last of the equal items
+#[test]
+fn test_tie() -> Result<()> {
+    let records = || {
+        [(3, 'a'), (5, 'b'), (5, 'c'), (1, 'd'), (1, 'e')]
+            .iter()
+            .map(|&(score, name)| Record { score, name })
+    };
+    let max = records().collect::<Max<_>>();
+    assert_eq!(max.tie(), Tie::First);
+    assert_eq!(max.into_inner().map(|r| r.name), Some('b'));
+    let mut max = Max::with_tie(Tie::Last);
+    max.extend(records());
+    assert_eq!(max.tie(), Tie::Last);
+    assert_eq!(max.into_inner().map(|r| r.name), Some('c'));
+    let mut min = Min::with_tie(Tie::Last);
+    min.extend(records());
+    assert_eq!(min.into_inner().map(|r| r.name), Some('e'));
+    Ok(())
+}
+
+/// Test folding items in with +=
+#[test]
+fn test_add_assign() -> Result<()> {
+    let mut min = Min::<i32>::default();
+    let mut max = Max::<i32>::default();
+    let mut minmax = MinMax::<i32>::default();
+    for value in [5, -2, 7] {
+        min += value;
+        max += value;
+        minmax += value;
+    }
+    assert_eq!(min.into_inner(), Some(-2));
+    assert_eq!(max.into_inner(), Some(7));
+    assert_eq!(minmax.to_inner(), Some((-2, 7)));
+    Ok(())
+}
+
+/// Test the const constructors in statics and consts
+#[test]
+fn test_const() -> Result<()> {
+    use std::sync::Mutex;
+    static PEAK: Mutex<Max<u64>> = Mutex::new(Max::empty());
+    const RANGE: MinMax<i32> = MinMax::new(0);
+    const FLOOR: Min<i32> = Min::with_tie(Tie::Last);
+    for value in [3, 9, 4] {
+        PEAK.lock().unwrap().reduce(value);
+    }
+    assert_eq!(PEAK.lock().unwrap().as_ref(), Some(&9));
+    let mut range = RANGE;
+    range.extend([-2, 5]);
+    assert_eq!(range.to_inner(), Some((-2, 5)));
+    assert!(FLOOR.is_empty());
+    assert!(MinMax::<u8>::empty().is_empty());
+    Ok(())
+}
+
+/// Test that reduce_slice matches extend, including ties and NaNs
+#[test]
+fn test_reduce_slice() -> Result<()> {
+    let bits = |item: Option<&f32>| item.map(|f| f.to_bits());
+    let slices: &[&[f32]] = &[
+        &[],
+        &[2.0, -0.0, 5.0, 0.0, 5.0, -1.0],
+        &[f32::NAN, 1.0, 3.0],
+        &[4.0, f32::NAN, -0.0, 0.0],
+    ];
+    for tie in [Tie::First, Tie::Last] {
+        for slice in slices {
+            for initial in [None, Some(0.0), Some(f32::NAN)] {
+                let mut min = Min::with_tie(tie);
+                let mut max = Max::with_tie(tie);
+                if let Some(initial) = initial {
+                    min.reduce(initial);
+                    max.reduce(initial);
+                }
+                let (mut min_extend, mut max_extend) = (min, max);
+                min.reduce_slice(slice);
+                max.reduce_slice(slice);
+                min_extend.extend(slice.iter());
+                max_extend.extend(slice.iter());
+                assert_eq!(bits(min.as_ref()), bits(min_extend.as_ref()));
+                assert_eq!(bits(max.as_ref()), bits(max_extend.as_ref()));
+                assert_eq!(min.count(), min_extend.count());
+                assert_eq!(max.count(), max_extend.count());
+            }
+        }
+    }
+    Ok(())
+}
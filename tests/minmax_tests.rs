@@ -17,11 +17,11 @@ fn test_minmax() -> Result<()> {
     assert_eq!(minmax.as_ref(), Some((&0, &3)));
     minmax.extend(1..=5);
     assert_eq!(minmax.as_ref(), Some((&0, &5)));
-    let clone = minmax.clone();
+    let clone = minmax;
     minmax.extend((6..=10).rev());
     assert_eq!(minmax.to_inner(), Some((0, 10)));
     assert_eq!(clone.to_inner(), Some((0, 5)));
-    let collect = (7..=9).rev().into_iter().collect::<MinMax<_>>();
+    let collect = (7..=9).rev().collect::<MinMax<_>>();
     assert_eq!(collect.to_inner(), Some((7, 9)));
     let vec = (2..=4).rev().collect::<Vec<_>>();
     let collect_ref = vec.iter().collect::<MinMax<usize>>();
@@ -49,14 +49,12 @@ fn test_type_without_clone() -> Result<()> {
     assert_eq!(minmax.min_as_ref(), Some(&Strnum::from(1)));
     assert_eq!(minmax.max_as_ref(), Some(&Strnum::from(5)));
     assert_eq!(minmax.as_ref(), Some((&Strnum::from(1), &Strnum::from(5))));
-    minmax.extend(
-        (6..=10)
-            .map(Strnum::from)
-            .rev()
-            .collect::<Vec<_>>()
-            .into_iter(),
-    );
+    minmax.extend((6..=10).map(Strnum::from).rev().collect::<Vec<_>>());
     assert_eq!(minmax.as_ref(), Some((&Strnum::from(1), &Strnum::from(10))));
+    minmax.fold_item(Strnum::from(0));
+    assert_eq!(minmax.min_as_ref(), Some(&Strnum::from(0)));
+    minmax += Strnum::from(11);
+    assert_eq!(minmax.max_as_ref(), Some(&Strnum::from(11)));
     Ok(())
 }
 
@@ -83,3 +81,197 @@ fn test_type_with_clone() -> Result<()> {
     );
     Ok(())
 }
+
+/// Test merging partial results
+#[test]
+fn test_merge() -> Result<()> {
+    let mut minmax = MinMax::default();
+    minmax.merge_from(MinMax::new(5));
+    assert_eq!(minmax.as_ref(), Some((&5, &5)));
+    minmax.merge_from(MinMax::default());
+    assert_eq!(minmax.as_ref(), Some((&5, &5)));
+    let other = vec![3, 8, 6].into_iter().collect::<MinMax<_>>();
+    let minmax = minmax.merge(other);
+    assert_eq!(minmax.to_inner(), Some((3, 8)));
+    let merged = MinMax::new(4).merge(MinMax::new(2));
+    assert_eq!(merged.to_inner(), Some((2, 4)));
+    Ok(())
+}
+
+/// Test collecting from an iterator of results
+#[test]
+fn test_collect_result() -> Result<()> {
+    let items: Vec<Result<i32, &str>> = vec![Ok(4), Ok(2), Ok(8)];
+    let minmax = items.into_iter().collect::<Result<MinMax<_>, _>>();
+    assert_eq!(minmax.map(MinMax::to_inner), Ok(Some((2, 8))));
+    let items: Vec<Result<i32, &str>> = vec![Ok(4), Err("bad"), Ok(8)];
+    let minmax = items.into_iter().collect::<Result<MinMax<_>, _>>();
+    assert_eq!(minmax.map(MinMax::to_inner), Err("bad"));
+    Ok(())
+}
+
+/// Test counting the items reduced in, including repeated ones
+#[test]
+fn test_count() -> Result<()> {
+    let mut minmax = MinMax::default();
+    assert!(minmax.is_empty());
+    minmax.extend(vec![5, 5, 5]);
+    assert_eq!(minmax.as_ref(), Some((&5, &5)));
+    assert_eq!(minmax.count(), 3);
+    let minmax = minmax.merge([1, 9].iter().collect());
+    assert_eq!((minmax.count(), minmax.to_inner()), (5, Some((1, 9))));
+    Ok(())
+}
+
+/// Test matching on the values kept
+#[test]
+fn test_extremes() -> Result<()> {
+    let mut minmax = MinMax::default();
+    assert_eq!(minmax.extremes(), &Extremes::None);
+    minmax.extend(vec![5, 5]);
+    assert_eq!(minmax.extremes(), &Extremes::Single(5));
+    minmax.reduce(1);
+    match minmax.into_extremes() {
+        Extremes::Both(min, max) => assert_eq!((min, max), (1, 5)),
+        other => panic!("unexpected {:?}", other),
+    }
+    Ok(())
+}
+
+/// Test reporting which values were replaced
+#[test]
+fn test_reduce_check() -> Result<()> {
+    let mut minmax = MinMax::default();
+    assert_eq!(minmax.reduce_check(5), MinMaxUpdate::Both);
+    assert_eq!(minmax.reduce_check(5), MinMaxUpdate::Unchanged);
+    assert_eq!(minmax.reduce_ref_check(&7), MinMaxUpdate::Max);
+    assert_eq!(minmax.reduce_check(6), MinMaxUpdate::Unchanged);
+    let update = minmax.reduce_ref_check(&1);
+    assert_eq!(update, MinMaxUpdate::Min);
+    assert!(update.is_changed() && update.min_changed() && !update.max_changed());
+    assert_eq!(minmax.to_inner(), Some((1, 7)));
+    Ok(())
+}
+
+/// Test reusing a MinMax across batches with reset, take and replace
+#[test]
+fn test_reset() -> Result<()> {
+    let mut minmax = [3, 9, 4].iter().collect::<MinMax<u32>>();
+    assert_eq!(minmax.take(), Some((3, 9)));
+    assert_eq!((minmax.count(), minmax.take()), (0, None));
+    assert_eq!(minmax.replace(5), None);
+    minmax.extend(&[1, 2]);
+    assert_eq!(minmax.replace(7), Some((1, 5)));
+    assert_eq!((minmax.count(), minmax.as_ref()), (1, Some((&7, &7))));
+    minmax.reset();
+    assert!(minmax.is_empty());
+    assert_eq!(minmax.to_inner(), None);
+    Ok(())
+}
+
+/// Test the fallbacks of to_inner for empty autofolders
+#[test]
+fn test_to_inner_or() -> Result<()> {
+    assert_eq!(MinMax::<u8>::default().to_inner_or((1, 2)), (1, 2));
+    assert_eq!(MinMax::new(5).to_inner_or((1, 2)), (5, 5));
+    assert_eq!(MinMax::<u8>::default().to_inner_or_else(|| (0, 9)), (0, 9));
+    assert_eq!(
+        [2, 7].iter().collect::<MinMax<u8>>().to_inner_or_default(),
+        (2, 7)
+    );
+    assert_eq!(MinMax::<u8>::default().to_inner_or_default(), (0, 0));
+    Ok(())
+}
+
+/// Test comparing and hashing, which ignore the count
+#[test]
+fn test_cmp() -> Result<()> {
+    let minmax = [1, 4, 1].iter().collect::<MinMax<u8>>();
+    assert_eq!(minmax, [4, 1].iter().collect());
+    assert_ne!(minmax, MinMax::new(1));
+    assert_eq!(MinMax::new(2), [2, 2].iter().collect());
+    assert!(MinMax::default() < MinMax::new(0));
+    let mut sorted = vec![MinMax::new(5), minmax, MinMax::new(1)];
+    sorted.sort();
+    assert_eq!(sorted, vec![MinMax::new(1), minmax, MinMax::new(5)]);
+    let mut seen = std::collections::HashSet::new();
+    assert!(seen.insert(minmax));
+    Ok(())
+}
+
+/// Test converting to ranges and querying containment
+#[test]
+fn test_range() -> Result<()> {
+    let minmax = [4.5, 1.0, 2.0].iter().collect::<MinMax<f64>>();
+    assert!(minmax.contains(&4.5));
+    assert!(minmax.contains(&1.0));
+    assert!(!minmax.contains(&0.5));
+    assert!(!minmax.contains(&f64::NAN));
+    assert_eq!(minmax.as_range(), Some(&1.0..=&4.5));
+    assert_eq!(minmax.into_range(), Some(1.0..=4.5));
+    let single = MinMax::new('x');
+    assert_eq!(single.into_range().map(|r| r.count()), Some(1));
+    let empty = MinMax::<u8>::default();
+    assert!(!empty.contains(&0));
+    assert_eq!(empty.as_range(), None);
+    assert_eq!(empty.into_range(), None);
+    Ok(())
+}
+
+/// Test building from a pair and extracting a single value
+#[test]
+fn test_both() -> Result<()> {
+    let minmax = MinMax::both(2, 8);
+    assert_eq!((minmax.count(), minmax.as_ref()), (2, Some((&2, &8))));
+    let mut minmax = MinMax::from((8, 2));
+    assert_eq!(minmax.as_ref(), Some((&2, &8)));
+    minmax.extend(&[1, 5]);
+    assert_eq!(minmax.into_min(), Some(1));
+    assert_eq!(MinMax::from((3, 3)).as_ref(), Some((&3, &3)));
+    let words = MinMax::both("b".to_string(), "a".to_string());
+    assert_eq!(words.into_max().as_deref(), Some("b"));
+    assert_eq!(MinMax::<u8>::default().into_min(), None);
+    let nan = MinMax::both(1.0, f64::NAN);
+    assert_eq!((nan.count(), nan.to_inner()), (2, Some((1.0, 1.0))));
+    Ok(())
+}
+
+/// Test reporting incomparable items instead of ignoring them
+#[test]
+fn test_try_reduce() -> Result<()> {
+    let mut minmax = MinMax::default();
+    assert_eq!(minmax.try_reduce(2.0), Ok(()));
+    assert!(minmax.try_reduce(f32::NAN).is_err());
+    assert_eq!(minmax.extend_strict(vec![1.0, 4.0]), Ok(()));
+    let error = minmax.extend_strict(vec![0.0, f32::NAN]).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "item NaN can't be compared with the current values"
+    );
+    assert_eq!((minmax.count(), minmax.to_inner()), (4, Some((0.0, 4.0))));
+    Ok(())
+}
+
+/// Test that reduce_slice matches extend, including NaNs
+#[test]
+fn test_reduce_slice() -> Result<()> {
+    let slices: &[&[f64]] = &[
+        &[],
+        &[2.0, 2.0],
+        &[1.0, f64::NAN, -3.0, 7.0],
+        &[f64::NAN, 1.0],
+    ];
+    for slice in slices {
+        for initial in [MinMax::default(), MinMax::new(2.0), MinMax::both(0.0, 5.0)] {
+            let mut minmax = initial;
+            let mut extend = initial;
+            minmax.reduce_slice(slice);
+            extend.extend(slice.iter());
+            assert_eq!(format!("{:?}", minmax), format!("{:?}", extend));
+        }
+    }
+    let mut minmax = MinMax::default();
+    minmax.reduce_slice(&[3_u8, 9, 1, 4]);
+    assert_eq!((minmax.count(), minmax.to_inner()), (4, Some((1, 9))));
+    Ok(())
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+use std::cell::Cell;
+
+// Type that doesn't implement PartialOrd
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Point {
+    name: &'static str,
+    x: i32,
+}
+
+fn points() -> Vec<Point> {
+    vec![
+        Point { name: "a", x: 3 },
+        Point { name: "b", x: -2 },
+        Point { name: "c", x: 7 },
+        Point { name: "d", x: 7 },
+        Point { name: "e", x: -2 },
+    ]
+}
+
+/// Test extend, reduce and ties for min_by
+#[test]
+fn test_min_by() -> Result<()> {
+    let mut min = MinBy::new(|p: &Point| p.x);
+    assert_eq!(min.as_ref(), None);
+    min.extend(points());
+    assert_eq!(min.key(), Some(&-2));
+    assert_eq!(min.as_ref().map(|p| p.name), Some("b"));
+    min.reduce_ref(&Point { name: "f", x: -5 });
+    assert_eq!(min.into_inner().map(|p| p.name), Some("f"));
+    Ok(())
+}
+
+/// Test extend, reduce and ties for max_by
+#[test]
+fn test_max_by() -> Result<()> {
+    let mut max = MaxBy::new(|p: &Point| p.x);
+    max.extend(&points());
+    assert_eq!(max.key(), Some(&7));
+    assert_eq!(max.into_inner().map(|p| p.name), Some("c"));
+    let len = |s: &&str| s.len();
+    let mut other = MaxBy::from(len);
+    other.extend(vec!["abc", "de"]);
+    let max = MaxBy::from(len).merge(other);
+    assert_eq!(max.into_inner(), Some("abc"));
+    Ok(())
+}
+
+/// Test that the key function is called only once per item
+#[test]
+fn test_cached_key() -> Result<()> {
+    let calls = Cell::new(0);
+    let key = |p: &Point| {
+        calls.set(calls.get() + 1);
+        p.x
+    };
+    let mut min = MinBy::new(key);
+    min.extend(points());
+    assert_eq!(calls.get(), 5);
+    let mut other = MinBy::new(key);
+    other.reduce(Point { name: "g", x: -9 });
+    assert_eq!(calls.get(), 6);
+    let min = min.merge(other);
+    assert_eq!(calls.get(), 6);
+    assert_eq!(min.into_inner().map(|p| p.name), Some("g"));
+    Ok(())
+}
+
+/// Test keeping the last of the items with equal keys
+#[test]
+fn test_tie_last() -> Result<()> {
+    let mut max = MaxBy::with_tie(|p: &Point| p.x, Tie::Last);
+    max.extend(points());
+    assert_eq!(max.tie(), Tie::Last);
+    assert_eq!(max.into_inner().map(|p| p.name), Some("d"));
+    let x = |p: &Point| p.x;
+    let mut min = MinBy::with_tie(x, Tie::Last);
+    min.extend(&points());
+    let mut other = MinBy::with_tie(x, Tie::First);
+    other.reduce(Point { name: "f", x: -2 });
+    min.merge_from(other);
+    assert_eq!(min.into_inner().map(|p| p.name), Some("f"));
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+// Value type that doesn't implement any comparison trait
+#[derive(Debug, Clone)]
+struct Record(&'static str);
+
+/// Test extend, collect and ties
+#[test]
+fn test_minmaxbykey() -> Result<()> {
+    let mut minmax = MinMaxByKey::<u32, Record>::default();
+    assert!(minmax.as_ref().is_none());
+    minmax.reduce((5, Record("a")));
+    assert_eq!(minmax.min_as_ref().map(|(k, v)| (*k, v.0)), Some((5, "a")));
+    assert_eq!(minmax.max_as_ref().map(|(k, v)| (*k, v.0)), Some((5, "a")));
+    minmax.extend(vec![(3, Record("b")), (9, Record("c")), (3, Record("d"))]);
+    minmax.extend(&[(9, Record("e")), (7, Record("f"))]);
+    let ((kmin, vmin), (kmax, vmax)) = minmax.to_inner().unwrap();
+    assert_eq!((kmin, vmin.0, kmax, vmax.0), (3, "b", 9, "c"));
+    let collect = [(2.5, "x"), (0.5, "y")]
+        .iter()
+        .collect::<MinMaxByKey<_, _>>();
+    assert_eq!(collect.to_inner(), Some(((0.5, "y"), (2.5, "x"))));
+    Ok(())
+}
+
+/// Test merging
+#[test]
+fn test_merge() -> Result<()> {
+    let a = vec![(10, 'a'), (20, 'b')]
+        .into_iter()
+        .collect::<MinMaxByKey<_, _>>();
+    let b = MinMaxByKey::from((15, 'c'));
+    let merged = a.merge(b).merge(MinMaxByKey::default());
+    assert_eq!(merged.to_inner(), Some(((10, 'a'), (20, 'b'))));
+    let mut single = MinMaxByKey::new(30, 'd');
+    single.merge_from(merged);
+    assert_eq!(single.to_inner(), Some(((10, 'a'), (30, 'd'))));
+    Ok(())
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test reduce, extend, collect
+#[test]
+fn test_elementwise() -> Result<()> {
+    let mut bbox = ElementwiseMinMax::<i32, 2>::default();
+    assert!(bbox.is_empty());
+    assert_eq!(bbox.as_ref(), None);
+    assert!(!bbox.contains(&[0, 0]));
+    bbox.reduce([3, -1]);
+    assert_eq!(bbox.as_ref(), Some((&[3, -1], &[3, -1])));
+    bbox.extend(&[[5, 0], [1, 2]]);
+    bbox.reduce_ref(&[4, -7]);
+    assert_eq!(bbox.count(), 4);
+    assert_eq!(bbox.min_as_ref(), Some(&[1, -7]));
+    assert_eq!(bbox.max_as_ref(), Some(&[5, 2]));
+    assert!(bbox.contains(&[1, 2]));
+    assert!(!bbox.contains(&[0, 0]));
+    let collect = vec![[1, 1], [0, 2]]
+        .into_iter()
+        .collect::<ElementwiseMinMax<_, 2>>();
+    assert_eq!(collect.into_inner(), Some(([0, 1], [1, 2])));
+    Ok(())
+}
+
+/// Test that NaNs are ignored after the first array
+#[test]
+fn test_nan() -> Result<()> {
+    let mut bbox = ElementwiseMinMax::from([0.0, 1.0, 2.0]);
+    bbox.reduce([f64::NAN, 3.0, -2.0]);
+    assert_eq!(bbox.into_inner(), Some(([0.0, 1.0, -2.0], [0.0, 3.0, 2.0])));
+    Ok(())
+}
+
+/// Test merge
+#[test]
+fn test_merge() -> Result<()> {
+    let a = [[0_u8, 9], [4, 4]]
+        .iter()
+        .collect::<ElementwiseMinMax<_, 2>>();
+    let b = [[7_u8, 1]].iter().collect::<ElementwiseMinMax<_, 2>>();
+    let mut merged = a.merge(b);
+    assert_eq!(merged.count(), 3);
+    assert_eq!(merged.into_inner(), Some(([0, 1], [7, 9])));
+    merged.merge_from(ElementwiseMinMax::default());
+    assert_eq!(merged.count(), 3);
+    Ok(())
+}
+
+/// Test the Folding trait and the empty statics
+#[test]
+fn test_folding() -> Result<()> {
+    static EMPTY: ElementwiseMinMax<u16, 3> = ElementwiseMinMax::empty();
+    let mut bbox = EMPTY;
+    bbox.fold_item([1, 2, 3]);
+    bbox.fold_item([3, 2, 1]);
+    assert_eq!(bbox.peek(), Some((&[1, 2, 1], &[3, 2, 3])));
+    assert_eq!(bbox.finish(), Some(([1, 2, 1], [3, 2, 3])));
+    Ok(())
+}
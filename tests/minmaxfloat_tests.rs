@@ -0,0 +1,67 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+const ITEMS: [f64; 5] = [2.0, f64::NAN, -1.0, f64::INFINITY, 0.5];
+
+/// Test ignoring NaNs, the default policy
+#[test]
+fn test_ignore() -> Result<()> {
+    let max = ITEMS.iter().collect::<MaxFloat<f64>>();
+    assert_eq!(max.policy(), NanPolicy::Ignore);
+    assert_eq!((max.count(), max.into_inner()), (5, Some(f64::INFINITY)));
+    let mut min = MinFloat::<f32>::default();
+    min.extend(vec![f32::NAN, 3.0, f32::NAN]);
+    assert_eq!(min.into_inner(), Some(3.0));
+    let all_nan = vec![f64::NAN; 3].into_iter().collect::<MinFloat<f64>>();
+    assert_eq!(all_nan.into_inner(), None);
+    Ok(())
+}
+
+/// Test propagating NaNs, regardless of their position
+#[test]
+fn test_propagate() -> Result<()> {
+    for position in 0..ITEMS.len() {
+        let mut items = vec![1.0; ITEMS.len()];
+        items[position] = f64::NAN;
+        let mut max = MaxFloat::new(NanPolicy::Propagate);
+        max.extend(&items);
+        assert!(max.into_inner().unwrap().is_nan());
+    }
+    let mut min = MinFloat::new(NanPolicy::Propagate);
+    min.extend(vec![2.0_f32, 1.0, 3.0]);
+    assert_eq!(min.into_inner(), Some(1.0));
+    Ok(())
+}
+
+/// Test the total order of floats, which also orders NaNs and signed zeros
+#[test]
+fn test_total_order() -> Result<()> {
+    let mut max = MaxFloat::new(NanPolicy::TotalOrder);
+    max.extend(&ITEMS);
+    assert!(max.as_ref().unwrap().is_nan());
+    let mut min = MinFloat::new(NanPolicy::TotalOrder);
+    min.extend(&ITEMS);
+    assert_eq!(min.into_inner(), Some(-1.0));
+    let mut zero = MaxFloat::new(NanPolicy::TotalOrder);
+    zero.extend(vec![-0.0_f32, 0.0]);
+    assert!(zero.into_inner().unwrap().is_sign_positive());
+    Ok(())
+}
+
+/// Test merging, which uses the policy of the left side
+#[test]
+fn test_merge() -> Result<()> {
+    let ignoring = vec![1.0, 4.0].into_iter().collect::<MaxFloat<f64>>();
+    let mut propagating = MaxFloat::new(NanPolicy::Propagate);
+    propagating.extend(vec![f64::NAN, 2.0]);
+    let merged = ignoring.merge(propagating);
+    assert_eq!((merged.count(), merged.into_inner()), (4, Some(4.0)));
+    let merged = propagating.merge(ignoring);
+    assert!(merged.into_inner().unwrap().is_nan());
+    Ok(())
+}
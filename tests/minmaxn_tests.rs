@@ -0,0 +1,45 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod minmaxn_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test extend, peek and with_items
+    #[test]
+    fn test_minmaxn() -> Result<()> {
+        let mut minmax = MinMaxN::new(3);
+        assert_eq!(minmax.peek(), None);
+        minmax.extend(vec![4, 8, 1]);
+        assert_eq!(minmax.peek(), Some((&8, &1)));
+        assert_eq!(minmax.len(), 3);
+        minmax.extend(&[6, 2, 9, 5]);
+        assert_eq!(minmax.peek(), Some((&4, &6)));
+        assert_eq!(minmax.n(), 3);
+        assert_eq!(minmax.into_sorted_vecs(), (vec![1, 2, 4], vec![6, 8, 9]));
+        let strings = ["b", "d", "a", "c"];
+        let minmax = MinMaxN::new(1).with_items(strings.iter().map(|s| s.to_string()));
+        let (bottom, top) = minmax.into_parts();
+        assert_eq!(bottom.into_sorted_vec(), vec!["a"]);
+        assert_eq!(top.into_sorted_vec(), vec!["d"]);
+        let mut empty = MinMaxN::<u8>::new(0);
+        empty.extend(1..=5);
+        assert!(empty.is_empty());
+        Ok(())
+    }
+
+    /// Test merging
+    #[test]
+    fn test_merge() -> Result<()> {
+        let a = MinMaxN::new(2).with_items(vec![5, 1, 9, 3]);
+        let b = MinMaxN::new(10).with_items(vec![0, 7, 10]);
+        let merged = a.merge(b);
+        assert_eq!(merged.bottom().peek(), Some(&1));
+        assert_eq!(merged.top().peek(), Some(&9));
+        assert_eq!(merged.finish(), (vec![0, 1], vec![9, 10]));
+        Ok(())
+    }
+}
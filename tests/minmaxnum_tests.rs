@@ -17,13 +17,13 @@ mod minmaxnum_tests {
         assert_eq!(maxnum.into_inner(), 3);
         maxnum.extend(1..=5);
         assert_eq!(maxnum.as_ref(), &5);
-        let clone = maxnum.clone();
+        let clone = maxnum;
         maxnum.extend((6..=10).rev());
         assert_eq!(maxnum.into_inner(), 10);
         assert_eq!(clone.into_inner(), 5);
-        let collect = (7..=9).rev().into_iter().collect::<MaxNum<_>>();
+        let collect = (7..=9).rev().collect::<MaxNum<_>>();
         assert_eq!(collect.into_inner(), 9);
-        let singleton = (2..=2).rev().into_iter().collect::<MaxNum<usize>>();
+        let singleton = (2..=2).rev().collect::<MaxNum<usize>>();
         assert_eq!(singleton.into_inner(), 2);
         Ok(())
     }
@@ -37,13 +37,13 @@ mod minmaxnum_tests {
         assert_eq!(minnum.as_ref(), &300);
         minnum.extend(10..=50);
         assert_eq!(minnum.as_ref(), &10);
-        let clone = minnum.clone();
+        let clone = minnum;
         minnum.extend((6..=10).rev());
         assert_eq!(minnum.into_inner(), 6);
         assert_eq!(clone.into_inner(), 10);
-        let collect = (2..=4).rev().into_iter().collect::<MinNum<_>>();
+        let collect = (2..=4).rev().collect::<MinNum<_>>();
         assert_eq!(collect.into_inner(), 2);
-        let singleton = (2..=2).rev().into_iter().collect::<MinNum<usize>>();
+        let singleton = (2..=2).rev().collect::<MinNum<usize>>();
         assert_eq!(singleton.into_inner(), 2);
         Ok(())
     }
@@ -57,14 +57,114 @@ mod minmaxnum_tests {
         assert_eq!(minmaxnum.as_ref(), (&300, &300));
         minmaxnum.extend(290..=310);
         assert_eq!(minmaxnum.as_ref(), (&290, &310));
-        let clone = minmaxnum.clone();
+        let clone = minmaxnum;
         minmaxnum.extend((280..=320).rev());
         assert_eq!(minmaxnum.into_inner(), (280, 320));
         assert_eq!(clone.into_inner(), (290, 310));
-        let collect = (2..=4).rev().into_iter().collect::<MinMaxNum<usize>>();
+        let collect = (2..=4).rev().collect::<MinMaxNum<usize>>();
         assert_eq!(collect.into_inner(), (2, 4));
-        let singleton = (2..=2).rev().into_iter().collect::<MinMaxNum<usize>>();
+        let singleton = (2..=2).rev().collect::<MinMaxNum<usize>>();
         assert_eq!(singleton.into_inner(), (2, 2));
         Ok(())
     }
+
+    /// Test merging partial results
+    #[test]
+    fn test_merge() -> Result<()> {
+        let shards = [vec![4, 9, 2], vec![], vec![7, 1]];
+        let minmax = shards
+            .iter()
+            .map(|s| s.iter().collect::<MinMaxNum<i32>>())
+            .fold(MinMaxNum::new(), MinMaxNum::merge);
+        assert_eq!(minmax.into_inner(), (1, 9));
+        let mut max = MaxNum::<i32>::new();
+        max.merge_from((1..=3).collect());
+        max.merge_from(MaxNum::new());
+        assert_eq!(max.into_inner(), 3);
+        let min = MinNum::from(5).merge(MinNum::from(8));
+        assert_eq!(min.into_inner(), 5);
+        Ok(())
+    }
+
+    /// Test counting the items reduced in
+    #[test]
+    fn test_count() -> Result<()> {
+        let mut max = MaxNum::<u8>::default();
+        assert!(max.is_empty());
+        max.extend(vec![0, 0]);
+        assert_eq!((max.count(), *max.as_ref()), (2, 0));
+        let min = MinNum::from(4_i8).merge((1..=3).collect());
+        assert_eq!(min.count(), 4);
+        let mut minmax = MinMaxNum::<i64>::default();
+        minmax.extend(&[2, -2, 0]);
+        minmax.merge_from(MinMaxNum::from(1));
+        assert_eq!((minmax.count(), minmax.into_inner()), (4, (-2, 2)));
+        Ok(())
+    }
+
+    /// Test telling empty autofolders apart from the bounds
+    #[test]
+    fn test_into_inner_checked() -> Result<()> {
+        assert_eq!(MinNum::<usize>::default().into_inner_checked(), None);
+        assert_eq!(MaxNum::<i8>::default().into_inner_checked(), None);
+        assert_eq!(MinMaxNum::<u32>::default().into_inner_checked(), None);
+        let min = vec![usize::MAX].into_iter().collect::<MinNum<_>>();
+        assert_eq!(min.into_inner_checked(), Some(usize::MAX));
+        let max = MaxNum::from(i8::MIN);
+        assert_eq!(max.into_inner_checked(), Some(i8::MIN));
+        let minmax = MinMaxNum::default().merge(MinMaxNum::from(u32::MAX));
+        assert_eq!(minmax.into_inner_checked(), Some((u32::MAX, u32::MAX)));
+        Ok(())
+    }
+
+    /// Test that reduce_slice matches extend
+    #[test]
+    fn test_reduce_slice() -> Result<()> {
+        let items = [3.5_f32, -1.0, f32::NAN, 8.0, 0.0];
+        let mut max = MaxNum::default();
+        max.reduce_slice(&items);
+        assert_eq!(max, items.iter().collect::<MaxNum<_>>());
+        assert_eq!(max.count(), 5);
+        let mut min = MinNum::from(-2.0_f32);
+        min.reduce_slice(&items);
+        assert_eq!(min.into_inner(), -2.0);
+        let mut minmax = MinMaxNum::default();
+        minmax.reduce_slice(&items[..0]);
+        assert!(minmax.is_empty());
+        minmax.reduce_slice(&items);
+        assert_eq!(minmax, items.iter().collect::<MinMaxNum<_>>());
+        Ok(())
+    }
+
+    /// Test reporting whether the values were replaced
+    #[test]
+    fn test_reduce_check() -> Result<()> {
+        let mut max = MaxNum::<u8>::default();
+        assert!(!max.reduce_check(0));
+        assert!(max.reduce_ref_check(&3));
+        let mut min = MinNum::<i8>::from(-2);
+        assert!(!min.reduce_check(-1));
+        let mut minmax = MinMaxNum::<i32>::default();
+        assert_eq!(minmax.reduce_check(4), MinMaxUpdate::Both);
+        assert_eq!(minmax.reduce_ref_check(&9), MinMaxUpdate::Max);
+        assert_eq!(minmax.reduce_check(4), MinMaxUpdate::Unchanged);
+        assert_eq!(minmax.reduce_check(-3), MinMaxUpdate::Min);
+        assert_eq!(minmax.into_inner(), (-3, 9));
+        Ok(())
+    }
+
+    /// Test comparing and hashing, which ignore the count
+    #[test]
+    fn test_cmp() -> Result<()> {
+        let max = [3, 1].iter().copied().collect::<MaxNum<u8>>();
+        assert_eq!(max, MaxNum::from(3));
+        assert!(MaxNum::from(2) < max);
+        assert!(MinNum::from(2.5) > MinNum::from(1.0));
+        let minmax = (1..=4).collect::<MinMaxNum<u8>>();
+        let mut set = std::collections::HashSet::new();
+        set.insert(minmax);
+        assert!(set.contains(&[4, 1].iter().copied().collect::<MinMaxNum<u8>>()));
+        assert_eq!(minmax.cmp(&MinMaxNum::from(2)), core::cmp::Ordering::Less);
+        Ok(())
+    }
 }
@@ -0,0 +1,57 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod mode_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test extend, collect, count
+    #[test]
+    fn test_mode() -> Result<()> {
+        let mut mode = Mode::<&str>::default();
+        assert!(mode.is_empty());
+        assert_eq!(mode.peek(), None);
+        mode.fold("x");
+        mode.extend(vec!["y", "z", "y"]);
+        assert_eq!(mode.peek(), Some((&"y", 2)));
+        assert_eq!(mode.count(&"z"), 1);
+        assert_eq!(mode.count(&"w"), 0);
+        assert_eq!(mode.len(), 3);
+        assert_eq!(mode.into_inner(), Some(("y", 2)));
+        let strings = ["b", "a", "b"].map(String::from);
+        let mode = strings.iter().collect::<Mode<_>>();
+        assert_eq!(mode.into_inner(), Some(("b".to_string(), 2)));
+        Ok(())
+    }
+
+    /// Test the tie policies
+    #[test]
+    fn test_ties() -> Result<()> {
+        let items = [3, 1, 2, 1, 3, 2, 4];
+        let first = items.iter().collect::<Mode<_>>();
+        assert_eq!(first.tie(), ModeTie::First);
+        assert_eq!(first.into_inner(), Some((3, 2)));
+        let mut last = Mode::with_tie(ModeTie::Last);
+        last.extend(items);
+        assert_eq!(last.into_inner(), Some((2, 2)));
+        Ok(())
+    }
+
+    /// Test merging
+    #[test]
+    fn test_merge() -> Result<()> {
+        let a = "aab".chars().collect::<Mode<_>>();
+        let b = "bbc".chars().collect::<Mode<_>>();
+        let merged = a.merge(b);
+        assert_eq!(merged.count(&'b'), 3);
+        assert_eq!(merged.peek(), Some((&'b', 3)));
+        let mut a = Mode::with_tie(ModeTie::Last);
+        a.extend("xy".chars());
+        a.merge_from("x".chars().collect());
+        assert_eq!(a.into_inner(), Some(('x', 2)));
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+mod strnum;
+use strnum::*;
+
+use std::convert::TryFrom;
+
+/// Test min, max and mean in one pass
+#[test]
+fn test_multifold() -> Result<()> {
+    let mut multi = MultiFold::new((Min::default(), Max::default(), Mean::default()));
+    multi.fold(4_u32);
+    multi.extend(vec![8, 3, 1]);
+    assert_eq!(multi.peek(), (Some(&1), Some(&8), Some(4.0)));
+    let (min, max, mean) = multi.into_inner();
+    assert_eq!(min.into_inner(), Some(1));
+    assert_eq!(max.into_inner(), Some(8));
+    assert_eq!(mean.into_inner(), Some(4.0));
+    let collect = (1..=10).collect::<MultiFold<(MinMax<_>, Stats<u8>)>>();
+    let (minmax, stats) = collect.finish();
+    assert_eq!(minmax, Some((1, 10)));
+    assert_eq!(stats.count(), 10);
+    Ok(())
+}
+
+/// Test tuples directly as autofolders, with a type that needs cloning
+#[test]
+fn test_tuple() -> Result<()> {
+    let mut tuple = (
+        Max::<StrnumClone>::default(),
+        Min::default(),
+        DynFolder::new(0, |count, _: StrnumClone| count + 1),
+        DynFolder::new(String::new(), |s, i: StrnumClone| {
+            s + &usize::try_from(i).unwrap().to_string()
+        }),
+    );
+    for i in [3, 9, 5].iter() {
+        tuple.fold_item(StrnumClone::from(*i));
+    }
+    assert_eq!(tuple.peek().0, Some(&StrnumClone::from(9)));
+    let (max, min, count, concat) = tuple.finish();
+    assert_eq!(max, Some(StrnumClone::from(9)));
+    assert_eq!(min, Some(StrnumClone::from(3)));
+    assert_eq!(count, 3);
+    assert_eq!(concat, "395");
+    Ok(())
+}
@@ -0,0 +1,110 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test the AddAssign-based adder
+#[test]
+fn test_adder() -> Result<()> {
+    let mut sum = Adder::<f64>::default();
+    assert_eq!(*sum.as_ref(), 0.0);
+    sum.fold(1.5);
+    sum.extend(vec![2.0, 4.0]);
+    sum.extend(&[0.5]);
+    assert_eq!(sum.into_inner(), 8.0);
+    let sum = (1..=5_u64).collect::<Adder<_>>();
+    let sum = sum.merge(Adder::default()).merge(Adder::new(2));
+    assert_eq!(sum.into_inner(), 17);
+    Ok(())
+}
+
+/// Test the bitwise folders
+#[test]
+fn test_bitwise() -> Result<()> {
+    let mut flags = BitOrer::<u8>::default();
+    flags.fold(0b0001);
+    flags.extend(&[0b0100, 0b0001]);
+    assert_eq!(*flags, 0b0101);
+    let flags = flags.merge(BitOrer::from(0b1000));
+    assert_eq!(flags.into_inner(), 0b1101);
+    let parity = [1_u32, 3, 7].iter().collect::<BitXorer<_>>();
+    assert_eq!(parity.into_inner(), 5);
+    let mut common = BitAnder::<u16>::default();
+    assert_eq!(*common, None);
+    common.extend([0xff0f, 0x0fff]);
+    let common = common
+        .merge(BitAnder::default())
+        .merge(BitAnder::new(0xf00f));
+    assert_eq!(common.into_inner(), Some(0x000f));
+    Ok(())
+}
+
+/// Test the multiplier
+#[test]
+fn test_multiplier() -> Result<()> {
+    let mut product = Multiplier::<f64>::default();
+    assert_eq!(product.as_ref(), None);
+    product.fold(1.5);
+    product.extend(&[2.0, 4.0]);
+    assert_eq!(*product, Some(12.0));
+    let product = product.merge(Multiplier::from(0.5));
+    assert_eq!(product.into_inner(), Some(6.0));
+    Ok(())
+}
+
+/// Test the count of items
+#[test]
+fn test_count() -> Result<()> {
+    let mut sum = Adder::new(10_u32);
+    assert!(sum.is_empty());
+    sum.extend(&[1, 2]);
+    let sum = sum.merge((1..=3).collect());
+    assert_eq!(sum.count(), 5);
+    let mut product = Multiplier::new(2_u32);
+    assert_eq!(product.count(), 1);
+    product.fold(3);
+    let product = product.merge(Multiplier::default());
+    assert_eq!(product.count(), 2);
+    assert!(BitXorer::<u8>::default().is_empty());
+    Ok(())
+}
+
+/// Test folding items in with +=
+#[test]
+fn test_add_assign() -> Result<()> {
+    let mut flags = BitOrer::<u8>::default();
+    flags += 0b01;
+    flags += 0b10;
+    assert_eq!(flags.into_inner(), 0b11);
+    Ok(())
+}
+
+/// Test the Sum and Product implementations
+#[test]
+fn test_sum_product() -> Result<()> {
+    let sum = (1..=4_u64).sum::<Adder<_>>();
+    assert_eq!(sum.into_inner(), 10);
+    let sum = [0.5, 1.5].iter().sum::<Adder<f64>>();
+    assert_eq!(sum.into_inner(), 2.0);
+    let product = (1..=4_u64).product::<Multiplier<_>>();
+    assert_eq!(product.into_inner(), Some(24));
+    Ok(())
+}
+
+/// Test that fold_slice matches extend
+#[test]
+fn test_fold_slice() -> Result<()> {
+    let items = [0.5_f32, 1.25, -3.0, 8.0];
+    let mut sum = Adder::new(1.0);
+    sum.fold_slice(&items);
+    let mut extend = Adder::new(1.0);
+    extend.extend(items.iter());
+    assert_eq!((sum.count(), *sum), (extend.count(), *extend));
+    let mut flags = BitOrer::<u8>::default();
+    flags.fold_slice(&[1, 4, 1]);
+    assert_eq!((flags.count(), flags.into_inner()), (3, 5));
+    Ok(())
+}
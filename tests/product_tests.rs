@@ -0,0 +1,66 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test the reducer product
+#[test]
+fn test_product() -> Result<()> {
+    let mut product = Product::<f64>::default();
+    assert_eq!(product.as_ref(), None);
+    product.reduce(1.5);
+    product.extend(vec![2.0, 4.0]);
+    product.extend(&[0.5]);
+    assert_eq!(product.into_inner(), Some(6.0));
+    let product = (1..=5_u64).collect::<Product<_>>();
+    let product = product.merge(Product::default()).merge(Product::new(2));
+    assert_eq!(product.into_inner(), Some(240));
+    let mut multiplier: Multiplier<u32> = Product::new(3);
+    multiplier.reduce_ref(&2);
+    assert_eq!((multiplier.count(), *multiplier), (2, Some(6)));
+    Ok(())
+}
+
+/// Test the checked product without overflow
+#[test]
+fn test_checked() -> Result<()> {
+    let empty = CheckedProduct::<i32>::default();
+    assert_eq!(empty.into_inner(), Some(1));
+    let mut product = (1..=10_i64).collect::<CheckedProduct<_>>();
+    product.fold(-1);
+    assert!(!product.is_overflowed());
+    assert_eq!(product.as_ref(), Some(&-3_628_800));
+    let product = product.merge(CheckedProduct::from(2));
+    assert_eq!(product.into_inner(), Some(-7_257_600));
+    Ok(())
+}
+
+/// Test that overflows are recorded
+#[test]
+fn test_overflow() -> Result<()> {
+    let mut product = (1..=20_u64).collect::<CheckedProduct<_>>();
+    assert_eq!(product.as_ref(), Some(&2_432_902_008_176_640_000));
+    product.fold(21);
+    assert!(product.is_overflowed());
+    product.fold(0);
+    assert_eq!(product.into_inner(), None);
+    let mut product = CheckedProduct::<u8>::from(2);
+    product.merge_from(vec![200, 200].into_iter().collect());
+    assert_eq!(product.into_inner(), None);
+    Ok(())
+}
+
+/// Test the Product implementations
+#[test]
+fn test_iter_product() -> Result<()> {
+    let product = (1..=5_u32).product::<Product<_>>();
+    assert_eq!(product.into_inner(), Some(120));
+    let product = [1.5, 2.0].iter().product::<Product<f64>>();
+    assert_eq!(product.into_inner(), Some(3.0));
+    let product = [16_u8, 16].iter().product::<CheckedProduct<u8>>();
+    assert_eq!(product.into_inner(), None);
+    Ok(())
+}
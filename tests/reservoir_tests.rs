@@ -0,0 +1,69 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod reservoir_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test filling up and sampling
+    #[test]
+    fn test_reservoir() -> Result<()> {
+        let mut reservoir = Reservoir::<String>::new(3);
+        assert!(reservoir.is_empty());
+        reservoir.extend(["a", "b"].iter().map(|s| s.to_string()));
+        assert_eq!(reservoir.as_slice(), &["a", "b"]);
+        let strings = ["c", "d", "e"].map(String::from);
+        reservoir.extend(&strings);
+        assert_eq!(reservoir.len(), 3);
+        assert_eq!(reservoir.k(), 3);
+        assert_eq!(reservoir.seen(), 5);
+        let mut sample = reservoir.into_inner();
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 3);
+        let mut unbounded = Reservoir::with_seed(usize::MAX, 1);
+        unbounded.extend(1..=3);
+        assert_eq!(unbounded.into_inner(), vec![1, 2, 3]);
+        Ok(())
+    }
+
+    /// Test that the sampling is deterministic with a seed, and uniform
+    #[test]
+    fn test_uniform() -> Result<()> {
+        let sample = |seed| {
+            let mut reservoir = Reservoir::with_seed(2, seed);
+            reservoir.extend(0..10_usize);
+            reservoir.into_inner()
+        };
+        assert_eq!(sample(7), sample(7));
+        let mut counts = [0; 10];
+        for seed in 0..10_000 {
+            for i in sample(seed) {
+                counts[i] += 1;
+            }
+        }
+        // Each item is expected 2000 times:
+        assert!(
+            counts.iter().all(|&c| (1800..2200).contains(&c)),
+            "{:?}",
+            counts
+        );
+        Ok(())
+    }
+
+    /// Test with a rand generator
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rand() -> Result<()> {
+        use rand::SeedableRng;
+        let rng = rand::rngs::StdRng::seed_from_u64(3);
+        let mut reservoir = Reservoir::with_rng(4, RandRng(rng));
+        reservoir.extend(0..100);
+        assert_eq!(reservoir.len(), 4);
+        assert!(reservoir.as_slice().iter().all(|i| *i < 100));
+        Ok(())
+    }
+}
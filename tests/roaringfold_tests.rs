@@ -0,0 +1,43 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "roaring")]
+mod roaringfold_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+    use roaring::RoaringBitmap;
+
+    /// Test folding IDs and bitmaps
+    #[test]
+    fn test_roaringfold() -> Result<()> {
+        let mut ids = RoaringFold::default();
+        assert!(ids.is_empty());
+        ids.fold(7);
+        ids.extend(&[1_u32, 2, 7]);
+        assert_eq!(ids.cardinality(), 3);
+        ids.fold_bitmap((100..200).collect());
+        ids.extend(vec![(150..250).collect::<RoaringBitmap>()]);
+        assert_eq!(ids.cardinality(), 153);
+        assert!(ids.contains(249));
+        assert!(!ids.contains(250));
+        let clone = ids.clone();
+        ids.extend(1000..1010_u32);
+        assert_eq!(ids.as_ref().len(), 163);
+        assert_eq!(clone.into_inner().max(), Some(249));
+        Ok(())
+    }
+
+    /// Test collect, merge
+    #[test]
+    fn test_merge() -> Result<()> {
+        let ids1 = (0..10_u32).collect::<RoaringFold>();
+        let ids2 = vec![(5..15).collect::<RoaringBitmap>()]
+            .into_iter()
+            .collect::<RoaringFold>();
+        let merged = ids1.merge(ids2);
+        assert_eq!(merged.into_inner(), (0..15).collect::<RoaringBitmap>());
+        Ok(())
+    }
+}
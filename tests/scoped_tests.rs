@@ -0,0 +1,81 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod scoped_tests {
+    use anyhow::Result;
+
+    use autofolder::*;
+
+    /// Test folding chunks on scoped threads
+    #[test]
+    fn test_basic() -> Result<()> {
+        let items = (0..1000_u64).rev().collect::<Vec<_>>();
+        let max = fold_chunks_scoped(&items, 64, Max::default, Max::merge);
+        assert_eq!(max.into_inner(), Some(999));
+        let minmax = fold_chunks_scoped(&items, 7, MinMax::default, MinMax::merge);
+        assert_eq!(minmax.to_inner(), Some((0, 999)));
+        let sum = fold_chunks_scoped(&items, 1000, || Adder::new(0), Adder::merge);
+        assert_eq!(sum.into_inner(), 499500);
+        Ok(())
+    }
+
+    /// Test that the partials are merged in the order of the chunks
+    #[test]
+    fn test_order() -> Result<()> {
+        let items = (0..100_u32).collect::<Vec<_>>();
+        let lists = fold_chunks_scoped(
+            &items,
+            9,
+            || {
+                DynFolder::new(vec![], |mut v: Vec<u32>, i: u32| {
+                    v.push(i);
+                    v
+                })
+            },
+            |mut a, b| {
+                a.extend(b.into_inner());
+                a
+            },
+        );
+        assert_eq!(lists.into_inner(), items);
+        let first = fold_chunks_scoped(
+            &[(1, 'a'), (0, 'b'), (0, 'c'), (1, 'd')],
+            1,
+            || MinBy::new(|p: &(u32, char)| p.0),
+            |a, b| a.merge(b),
+        );
+        assert_eq!(first.into_inner(), Some((0, 'b')));
+        Ok(())
+    }
+
+    /// Test the empty slice
+    #[test]
+    fn test_empty() -> Result<()> {
+        let max = fold_chunks_scoped(&[] as &[u8], 4, Max::<u8>::default, Max::merge);
+        assert!(max.is_empty());
+        Ok(())
+    }
+
+    /// Test that a panic in a chunk is propagated
+    #[test]
+    #[should_panic(expected = "item 42")]
+    fn test_panic() {
+        let items = (0..100_u32).collect::<Vec<_>>();
+        fold_chunks_scoped(
+            &items,
+            10,
+            || {
+                DynFolder::new(0, |a: u32, i: u32| {
+                    assert!(i != 42, "item 42");
+                    a + i
+                })
+            },
+            |mut a, b| {
+                a.fold(b.into_inner());
+                a
+            },
+        );
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+    use serde::{Deserialize, Serialize};
+
+    /// Test checkpointing the trait-based autofolders
+    #[test]
+    fn test_impl_checkpoint() -> Result<()> {
+        #[derive(Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+        pub struct Usize(usize);
+        pub type Adder = ImplFolder<Usize, u16>;
+        impl FolderTrait<Usize, u16> for Adder {
+            fn fold(accum: Usize, item: u16) -> Usize {
+                Usize(accum.0 + item as usize)
+            }
+        }
+        let sum = (1..=5).collect::<Adder>();
+        let json = serde_json::to_string(&sum)?;
+        let mut sum = serde_json::from_str::<Adder>(&json)?;
+        sum.extend(6..=10);
+        assert_eq!(sum.into_inner(), Usize(55));
+        #[derive(Debug, Serialize, Deserialize)]
+        pub struct MyString(String);
+        autofolder_impl_reducetrait!(|lhs, rhs| -> MyString { MyString(lhs.0 + &rhs.0) });
+        let concat = vec![MyString("a".into()), MyString("b".into())]
+            .into_iter()
+            .collect::<ImplReduce<MyString>>();
+        let json = serde_json::to_string(&concat)?;
+        let mut concat = serde_json::from_str::<ImplReduce<MyString>>(&json)?;
+        concat.reduce(MyString("c".into()));
+        assert_eq!(concat.into_inner().map(|s| s.0).as_deref(), Some("abc"));
+        Ok(())
+    }
+
+    /// Test checkpointing the min/max types
+    #[test]
+    fn test_minmax_checkpoint() -> Result<()> {
+        let minmax = vec![4, 2, 8].into_iter().collect::<MinMax<i32>>();
+        let json = serde_json::to_string(&minmax)?;
+        let mut minmax = serde_json::from_str::<MinMax<i32>>(&json)?;
+        minmax.reduce(1);
+        assert_eq!(minmax.to_inner(), Some((1, 8)));
+        let max =
+            serde_json::from_str::<Max<i32>>(&serde_json::to_string(&Max::<i32>::default())?)?;
+        assert_eq!(max.into_inner(), None);
+        Ok(())
+    }
+
+    /// Test restoring the closure-based autofolders from their state
+    #[test]
+    fn test_dyn_state() -> Result<()> {
+        let mut sum = DynFolder::new(0_usize, |a, i: usize| a + i);
+        sum.extend(1..=5);
+        let json = serde_json::to_string(&sum.into_state())?;
+        let mut sum =
+            DynFolder::from_state(serde_json::from_str::<usize>(&json)?, |a, i: usize| a + i);
+        sum.extend(6..=10);
+        assert_eq!(sum.into_inner(), 55);
+        let max = DynReduce::new(std::cmp::max::<i32>);
+        let json = serde_json::to_string(&max.into_state())?;
+        let mut max = DynReduce::from_state(serde_json::from_str(&json)?, std::cmp::max);
+        assert_eq!(max.as_ref(), None);
+        max.extend(vec![3, 9, 4]);
+        assert_eq!(max.into_inner(), Some(9));
+        Ok(())
+    }
+}
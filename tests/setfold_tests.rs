@@ -0,0 +1,65 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod setfold_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    use std::collections::BTreeSet;
+    use std::collections::HashSet;
+
+    fn hashset(items: &[usize]) -> HashSet<usize> {
+        items.iter().copied().collect()
+    }
+
+    fn btreeset(items: &[usize]) -> BTreeSet<usize> {
+        items.iter().copied().collect()
+    }
+
+    /// Test union of HashSets
+    #[test]
+    fn test_union() -> Result<()> {
+        let mut union = UnionFold::<HashSet<usize>>::default();
+        assert!(union.as_ref().is_empty());
+        union.reduce(hashset(&[1, 2]));
+        union.extend(vec![hashset(&[2, 3, 4]), hashset(&[])]);
+        assert_eq!(union.as_ref(), &hashset(&[1, 2, 3, 4]));
+        let clone = union.clone();
+        union.reduce(hashset(&[9]));
+        assert_eq!(union.into_inner(), hashset(&[1, 2, 3, 4, 9]));
+        let merged = clone.merge(UnionFold::from(hashset(&[0])));
+        assert_eq!(merged.into_inner(), hashset(&[0, 1, 2, 3, 4]));
+        let collect = vec![btreeset(&[5]), btreeset(&[3])]
+            .into_iter()
+            .collect::<UnionFold<_>>();
+        assert_eq!(collect.into_inner(), btreeset(&[3, 5]));
+        Ok(())
+    }
+
+    /// Test intersection of BTreeSets, including the empty-start case
+    #[test]
+    fn test_intersection() -> Result<()> {
+        let mut intersection = IntersectionFold::<BTreeSet<usize>>::default();
+        assert_eq!(intersection.as_ref(), None);
+        intersection.reduce(btreeset(&[1, 2, 3, 4]));
+        assert_eq!(intersection.as_ref(), Some(&btreeset(&[1, 2, 3, 4])));
+        intersection.extend(vec![btreeset(&[2, 3, 4, 5]), btreeset(&[0, 3, 4])]);
+        assert_eq!(intersection.as_ref(), Some(&btreeset(&[3, 4])));
+        let merged = intersection.merge(IntersectionFold::default());
+        assert_eq!(merged.as_ref(), Some(&btreeset(&[3, 4])));
+        let merged = merged.merge(IntersectionFold::from(btreeset(&[4])));
+        assert_eq!(merged.into_inner(), Some(btreeset(&[4])));
+        let collect = vec![hashset(&[1, 2]), hashset(&[3])]
+            .into_iter()
+            .collect::<IntersectionFold<_>>();
+        assert_eq!(collect.into_inner(), Some(hashset(&[])));
+        let empty = Vec::<HashSet<usize>>::new()
+            .into_iter()
+            .collect::<IntersectionFold<_>>();
+        assert_eq!(empty.into_inner(), None);
+        Ok(())
+    }
+}
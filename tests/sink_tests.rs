@@ -0,0 +1,41 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "futures")]
+mod sink_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+    use futures::SinkExt;
+
+    /// Test forwarding streams into folders
+    #[test]
+    fn test_forward() -> Result<()> {
+        let mut sum = DynFolder::new(0_usize, |a, i: u16| a + i as usize);
+        block_on(stream::iter(1..=4_u16).map(Ok).forward(&mut sum))?;
+        assert_eq!(sum.into_inner(), 10);
+        let mut minmax = MinMax::default();
+        block_on(stream::iter(vec![5, 2, 9]).map(Ok).forward(&mut minmax))?;
+        assert_eq!(minmax.to_inner(), Some((2, 9)));
+        Ok(())
+    }
+
+    /// Test sending individual items into reducers
+    #[test]
+    fn test_send() -> Result<()> {
+        let mut concat = DynReduce::new(|a: String, b: String| a + &b);
+        block_on(async {
+            concat.send("a".to_string()).await?;
+            concat.send("b".to_string()).await?;
+            concat.close().await
+        })?;
+        assert_eq!(concat.into_inner().as_deref(), Some("ab"));
+        let mut min = Min::default();
+        block_on(min.send_all(&mut stream::iter(vec![Ok(3), Ok(1)])))?;
+        assert_eq!(min.into_inner(), Some(1));
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "snapshot")]
+mod snapshot_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test roundtrip of the min/max types
+    #[test]
+    fn test_roundtrip() -> Result<()> {
+        let min = (3..=7).collect::<Min<i32>>();
+        let min = Min::<i32>::from_bytes(&min.to_bytes()?)?;
+        assert_eq!(min.into_inner(), Some(3));
+        let max = Max::<String>::from("a".to_string());
+        let max = Max::<String>::from_bytes(&max.to_bytes()?)?;
+        assert_eq!(max.into_inner(), Some("a".to_string()));
+        let mut minmax = MinMax::<usize>::default();
+        let bytes = minmax.to_bytes()?;
+        assert_eq!(MinMax::<usize>::from_bytes(&bytes)?.to_inner(), None);
+        minmax.extend(vec![5, 1, 9]);
+        let mut minmax = MinMax::<usize>::from_bytes(&minmax.to_bytes()?)?;
+        minmax.reduce(10);
+        assert_eq!(minmax.to_inner(), Some((1, 10)));
+        Ok(())
+    }
+
+    /// Test header validation
+    #[test]
+    fn test_header() -> Result<()> {
+        let bytes = Max::<u8>::from(7).to_bytes()?;
+        assert_eq!(&bytes[..2], b"AF");
+        assert_eq!(bytes[2], SNAPSHOT_VERSION);
+        assert!(matches!(
+            Min::<u8>::from_bytes(&bytes),
+            Err(SnapshotError::TagMismatch {
+                expected: "Min",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Max::<u8>::from_bytes(b"XX"),
+            Err(SnapshotError::BadMagic)
+        ));
+        let mut future = bytes.clone();
+        future[2] = SNAPSHOT_VERSION + 1;
+        assert!(matches!(
+            Max::<u8>::from_bytes(&future),
+            Err(SnapshotError::UnsupportedVersion(_))
+        ));
+        assert!(matches!(
+            Max::<u8>::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(SnapshotError::Encoding(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod sortedmerge_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test extend, collect
+    #[test]
+    fn test_sortedmerge() -> Result<()> {
+        let mut merge = SortedMerge::<usize>::default();
+        assert!(merge.is_empty());
+        merge.reduce(vec![1, 4, 7]);
+        merge.reduce(vec![]);
+        merge.extend(vec![vec![2, 5, 8], vec![3, 6, 9]]);
+        assert_eq!(merge.run_count(), 3);
+        assert_eq!(merge.len(), 9);
+        let clone = merge.clone();
+        assert_eq!(merge.into_inner(), (1..=9).collect::<Vec<_>>());
+        let iter = clone.into_iter();
+        assert_eq!(iter.len(), 9);
+        assert_eq!(iter.take(3).collect::<Vec<_>>(), vec![1, 2, 3]);
+        let collect = vec![vec![5, 6], vec![0, 10]]
+            .into_iter()
+            .collect::<SortedMerge<_>>();
+        assert_eq!(collect.into_inner(), vec![0, 5, 6, 10]);
+        Ok(())
+    }
+
+    /// Test that equal items keep the order of their runs
+    #[test]
+    fn test_stable() -> Result<()> {
+        #[derive(Debug)]
+        struct Item(usize, &'static str);
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Item {}
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+        let run1 = vec![Item(1, "a"), Item(2, "a")];
+        let run2 = vec![Item(1, "b"), Item(2, "b")];
+        let merge = SortedMerge::from(run1).merge(SortedMerge::from(run2));
+        let labels = merge.into_iter().map(|i| i.1).collect::<Vec<_>>();
+        assert_eq!(labels, vec!["a", "b", "a", "b"]);
+        Ok(())
+    }
+}
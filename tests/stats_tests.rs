@@ -0,0 +1,64 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+fn assert_close(value: Option<f64>, expected: f64) {
+    let value = value.unwrap();
+    assert!((value - expected).abs() < 1e-9, "{} != {}", value, expected);
+}
+
+/// Test the statistics of a small set
+#[test]
+fn test_stats() -> Result<()> {
+    let mut stats = Stats::<i32>::default();
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.variance(), None);
+    stats.reduce(3);
+    assert_eq!(stats.variance(), Some(0.0));
+    assert_eq!(stats.sample_variance(), None);
+    stats.extend(&[5, 7, 9]);
+    assert_eq!(stats.count(), 4);
+    assert_close(stats.mean(), 6.0);
+    assert_close(stats.variance(), 5.0);
+    assert_close(stats.sample_variance(), 20.0 / 3.0);
+    #[cfg(feature = "std")]
+    assert_close(stats.stddev(), 5.0_f64.sqrt());
+    #[cfg(feature = "std")]
+    assert_close(stats.sample_stddev(), (20.0_f64 / 3.0).sqrt());
+    Ok(())
+}
+
+/// Test numerical stability with a large offset
+#[test]
+fn test_large_offset() -> Result<()> {
+    let stats = [4.0, 7.0, 13.0, 16.0]
+        .iter()
+        .map(|v| v + 1e9)
+        .collect::<Stats<f64>>();
+    assert_close(stats.mean(), 1e9 + 10.0);
+    assert_close(stats.sample_variance(), 30.0);
+    Ok(())
+}
+
+/// Test merging partial statistics
+#[test]
+fn test_merge() -> Result<()> {
+    let items = (1..=20).map(|i| (i * i) as f32).collect::<Vec<_>>();
+    let whole = items.iter().collect::<Stats<_>>();
+    let merged = items[..7]
+        .iter()
+        .collect::<Stats<_>>()
+        .merge(Stats::default())
+        .merge(items[7..].iter().collect());
+    assert_eq!(merged.count(), whole.count());
+    assert_close(merged.mean(), whole.mean().unwrap());
+    assert_close(merged.variance(), whole.variance().unwrap());
+    let mut empty = Stats::<f32>::new();
+    empty.merge_from(whole);
+    assert_close(empty.sample_variance(), whole.sample_variance().unwrap());
+    Ok(())
+}
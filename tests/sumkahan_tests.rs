@@ -0,0 +1,44 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+/// Test accuracy on a long stream of small values
+#[test]
+fn test_long_stream() -> Result<()> {
+    let naive = std::iter::repeat_n(0.1_f64, 10_000_000).sum::<f64>();
+    let sum = std::iter::repeat_n(0.1_f64, 10_000_000).collect::<SumKahan<f64>>();
+    assert!((naive - 1e6).abs() > 1e-6);
+    assert!((sum.into_inner() - 1e6).abs() < 1e-6);
+    let naive = std::iter::repeat_n(0.1_f32, 100_000).sum::<f32>();
+    let sum = std::iter::repeat_n(0.1_f32, 100_000).collect::<SumKahan<f32>>();
+    assert!((naive - 10_000.0).abs() > 1.0);
+    assert!((sum.into_inner() - 10_000.0).abs() < 1e-2);
+    Ok(())
+}
+
+/// Test items larger than the running sum
+#[test]
+fn test_large_items() -> Result<()> {
+    let mut sum = SumKahan::<f64>::new(1.0);
+    sum.extend(&[1e100, 1.0, -1e100]);
+    assert_eq!(sum.sum(), 2.0);
+    sum.fold(0.5);
+    assert_eq!(sum.into_inner(), 2.5);
+    Ok(())
+}
+
+/// Test merging partial sums
+#[test]
+fn test_merge() -> Result<()> {
+    let a = vec![1e16_f64, 1.0].into_iter().collect::<SumKahan<_>>();
+    let b = vec![1.0, -1e16_f64].into_iter().collect::<SumKahan<_>>();
+    assert_eq!(a.merge(b).into_inner(), 2.0);
+    let mut empty = SumKahan::<f32>::default();
+    empty.merge_from(SumKahan::from(3.5));
+    assert_eq!(empty.into_inner(), 3.5);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "num")]
+mod sumnum_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test fold, extend and collect for SumNum
+    #[test]
+    fn test_sumnum() -> Result<()> {
+        let mut sum = SumNum::<u64>::new();
+        assert_eq!((sum.is_empty(), *sum.as_ref()), (true, 0));
+        sum.fold(u64::from(u32::MAX));
+        sum.extend(&[1, 2]);
+        assert_eq!(sum.count(), 3);
+        assert_eq!(sum.into_inner(), 4294967298);
+        let sum = vec![1.5, 2.5].into_iter().collect::<SumNum<f32>>();
+        assert_eq!(sum.finish(), 4.0);
+        let sum = [num::Complex::new(1, 2), num::Complex::new(3, -1)]
+            .iter()
+            .collect::<SumNum<_>>();
+        assert_eq!(sum.into_inner(), num::Complex::new(4, 1));
+        Ok(())
+    }
+
+    /// Test fold, extend and merge for ProductNum
+    #[test]
+    fn test_productnum() -> Result<()> {
+        let mut product = ProductNum::<i64>::default();
+        assert_eq!(*product.as_ref(), 1);
+        product.extend(vec![-2, 3]);
+        let other = (1..=4).collect::<ProductNum<i64>>();
+        let product = product.merge(other);
+        assert_eq!((product.count(), product.into_inner()), (6, -144));
+        Ok(())
+    }
+
+    /// Test reduce, extend and merge for MeanNum
+    #[test]
+    fn test_meannum() -> Result<()> {
+        let mut mean = MeanNum::<usize>::default();
+        assert_eq!(mean.mean(), None);
+        mean.extend(&[1, 2, 3]);
+        let mut other = vec![u64::MAX, u64::MAX]
+            .into_iter()
+            .collect::<MeanNum<u64>>();
+        assert_eq!(other.mean(), Some(u64::MAX as f64));
+        other.reduce(0);
+        assert_eq!(other.count(), 3);
+        let mean = mean.merge(vec![10, 14].into_iter().collect());
+        assert_eq!((mean.count(), mean.into_inner()), (5, Some(6.0)));
+        Ok(())
+    }
+}
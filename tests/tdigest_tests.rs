@@ -0,0 +1,145 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "tdigest")]
+mod tdigest_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    fn values(len: u64, seed: u64) -> Vec<f64> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1_u64 << 53) as f64
+            })
+            .collect()
+    }
+
+    fn exact_quantile(sorted: &[f64], q: f64) -> f64 {
+        sorted[((sorted.len() - 1) as f64 * q).round() as usize]
+    }
+
+    /// Test the empty and small digests
+    #[test]
+    fn test_small() -> Result<()> {
+        let mut digest = TDigest::<f32>::default();
+        assert!(digest.is_empty());
+        assert_eq!(digest.quantile(0.5), None);
+        assert_eq!(digest.cdf(0.0), None);
+        assert_eq!(digest.min(), None);
+        digest.reduce(3.0);
+        assert_eq!(digest.quantile(0.0), Some(3.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(1.0), Some(3.0));
+        assert_eq!(digest.cdf(2.0), Some(0.0));
+        assert_eq!(digest.cdf(3.0), Some(1.0));
+        digest.extend(&[1.0, f32::NAN, 5.0, f32::INFINITY, f32::NEG_INFINITY]);
+        assert_eq!(digest.count(), 3);
+        assert_eq!((digest.min(), digest.max()), (Some(1.0), Some(5.0)));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(2.0), Some(5.0));
+        assert_eq!(digest.cdf(3.0), Some(0.5));
+        Ok(())
+    }
+
+    /// Test the accuracy against the exact quantiles
+    #[test]
+    fn test_accuracy() -> Result<()> {
+        let mut items = values(100_000, 1);
+        let digest = items.iter().collect::<TDigest<_>>();
+        items.sort_by(f64::total_cmp);
+        for q in [0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let estimate = digest.quantile(q).unwrap();
+            let expected = exact_quantile(&items, q);
+            let error = (q * (1.0 - q)).sqrt() * 0.02;
+            assert!(
+                (estimate - expected).abs() < error,
+                "q {}: {} != {}",
+                q,
+                estimate,
+                expected
+            );
+            let cdf = digest.cdf(expected).unwrap();
+            assert!((cdf - q).abs() < error, "cdf at q {}: {}", q, cdf);
+        }
+        assert_eq!(digest.quantile(0.0), Some(items[0]));
+        assert_eq!(digest.quantile(1.0), Some(items[items.len() - 1]));
+        Ok(())
+    }
+
+    /// Test merging digests built from shards
+    #[test]
+    fn test_merge() -> Result<()> {
+        let shards = (0..10).map(|seed| values(10_000, seed)).collect::<Vec<_>>();
+        let mut merged = TDigest::<f64>::new(200.0);
+        for shard in &shards {
+            merged.merge_from(shard.iter().collect());
+        }
+        let mut items = shards.concat();
+        items.sort_by(f64::total_cmp);
+        assert_eq!(merged.count(), items.len());
+        assert_eq!(merged.compression(), 200.0);
+        for q in [0.01, 0.5, 0.99] {
+            let estimate = merged.quantile(q).unwrap();
+            assert!((estimate - exact_quantile(&items, q)).abs() < 0.005);
+        }
+        let merged = merged.merge(TDigest::default());
+        assert_eq!(merged.count(), items.len());
+        Ok(())
+    }
+
+    /// Test the unsupported compression
+    #[test]
+    #[should_panic(expected = "unsupported compression")]
+    fn test_bad_compression() {
+        TDigest::<f64>::new(0.5);
+    }
+
+    /// Test the compression limits
+    #[test]
+    fn test_compression_limits() -> Result<()> {
+        for compression in [f64::NAN, f64::INFINITY, 1e20] {
+            let result = std::panic::catch_unwind(|| TDigest::<f64>::new(compression));
+            assert!(result.is_err());
+        }
+        let mut digest = TDigest::<f64>::new(TDigest::<f64>::MAX_COMPRESSION);
+        digest.extend(values(1000, 1));
+        assert!(digest.quantile(0.5).is_some());
+        Ok(())
+    }
+
+    /// Test that deserialization validates the state
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize() -> Result<()> {
+        let digest = values(1000, 1).into_iter().collect::<TDigest<f64>>();
+        let json = serde_json::to_string(&digest)?;
+        let restored = serde_json::from_str::<TDigest<f64>>(&json)?;
+        assert_eq!(restored.quantile(0.5), digest.quantile(0.5));
+        let huge = json.replace("\"compression\":100.0", "\"compression\":1e20");
+        assert!(huge.contains("1e20"));
+        assert!(serde_json::from_str::<TDigest<f64>>(&huge).is_err());
+        let state = |centroids, count, min, max| {
+            format!(
+                r#"{{"compression":100,"centroids":{},"buffer":[],"count":{},"min":{},"max":{}}}"#,
+                centroids, count, min, max
+            )
+        };
+        let centroids = r#"[{"mean":0.5,"weight":2.0}]"#;
+        let valid = state(centroids, 2, 0.0, 1.0);
+        assert!(serde_json::from_str::<TDigest<f64>>(&valid).is_ok());
+        for invalid in [
+            state(centroids, 3, 0.0, 1.0),
+            state(centroids, 2, 1.0, 0.0),
+            state("[]", 3, 0.0, 1.0),
+        ] {
+            assert!(serde_json::from_str::<TDigest<f64>>(&invalid).is_err());
+        }
+        Ok(())
+    }
+}
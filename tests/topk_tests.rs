@@ -0,0 +1,58 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod topk_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test extend, peek, with_items for TopK
+    #[test]
+    fn test_topk() -> Result<()> {
+        let mut top = TopK::<i32>::new(3);
+        assert!(top.is_empty());
+        assert_eq!(top.peek(), None);
+        top.fold(4);
+        top.extend(vec![8, -1, 6]);
+        assert_eq!(top.peek(), Some(&4));
+        top.extend(&[5, 2, 10]);
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.k(), 3);
+        assert_eq!(top.into_sorted_vec(), vec![6, 8, 10]);
+        let top = TopK::new(2).with_items("autofolder".chars());
+        assert_eq!(top.into_sorted_vec(), vec!['t', 'u']);
+        Ok(())
+    }
+
+    /// Test extend, peek, with_items for BottomK
+    #[test]
+    fn test_bottomk() -> Result<()> {
+        let strings = ["d", "b", "e", "a", "c"].map(String::from);
+        let mut bottom = BottomK::new(2).with_items(strings.iter().cloned());
+        assert_eq!(bottom.peek().map(String::as_str), Some("b"));
+        bottom.extend(&strings);
+        assert_eq!(bottom.into_sorted_vec(), vec!["a", "a"]);
+        let mut empty = BottomK::<u8>::new(0);
+        empty.extend(1..=5);
+        assert!(empty.is_empty());
+        let top = TopK::<u8>::new(usize::MAX).with_items(1..=3);
+        let bottom = BottomK::<u8>::new(usize::MAX).with_items(1..=3);
+        assert_eq!(top.into_sorted_vec(), vec![1, 2, 3]);
+        assert_eq!(bottom.into_sorted_vec(), vec![1, 2, 3]);
+        Ok(())
+    }
+
+    /// Test merging
+    #[test]
+    fn test_merge() -> Result<()> {
+        let a = TopK::new(3).with_items(vec![1, 5, 9, 2]);
+        let b = TopK::new(10).with_items(vec![7, 3, 8]);
+        assert_eq!(a.merge(b).into_sorted_vec(), vec![7, 8, 9]);
+        let mut a = BottomK::new(3).with_items(vec![1, 5, 9, 2]);
+        a.merge_from(BottomK::new(3).with_items(vec![7, 0, 8]));
+        assert_eq!(a.into_sorted_vec(), vec![0, 1, 2]);
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod topkscored_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test fold, extend, ties
+    #[test]
+    fn test_topkscored() -> Result<()> {
+        let mut top = TopKScored::<usize>::new(3);
+        assert!(top.is_empty());
+        assert_eq!(top.threshold(), None);
+        top.fold(1, 1.0);
+        top.extend(vec![(2, 5.0), (3, 3.0), (4, 3.0)]);
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.threshold(), Some(3.0));
+        top.extend(&[(5, 3.0), (6, 0.5)]);
+        assert_eq!(top.len(), 3);
+        let clone = top.clone();
+        top.fold(7, 3.0);
+        assert_eq!(top.k(), 3);
+        assert_eq!(top.into_inner(), vec![(2, 5.0), (3, 3.0), (4, 3.0)]);
+        assert_eq!(clone.into_inner().len(), 3);
+        let mut empty = TopKScored::new(0);
+        empty.fold("a", 1.0);
+        assert!(empty.into_inner().is_empty());
+        Ok(())
+    }
+
+    /// Test NaN scores and an unbounded k
+    #[test]
+    fn test_nan() -> Result<()> {
+        let mut top = TopKScored::new(usize::MAX);
+        top.extend(vec![("a", 1.0), ("nan", f64::NAN), ("inf", f64::INFINITY)]);
+        top.fold_ref(&"-nan", -f64::NAN);
+        assert_eq!(top.threshold(), Some(1.0));
+        assert_eq!(top.into_inner(), vec![("inf", f64::INFINITY), ("a", 1.0)]);
+        Ok(())
+    }
+
+    /// Test key closure with a type without clone, merge
+    #[test]
+    fn test_merge() -> Result<()> {
+        #[derive(Debug, PartialEq)]
+        struct Doc(&'static str);
+        let mut top1 = TopKScored::new(2);
+        top1.extend_with(vec![Doc("a"), Doc("abcd")], |d| d.0.len() as f64);
+        let mut top2 = TopKScored::new(2);
+        top2.extend_with(vec![Doc("abc"), Doc("ab")], |d| d.0.len() as f64);
+        let merged = top1.merge(top2);
+        assert_eq!(
+            merged.into_inner(),
+            vec![(Doc("abcd"), 4.0), (Doc("abc"), 3.0)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use autofolder::*;
+
+use anyhow::Result;
+
+fn parse_add(a: &i64, s: &str) -> Result<i64, std::num::ParseIntError> {
+    Ok(a + s.parse::<i64>()?)
+}
+
+/// Test folding without errors
+#[test]
+fn test_try_fold_ok() -> Result<()> {
+    let mut sum = TryDynFolder::new(0, parse_add);
+    sum.try_fold("3")?;
+    sum.try_extend(vec!["4", "5"])?;
+    assert_eq!(sum.as_ref(), &12);
+    eprintln!("{:?}", sum);
+    assert_eq!(sum.into_inner(), 12);
+    Ok(())
+}
+
+/// Test that the first error stops the iteration and keeps the last good state
+#[test]
+fn test_try_extend_error() -> Result<()> {
+    let mut sum = TryDynFolder::new(0, parse_add);
+    let mut items = vec!["1", "2", "x", "4"].into_iter();
+    assert!(sum.try_extend(&mut items).is_err());
+    assert_eq!(sum.as_ref(), &3);
+    assert_eq!(items.collect::<Vec<_>>(), vec!["4"]);
+    assert!(sum.try_fold("y").is_err());
+    sum.try_fold("10")?;
+    assert_eq!(sum.into_inner(), 13);
+    Ok(())
+}
+
+/// Test output that is not Copy
+#[test]
+fn test_vec_output() -> Result<()> {
+    let mut vec = TryDynFolder::new(vec![], |v: &Vec<u8>, i: u8| {
+        if v.len() < 2 {
+            Ok([v.as_slice(), &[i]].concat())
+        } else {
+            Err(i)
+        }
+    });
+    assert_eq!(vec.try_extend(1..=5), Err(3));
+    assert_eq!(vec.into_inner(), vec![1, 2]);
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod windowed_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+
+    /// Test rolling max and eviction
+    #[test]
+    fn test_windowed() -> Result<()> {
+        let mut rolling = Windowed::new(3, std::cmp::max);
+        assert!(rolling.is_empty());
+        assert_eq!(rolling.result(), None);
+        assert_eq!(rolling.fold(5), None);
+        rolling.extend(vec![1, 2]);
+        assert!(rolling.is_full());
+        assert_eq!(rolling.result(), Some(5));
+        assert_eq!(rolling.fold(0), Some(5));
+        assert_eq!(rolling.result(), Some(2));
+        rolling.extend(&[4, -1]);
+        assert_eq!(rolling.iter().copied().collect::<Vec<_>>(), vec![0, 4, -1]);
+        assert_eq!(rolling.len(), rolling.size());
+        assert_eq!(rolling.into_inner(), Some(4));
+        Ok(())
+    }
+
+    /// Test the window results along a stream
+    #[test]
+    fn test_rolling_sum() -> Result<()> {
+        let mut rolling = Windowed::new(2, |a: String, b: String| a + &b);
+        let results = ["a", "b", "c", "d"]
+            .iter()
+            .map(|s| {
+                rolling.fold(s.to_string());
+                rolling.result().unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(results, vec!["a", "ab", "bc", "cd"]);
+        assert_eq!(rolling.into_window(), vec!["c", "d"]);
+        Ok(())
+    }
+
+    /// Test an empty window
+    #[test]
+    fn test_empty() -> Result<()> {
+        let mut rolling = Windowed::new(0, |a: u8, b| a + b);
+        assert_eq!(rolling.fold(1), Some(1));
+        assert!(rolling.is_empty());
+        assert!(rolling.is_full());
+        assert_eq!(rolling.into_inner(), None);
+        Ok(())
+    }
+}
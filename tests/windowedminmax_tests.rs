@@ -0,0 +1,72 @@
+// Copyright (C) 2026 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#[cfg(feature = "std")]
+mod windowedminmax_tests {
+    use autofolder::*;
+
+    use anyhow::Result;
+    use std::time::Duration;
+    use std::time::Instant;
+
+    /// Test the window against recomputing the min and max of the live items
+    #[test]
+    fn test_brute_force() -> Result<()> {
+        let mut minmax = WindowedMinMax::<u8, u32, u32>::new(10);
+        let mut pairs = vec![];
+        let mut seed = 7_u64;
+        let mut key = 0;
+        for _ in 0..1000 {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            key += (seed >> 62) as u32;
+            let item = (seed >> 59) as u8;
+            minmax.reduce((key, item));
+            pairs.push((key, item));
+            let live = pairs
+                .iter()
+                .filter(|(k, _)| key - k < 10)
+                .map(|(_, i)| *i)
+                .collect::<MinMax<_>>();
+            assert_eq!(minmax.as_ref(), live.as_ref());
+        }
+        Ok(())
+    }
+
+    /// Test advance_to, older keys and NaNs
+    #[test]
+    fn test_advance() -> Result<()> {
+        let mut minmax = WindowedMinMax::<f64, u64, u64>::new(5);
+        assert!(minmax.is_empty());
+        assert_eq!(minmax.latest(), None);
+        minmax.extend(&[(10, 2.0), (11, f64::NAN), (12, 1.0)]);
+        assert_eq!(minmax.as_ref(), Some((&1.0, &2.0)));
+        // An older key is taken as the latest one:
+        minmax.reduce((3, 0.5));
+        assert_eq!(minmax.latest(), Some(&12));
+        minmax.advance_to(15);
+        assert_eq!(minmax.min_as_ref(), Some(&0.5));
+        assert_eq!(minmax.max_as_ref(), Some(&1.0));
+        minmax.advance_to(1);
+        assert_eq!(minmax.latest(), Some(&15));
+        minmax.advance_to(17);
+        assert!(minmax.is_empty());
+        assert_eq!(*minmax.window(), 5);
+        assert_eq!(minmax.into_inner(), None);
+        Ok(())
+    }
+
+    /// Test with instants and the Folding trait
+    #[test]
+    fn test_instant() -> Result<()> {
+        let start = Instant::now();
+        let mut minmax = WindowedMinMax::<&str>::new(Duration::from_secs(60));
+        minmax.fold_item((start, "m"));
+        minmax.fold_item((start + Duration::from_secs(30), "z"));
+        minmax.fold_item((start + Duration::from_secs(61), "a"));
+        assert_eq!(minmax.peek(), Some((&"a", &"z")));
+        minmax.advance_to(start + Duration::from_secs(90));
+        assert_eq!(minmax.finish(), Some(("a", "a")));
+        Ok(())
+    }
+}